@@ -1,10 +1,10 @@
 use std::{error::Error, io};
 use tui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Layout},
     style::{Color, Modifier, Style},
     text::Span,
-    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    widgets::{Block, Borders, Paragraph, Popup, Wrap},
     Frame, Terminal,
 };
 
@@ -93,36 +93,12 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &App) {
     f.render_widget(block, chunks[1]);
 
     if app.show_popup {
-        let block = Block::default().title("Popup").borders(Borders::ALL);
-        let area = centered_rect(60, 20, size);
-        f.render_widget(Clear, area); //this clears out the background
-        f.render_widget(block, area);
+        let popup = Popup::new(Paragraph::new("Press p to close"))
+            .block(Block::default().title("Popup").borders(Borders::ALL))
+            .width(Constraint::Percentage(60))
+            .height(Constraint::Percentage(20))
+            .backdrop_style(Style::DEFAULT.add_modifier(Modifier::DIM))
+            .shadow_style(Style::DEFAULT.bg(Color::Black));
+        f.render_widget(popup, size);
     }
 }
-
-/// helper function to create a centered rect using up certain percentage of the available rect `r`
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints(
-            [
-                Constraint::Percentage((100 - percent_y) / 2),
-                Constraint::Percentage(percent_y),
-                Constraint::Percentage((100 - percent_y) / 2),
-            ]
-            .as_ref(),
-        )
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(
-            [
-                Constraint::Percentage((100 - percent_x) / 2),
-                Constraint::Percentage(percent_x),
-                Constraint::Percentage((100 - percent_x) / 2),
-            ]
-            .as_ref(),
-        )
-        .split(popup_layout[1])[1]
-}
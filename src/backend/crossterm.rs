@@ -2,14 +2,14 @@ use crate::{
     backend::Backend,
     buffer::Cell,
     layout::Rect,
-    style::{Color, Modifier},
+    style::{Color, ColorDepth, Hyperlink, Modifier},
 };
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
     execute, queue,
     style::{
         Attribute as CAttribute, Color as CColor, Print, SetAttribute, SetBackgroundColor,
-        SetForegroundColor,
+        SetForegroundColor, SetUnderlineColor,
     },
     terminal::{size as terminalSize, Clear, ClearType},
 };
@@ -17,6 +17,7 @@ use std::io::{self, Write};
 
 pub struct CrosstermBackend<W: Write> {
     buffer: W,
+    color_depth: ColorDepth,
 }
 
 impl<W> CrosstermBackend<W>
@@ -24,7 +25,15 @@ where
     W: Write,
 {
     pub fn new(buffer: W) -> CrosstermBackend<W> {
-        CrosstermBackend { buffer }
+        CrosstermBackend {
+            buffer,
+            color_depth: ColorDepth::TrueColor,
+        }
+    }
+
+    /// Sets the number of colors supported by the terminal, e.g. from [`ColorDepth::detect`].
+    pub fn set_color_depth(&mut self, color_depth: ColorDepth) {
+        self.color_depth = color_depth;
     }
 }
 
@@ -51,7 +60,9 @@ where
     {
         let mut fg = Color::Reset;
         let mut bg = Color::Reset;
+        let mut underline_color = Color::Reset;
         let mut modifier = Modifier::EMPTY;
+        let mut hyperlink = Hyperlink::NONE;
         let mut last_pos: Option<(u16, u16)> = None;
         for (x, y, cell) in content {
             // Move the cursor if the previous location was not (x - 1, y)
@@ -81,10 +92,25 @@ where
                 map_error(queue!(self.buffer, SetBackgroundColor(color)))?;
                 bg = cell.bg;
             }
+            if cell.underline_color != underline_color {
+                let color = CColor::from(cell.underline_color);
+                map_error(queue!(self.buffer, SetUnderlineColor(color)))?;
+                underline_color = cell.underline_color;
+            }
+
+            if cell.hyperlink != hyperlink {
+                queue_hyperlink(&mut self.buffer, cell.hyperlink)?;
+                hyperlink = cell.hyperlink;
+            }
 
             map_error(queue!(self.buffer, Print(&cell.symbol)))?;
         }
 
+        // Close the last hyperlink so that it does not extend to text written afterwards
+        if hyperlink != Hyperlink::NONE {
+            queue_hyperlink(&mut self.buffer, Hyperlink::NONE)?;
+        }
+
         map_error(queue!(
             self.buffer,
             SetForegroundColor(CColor::Reset),
@@ -124,6 +150,41 @@ where
     fn flush(&mut self) -> io::Result<()> {
         self.buffer.flush()
     }
+
+    fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+}
+
+/// Queues an OSC 8 sequence linking the following text to the given hyperlink, or ending the
+/// current link for [`Hyperlink::NONE`].
+fn queue_hyperlink<W>(w: &mut W, hyperlink: Hyperlink) -> io::Result<()>
+where
+    W: Write,
+{
+    let url = hyperlink.url();
+    let url = url.as_deref().unwrap_or_default();
+    map_error(queue!(
+        w,
+        Print(format!("\x1b]8;;{}\x1b\\", escape_url(url)))
+    ))
+}
+
+/// Percent-encodes the control characters of the URL, which could otherwise end the OSC 8 sequence
+/// and be interpreted by the terminal.
+fn escape_url(url: &str) -> String {
+    let mut escaped = String::with_capacity(url.len());
+    for c in url.chars() {
+        if c.is_control() {
+            let mut bytes = [0; 4];
+            for byte in c.encode_utf8(&mut bytes).bytes() {
+                escaped.push_str(&format!("%{:02X}", byte));
+            }
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
 }
 
 fn map_error(error: crossterm::Result<()>) -> io::Result<()> {
@@ -181,8 +242,20 @@ impl ModifierDiff {
         if removed.contains(Modifier::ITALIC) {
             map_error(queue!(w, SetAttribute(CAttribute::NoItalic)))?;
         }
-        if removed.contains(Modifier::UNDERLINED) {
+        // Removing any kind of underline removes all of them, the remaining ones are added back
+        // below.
+        let underlines = Modifier::UNDERLINED
+            | Modifier::DOUBLE_UNDERLINED
+            | Modifier::CURLY_UNDERLINED
+            | Modifier::DOTTED_UNDERLINED
+            | Modifier::DASHED_UNDERLINED;
+        let mut added = self.to - self.from;
+        if removed.intersects(underlines) {
             map_error(queue!(w, SetAttribute(CAttribute::NoUnderline)))?;
+            added = added | (self.to & underlines);
+        }
+        if removed.contains(Modifier::OVERLINED) {
+            map_error(queue!(w, SetAttribute(CAttribute::NotOverLined)))?;
         }
         if removed.contains(Modifier::DIM) {
             map_error(queue!(w, SetAttribute(CAttribute::NormalIntensity)))?;
@@ -194,7 +267,6 @@ impl ModifierDiff {
             map_error(queue!(w, SetAttribute(CAttribute::NoBlink)))?;
         }
 
-        let added = self.to - self.from;
         if added.contains(Modifier::REVERSED) {
             map_error(queue!(w, SetAttribute(CAttribute::Reverse)))?;
         }
@@ -204,9 +276,23 @@ impl ModifierDiff {
         if added.contains(Modifier::ITALIC) {
             map_error(queue!(w, SetAttribute(CAttribute::Italic)))?;
         }
-        if added.contains(Modifier::UNDERLINED) {
+        if added.intersects(underlines) {
+            // Terminals that do not support the styled underlines fall back to a plain one
             map_error(queue!(w, SetAttribute(CAttribute::Underlined)))?;
         }
+        for (modifier, attribute) in [
+            (Modifier::DOUBLE_UNDERLINED, CAttribute::DoubleUnderlined),
+            (Modifier::CURLY_UNDERLINED, CAttribute::Undercurled),
+            (Modifier::DOTTED_UNDERLINED, CAttribute::Underdotted),
+            (Modifier::DASHED_UNDERLINED, CAttribute::Underdashed),
+        ] {
+            if added.contains(modifier) {
+                map_error(queue!(w, SetAttribute(attribute)))?;
+            }
+        }
+        if added.contains(Modifier::OVERLINED) {
+            map_error(queue!(w, SetAttribute(CAttribute::OverLined)))?;
+        }
         if added.contains(Modifier::DIM) {
             map_error(queue!(w, SetAttribute(CAttribute::Dim)))?;
         }
@@ -223,3 +309,68 @@ impl ModifierDiff {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+
+    fn queue_diff(from: Modifier, to: Modifier) -> String {
+        let mut output = Vec::new();
+        ModifierDiff { from, to }.queue(&mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn styled_underlines_fall_back_to_a_plain_underline() {
+        assert_eq!(
+            queue_diff(Modifier::EMPTY, Modifier::CURLY_UNDERLINED),
+            "\x1b[4m\x1b[4:3m"
+        );
+        assert_eq!(
+            queue_diff(
+                Modifier::UNDERLINED | Modifier::DOTTED_UNDERLINED,
+                Modifier::UNDERLINED
+            ),
+            "\x1b[24m\x1b[4m"
+        );
+        assert_eq!(queue_diff(Modifier::OVERLINED, Modifier::EMPTY), "\x1b[55m");
+    }
+
+    #[test]
+    fn hyperlinks_escape_control_characters() {
+        assert_eq!(
+            escape_url("https://example.com/\x1b]0;title\x07\u{9b}"),
+            "https://example.com/%1B]0;title%07%C2%9B"
+        );
+    }
+
+    #[test]
+    fn hyperlinks_are_closed_at_their_boundaries() {
+        let link = Hyperlink::new("https://example.com");
+        let mut linked = Cell::default();
+        linked
+            .set_symbol("a")
+            .set_style(Style::DEFAULT.hyperlink(link));
+        let mut plain = Cell::default();
+        plain.set_symbol("b");
+
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend
+            .draw(
+                [
+                    (0, 0, &linked),
+                    (1, 0, &linked),
+                    (2, 0, &plain),
+                    (0, 1, &linked),
+                ]
+                .into_iter(),
+            )
+            .unwrap();
+        let output = String::from_utf8(backend.buffer).unwrap();
+        assert_eq!(
+            output,
+            "\x1b[1;1H\x1b]8;;https://example.com\x1b\\aa\x1b]8;;\x1b\\b\x1b[2;1H\x1b]8;;https://example.com\x1b\\a\x1b]8;;\x1b\\\x1b[39m\x1b[49m\x1b[0m"
+        );
+    }
+}
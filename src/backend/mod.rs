@@ -2,6 +2,7 @@ use std::io;
 
 use crate::buffer::Cell;
 use crate::layout::Rect;
+use crate::style::ColorDepth;
 
 mod crossterm;
 pub use self::crossterm::CrosstermBackend;
@@ -20,4 +21,9 @@ pub trait Backend {
     fn clear(&mut self) -> Result<(), io::Error>;
     fn size(&self) -> Result<Rect, io::Error>;
     fn flush(&mut self) -> Result<(), io::Error>;
+    /// Returns the number of colors the backend is able to display. Colors of the drawn cells are
+    /// downgraded accordingly by the [`Terminal`](crate::Terminal).
+    fn color_depth(&self) -> ColorDepth {
+        ColorDepth::TrueColor
+    }
 }
@@ -2,6 +2,7 @@ use crate::{
     backend::Backend,
     buffer::{Buffer, Cell},
     layout::Rect,
+    style::ColorDepth,
 };
 use std::{fmt::Write, io};
 use unicode_width::UnicodeWidthStr;
@@ -14,6 +15,7 @@ pub struct TestBackend {
     height: u16,
     cursor: bool,
     pos: (u16, u16),
+    color_depth: ColorDepth,
 }
 
 /// Returns a string representation of the given buffer for debugging purpose.
@@ -53,6 +55,7 @@ impl TestBackend {
             buffer: Buffer::empty(Rect::new(0, 0, width, height)),
             cursor: false,
             pos: (0, 0),
+            color_depth: ColorDepth::TrueColor,
         }
     }
 
@@ -60,6 +63,10 @@ impl TestBackend {
         &self.buffer
     }
 
+    pub fn set_color_depth(&mut self, color_depth: ColorDepth) {
+        self.color_depth = color_depth;
+    }
+
     pub fn resize(&mut self, width: u16, height: u16) {
         self.buffer.resize(Rect::new(0, 0, width, height));
         self.width = width;
@@ -103,6 +110,65 @@ impl TestBackend {
         debug_info.push_str(&nice_diff);
         panic!("{}", debug_info);
     }
+
+    /// Asserts that the backend buffer is equal to the expected one.
+    ///
+    /// On mismatch, the expected and actual screens are shown side by side with the cells whose
+    /// symbols differ wrapped in `[` `]`, followed by the list of cells whose styles differ.
+    pub fn assert_buffer_eq(&self, expected: &Buffer) {
+        if expected.area != self.buffer.area {
+            panic!(
+                "Buffer areas are not equal: expected {:?}, got {:?}",
+                expected.area, self.buffer.area
+            );
+        }
+
+        let width = self.buffer.area.width as usize;
+        let mut symbols_differ = false;
+        let mut view = String::from("Expected | Got\n");
+        let mut style_diff = String::new();
+        let rows = expected
+            .content
+            .chunks(width)
+            .zip(self.buffer.content.chunks(width));
+        for (y, (expected_row, row)) in rows.enumerate() {
+            let mut expected_line = String::new();
+            let mut line = String::new();
+            for (x, (expected_cell, cell)) in expected_row.iter().zip(row).enumerate() {
+                if expected_cell.symbol == cell.symbol {
+                    expected_line.push_str(&expected_cell.symbol);
+                    line.push_str(&cell.symbol);
+                } else {
+                    symbols_differ = true;
+                    write!(&mut expected_line, "[{}]", expected_cell.symbol).unwrap();
+                    write!(&mut line, "[{}]", cell.symbol).unwrap();
+                }
+                if expected_cell.style() != cell.style() {
+                    writeln!(
+                        &mut style_diff,
+                        "({}, {}): expected {:?}, got {:?}",
+                        x,
+                        y,
+                        expected_cell.style(),
+                        cell.style()
+                    )
+                    .unwrap();
+                }
+            }
+            writeln!(&mut view, "{} | {}", expected_line, line).unwrap();
+        }
+
+        if !symbols_differ && style_diff.is_empty() {
+            return;
+        }
+        let mut debug_info = String::from("Buffers are not equal\n");
+        debug_info.push_str(&view);
+        if !style_diff.is_empty() {
+            debug_info.push_str("Style mismatches:\n");
+            debug_info.push_str(&style_diff);
+        }
+        panic!("{}", debug_info);
+    }
 }
 
 impl Backend for TestBackend {
@@ -148,4 +214,43 @@ impl Backend for TestBackend {
     fn flush(&mut self) -> Result<(), io::Error> {
         Ok(())
     }
+
+    fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{Color, Style};
+
+    #[test]
+    fn assert_buffer_eq_accepts_equal_buffers() {
+        let mut backend = TestBackend::new(4, 1);
+        backend
+            .buffer
+            .set_string(0, 0, "ab", Style::DEFAULT.fg(Color::Red));
+        let mut expected = Buffer::with_lines(vec!["ab  "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::DEFAULT.fg(Color::Red));
+        backend.assert_buffer_eq(&expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "a[b]cd | a[x]cd")]
+    fn assert_buffer_eq_highlights_mismatched_symbols() {
+        let mut backend = TestBackend::new(4, 1);
+        backend.buffer.set_string(0, 0, "axcd", Style::DEFAULT);
+        backend.assert_buffer_eq(&Buffer::with_lines(vec!["abcd"]));
+    }
+
+    #[test]
+    #[should_panic(expected = "(1, 0): expected Style")]
+    fn assert_buffer_eq_lists_style_mismatches() {
+        let mut backend = TestBackend::new(2, 1);
+        backend.buffer.set_string(0, 0, "ab", Style::DEFAULT);
+        let mut expected = Buffer::with_lines(vec!["ab"]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::DEFAULT.fg(Color::Red));
+        backend.assert_buffer_eq(&expected);
+    }
 }
@@ -1,9 +1,9 @@
 use crate::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Color, Gradient, Hyperlink, Modifier, Style},
     text::{Span, Spans},
 };
-use std::cmp::min;
+use std::{cmp::min, error::Error, fmt};
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
@@ -13,6 +13,8 @@ pub struct Cell {
     pub symbol: String,
     pub fg: Color,
     pub bg: Color,
+    pub underline_color: Color,
+    pub hyperlink: Hyperlink,
     pub modifier: Modifier,
 }
 
@@ -40,11 +42,21 @@ impl Cell {
     }
 
     pub fn set_style(&mut self, style: Style) -> &mut Cell {
+        let mix = |current: Color, color: Color, alpha: Option<u8>| match alpha {
+            Some(alpha) if alpha < u8::MAX => current.lerp(color, f64::from(alpha) / 255.0),
+            _ => color,
+        };
         if let Some(c) = style.fg {
-            self.fg = c;
+            self.fg = mix(self.fg, c, style.fg_alpha);
         }
         if let Some(c) = style.bg {
-            self.bg = c;
+            self.bg = mix(self.bg, c, style.bg_alpha);
+        }
+        if let Some(c) = style.underline_color {
+            self.underline_color = mix(self.underline_color, c, style.underline_alpha);
+        }
+        if let Some(link) = style.hyperlink {
+            self.hyperlink = link;
         }
         self.modifier = self
             .modifier
@@ -57,6 +69,8 @@ impl Cell {
         Style::DEFAULT
             .fg(self.fg)
             .bg(self.bg)
+            .underline_color(self.underline_color)
+            .hyperlink(self.hyperlink)
             .add_modifier(self.modifier)
     }
 
@@ -65,6 +79,8 @@ impl Cell {
         self.symbol.push(' ');
         self.fg = Color::Reset;
         self.bg = Color::Reset;
+        self.underline_color = Color::Reset;
+        self.hyperlink = Hyperlink::NONE;
         self.modifier = Modifier::EMPTY;
     }
 }
@@ -75,6 +91,8 @@ impl<'a> Default for Cell {
             symbol: " ".into(),
             fg: Color::Reset,
             bg: Color::Reset,
+            underline_color: Color::Reset,
+            hyperlink: Hyperlink::NONE,
             modifier: Modifier::EMPTY,
         }
     }
@@ -92,7 +110,7 @@ impl<'a> Default for Cell {
 /// ```
 /// use tui::buffer::{Buffer, Cell};
 /// use tui::layout::Rect;
-/// use tui::style::{Color, Style, Modifier};
+/// use tui::style::{Color, Hyperlink, Style, Modifier};
 ///
 /// let mut buf = Buffer::empty(Rect{x: 0, y: 0, width: 10, height: 5});
 /// buf.get_mut(0, 2).set_symbol("x");
@@ -102,6 +120,8 @@ impl<'a> Default for Cell {
 ///     symbol: String::from("r"),
 ///     fg: Color::Red,
 ///     bg: Color::White,
+///     underline_color: Color::Reset,
+///     hyperlink: Hyperlink::NONE,
 ///     modifier: Modifier::EMPTY
 /// });
 /// buf.get_mut(5, 0).set_char('x');
@@ -116,6 +136,41 @@ pub struct Buffer {
     pub content: Vec<Cell>,
 }
 
+/// An immutable copy of a [`Buffer`] taken with [`Buffer::snapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferSnapshot {
+    buffer: Buffer,
+}
+
+impl BufferSnapshot {
+    /// Returns the area covered by the snapshot
+    pub fn area(&self) -> &Rect {
+        &self.buffer.area
+    }
+}
+
+/// Error returned when restoring a [`BufferSnapshot`] into a buffer of a different size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotSizeMismatch {
+    pub snapshot_area: Rect,
+    pub buffer_area: Rect,
+}
+
+impl fmt::Display for SnapshotSizeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "snapshot of size {}x{} cannot be restored into a buffer of size {}x{}",
+            self.snapshot_area.width,
+            self.snapshot_area.height,
+            self.buffer_area.width,
+            self.buffer_area.height
+        )
+    }
+}
+
+impl Error for SnapshotSizeMismatch {}
+
 impl Buffer {
     /// Returns a Buffer with all cells set to the default one
     pub fn empty(area: Rect) -> Buffer {
@@ -353,6 +408,16 @@ impl Buffer {
         }
     }
 
+    /// Sets the background color of every cell of the area to the color of the gradient at its
+    /// position.
+    pub fn set_gradient(&mut self, area: Rect, gradient: &Gradient) {
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                self.get_mut(x, y).set_bg(gradient.color_in(area, x, y));
+            }
+        }
+    }
+
     /// Resize the buffer so that the mapped area matches the given area and that the buffer
     /// length is equal to area.width * area.height
     pub fn resize(&mut self, area: Rect) {
@@ -365,6 +430,44 @@ impl Buffer {
         self.area = area;
     }
 
+    /// Captures a copy of the buffer that can later be given to [`Buffer::restore_from`].
+    pub fn snapshot(&self) -> BufferSnapshot {
+        BufferSnapshot {
+            buffer: self.clone(),
+        }
+    }
+
+    /// Copies all the cells of the snapshot back into the buffer.
+    ///
+    /// Fails if the snapshot and the buffer do not have the same width and height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::Rect;
+    /// # use tui::style::Style;
+    /// let mut buffer = Buffer::with_lines(vec!["Hello"]);
+    /// let snapshot = buffer.snapshot();
+    /// buffer.set_string(0, 0, "World", Style::DEFAULT);
+    /// buffer.restore_from(&snapshot).unwrap();
+    /// assert_eq!(buffer, Buffer::with_lines(vec!["Hello"]));
+    ///
+    /// let mut smaller = Buffer::empty(Rect::new(0, 0, 4, 1));
+    /// assert!(smaller.restore_from(&snapshot).is_err());
+    /// ```
+    pub fn restore_from(&mut self, snapshot: &BufferSnapshot) -> Result<(), SnapshotSizeMismatch> {
+        let snapshot_area = snapshot.buffer.area;
+        if snapshot_area.width != self.area.width || snapshot_area.height != self.area.height {
+            return Err(SnapshotSizeMismatch {
+                snapshot_area,
+                buffer_area: self.area,
+            });
+        }
+        self.content.clone_from_slice(&snapshot.buffer.content);
+        Ok(())
+    }
+
     /// Reset all cells in the buffer
     pub fn reset(&mut self) {
         for c in &mut self.content {
@@ -467,6 +570,17 @@ mod tests {
         cell
     }
 
+    #[test]
+    fn cell_keeps_the_underline_color_of_its_style() {
+        let mut cell = Cell::default();
+        cell.set_style(Style::DEFAULT.underline_color(Color::Red));
+        assert_eq!(cell.underline_color, Color::Red);
+        cell.set_style(Style::DEFAULT.fg(Color::Blue));
+        assert_eq!(cell.style().underline_color, Some(Color::Red));
+        cell.reset();
+        assert_eq!(cell, Cell::default());
+    }
+
     #[test]
     fn it_translates_to_and_from_coordinates() {
         let rect = Rect::new(200, 100, 50, 80);
@@ -654,6 +768,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn buffer_diffing_hyperlinks() {
+        let prev = Buffer::with_lines(vec!["link"]);
+        let mut next = prev.clone();
+        next.set_style(
+            Rect::new(1, 0, 2, 1),
+            Style::DEFAULT.hyperlink("https://example.com"),
+        );
+
+        let diff = prev.diff(&next);
+        assert_eq!(diff, vec![(1, 0, next.get(1, 0)), (2, 0, next.get(2, 0))]);
+        // Removing the link redraws the cells so that the backend ends it
+        let diff = next.diff(&prev);
+        assert_eq!(diff, vec![(1, 0, prev.get(1, 0)), (2, 0, prev.get(2, 0))]);
+    }
+
     #[test]
     fn buffer_merge() {
         let mut one = Buffer::filled(
@@ -2,7 +2,7 @@ use std::cell::RefCell;
 use std::cmp::{max, min};
 use std::collections::HashMap;
 
-use cassowary::strength::{REQUIRED, WEAK};
+use cassowary::strength::{MEDIUM, REQUIRED, WEAK};
 use cassowary::WeightedRelation::*;
 use cassowary::{Constraint as CassowaryConstraint, Expression, Solver, Variable};
 
@@ -28,6 +28,14 @@ pub enum Constraint {
     Length(u16),
     Max(u16),
     Min(u16),
+    /// Shares the space left by the other constraints with the other `Fill` constraints of the
+    /// layout, proportionally to the given weight.
+    Fill(u16),
+    /// Takes the size of its content, then shares the space left by the other constraints,
+    /// `Fill` ones included, equally with the other `Fit` constraints. Only the widgets able to
+    /// measure their content, such as the columns of a [`Table`](crate::widgets::Table), give it
+    /// a size: the content of a [`Layout`] is empty.
+    Fit,
 }
 
 impl Constraint {
@@ -41,10 +49,32 @@ impl Constraint {
             Constraint::Length(l) => length.min(l),
             Constraint::Max(m) => length.min(m),
             Constraint::Min(m) => length.max(m),
+            Constraint::Fill(_) | Constraint::Fit => length,
         }
     }
 }
 
+/// Converts a length into a [`Constraint::Length`].
+impl From<u16> for Constraint {
+    fn from(length: u16) -> Constraint {
+        Constraint::Length(length)
+    }
+}
+
+/// Converts a `(numerator, denominator)` pair into a [`Constraint::Ratio`].
+impl From<(u32, u32)> for Constraint {
+    fn from((num, den): (u32, u32)) -> Constraint {
+        Constraint::Ratio(num, den)
+    }
+}
+
+/// Converts a fraction (e.g. `0.25`) into a [`Constraint::Percentage`].
+impl From<f32> for Constraint {
+    fn from(fraction: f32) -> Constraint {
+        Constraint::Percentage((fraction * 100.0).round() as u16)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Margin {
     pub vertical: u16,
@@ -58,6 +88,13 @@ pub enum Alignment {
     Right,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerticalAlignment {
+    Top,
+    Center,
+    Bottom,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Layout {
     direction: Direction,
@@ -66,8 +103,18 @@ pub struct Layout {
     /// Whether the last chunk of the computed layout should be expanded to fill the available
     /// space.
     expand_to_fill: bool,
+    /// Whether consecutive chunks overlap by one cell so that their borders are shared
+    collapse_borders: bool,
 }
 
+/// Strength of the constraint making `Fill` elements take all the available space. It is weaker
+/// than all the other constraints so that the fill elements only get the remaining space.
+const FILL: f64 = WEAK / 10.0;
+
+/// Strength of the constraint making `Fit` elements take all the available space, weaker than the
+/// one of `Fill` elements so that they only get the space left by the latter.
+const FIT: f64 = FILL / 10.0;
+
 thread_local! {
     static LAYOUT_CACHE: RefCell<HashMap<(Rect, Layout), Vec<Rect>>> = RefCell::new(HashMap::new());
 }
@@ -82,11 +129,40 @@ impl Default for Layout {
             },
             constraints: Vec::new(),
             expand_to_fill: true,
+            collapse_borders: false,
         }
     }
 }
 
 impl Layout {
+    /// Creates a layout in the given direction from anything that converts into constraints.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tui::layout::{Constraint, Direction, Layout, Rect};
+    /// let chunks = Layout::new(Direction::Horizontal, [0.25, 0.75]).split(Rect::new(0, 0, 20, 1));
+    /// assert_eq!(chunks, vec![Rect::new(0, 0, 5, 1), Rect::new(5, 0, 15, 1)]);
+    ///
+    /// let layout = Layout::new(Direction::Vertical, [3, 10]);
+    /// assert_eq!(
+    ///     layout,
+    ///     Layout::default()
+    ///         .direction(Direction::Vertical)
+    ///         .constraints([Constraint::Length(3), Constraint::Length(10)])
+    /// );
+    /// ```
+    pub fn new<I>(direction: Direction, constraints: I) -> Layout
+    where
+        I: IntoIterator,
+        I::Item: Into<Constraint>,
+    {
+        Layout {
+            direction,
+            constraints: constraints.into_iter().map(Into::into).collect(),
+            ..Layout::default()
+        }
+    }
+
     pub fn constraints<C>(mut self, constraints: C) -> Layout
     where
         C: Into<Vec<Constraint>>,
@@ -113,6 +189,26 @@ impl Layout {
         self
     }
 
+    /// Extends every chunk but the last one by one cell over the next chunk, so that the borders
+    /// of blocks rendered in consecutive chunks are drawn on the same line. See
+    /// [`Block::merge_borders`](crate::widgets::Block::merge_borders) to join them properly.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tui::layout::{Direction, Layout, Rect};
+    /// let chunks = Layout::new(Direction::Vertical, [2, 2, 2])
+    ///     .collapse_borders(true)
+    ///     .split(Rect::new(0, 0, 10, 6));
+    /// assert_eq!(
+    ///     chunks,
+    ///     vec![Rect::new(0, 0, 10, 3), Rect::new(0, 2, 10, 3), Rect::new(0, 4, 10, 2)]
+    /// );
+    /// ```
+    pub fn collapse_borders(mut self, collapse_borders: bool) -> Layout {
+        self.collapse_borders = collapse_borders;
+        self
+    }
+
     pub fn direction(mut self, direction: Direction) -> Layout {
         self.direction = direction;
         self
@@ -258,7 +354,11 @@ fn split(area: Rect, layout: &Layout) -> Vec<Rect> {
                             | (f64::from(dest_area.width) * f64::from(n) / f64::from(d))
                     }
                     Constraint::Min(v) => elements[i].width | GE(WEAK) | f64::from(v),
+                    Constraint::Fit => elements[i].width | EQ(FIT) | f64::from(dest_area.width),
                     Constraint::Max(v) => elements[i].width | LE(WEAK) | f64::from(v),
+                    Constraint::Fill(_) => {
+                        elements[i].width | EQ(FILL) | f64::from(dest_area.width)
+                    }
                 });
             }
         }
@@ -280,11 +380,47 @@ fn split(area: Rect, layout: &Layout) -> Vec<Rect> {
                             | (f64::from(dest_area.height) * f64::from(n) / f64::from(d))
                     }
                     Constraint::Min(v) => elements[i].height | GE(WEAK) | f64::from(v),
+                    Constraint::Fit => elements[i].height | EQ(FIT) | f64::from(dest_area.height),
                     Constraint::Max(v) => elements[i].height | LE(WEAK) | f64::from(v),
+                    Constraint::Fill(_) => {
+                        elements[i].height | EQ(FILL) | f64::from(dest_area.height)
+                    }
                 });
             }
         }
     }
+    // Share the remaining space between the fill elements according to their weights
+    let fills = layout
+        .constraints
+        .iter()
+        .zip(&elements)
+        .filter_map(|(constraint, element)| match *constraint {
+            Constraint::Fill(weight) => Some((f64::from(weight), element)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    for pair in fills.windows(2) {
+        let ((w0, e0), (w1, e1)) = (pair[0], pair[1]);
+        let (s0, s1) = match layout.direction {
+            Direction::Horizontal => (e0.width, e1.width),
+            Direction::Vertical => (e0.height, e1.height),
+        };
+        ccs.push((s0 * w1) | EQ(MEDIUM) | (s1 * w0));
+    }
+    // and the space they leave equally between the fit elements
+    let fits = layout
+        .constraints
+        .iter()
+        .zip(&elements)
+        .filter(|(constraint, _)| **constraint == Constraint::Fit)
+        .map(|(_, element)| match layout.direction {
+            Direction::Horizontal => element.width,
+            Direction::Vertical => element.height,
+        })
+        .collect::<Vec<_>>();
+    for pair in fits.windows(2) {
+        ccs.push(pair[0] | EQ(MEDIUM) | pair[1]);
+    }
     solver.add_constraints(&ccs).unwrap();
     for &(var, value) in solver.fetch_changes() {
         let (index, attr) = vars[&var];
@@ -323,6 +459,17 @@ fn split(area: Rect, layout: &Layout) -> Vec<Rect> {
             }
         }
     }
+
+    if layout.collapse_borders {
+        let count = results.len();
+        for rect in results.iter_mut().take(count.saturating_sub(1)) {
+            match layout.direction {
+                Direction::Vertical if rect.bottom() < dest_area.bottom() => rect.height += 1,
+                Direction::Horizontal if rect.right() < dest_area.right() => rect.width += 1,
+                _ => {}
+            }
+        }
+    }
     results
 }
 
@@ -427,11 +574,12 @@ impl Rect {
         }
     }
 
+    /// Returns the smallest `Rect` containing both `self` and `other`.
     pub fn union(self, other: Rect) -> Rect {
         let x1 = min(self.x, other.x);
         let y1 = min(self.y, other.y);
-        let x2 = max(self.x + self.width, other.x + other.width);
-        let y2 = max(self.y + self.height, other.y + other.height);
+        let x2 = max(self.right(), other.right());
+        let y2 = max(self.bottom(), other.bottom());
         Rect {
             x: x1,
             y: y1,
@@ -440,24 +588,36 @@ impl Rect {
         }
     }
 
-    pub fn intersection(self, other: Rect) -> Rect {
+    /// Returns the area shared by `self` and `other`, or `None` if they do not overlap.
+    pub fn intersection(self, other: Rect) -> Option<Rect> {
         let x1 = max(self.x, other.x);
         let y1 = max(self.y, other.y);
-        let x2 = min(self.x + self.width, other.x + other.width);
-        let y2 = min(self.y + self.height, other.y + other.height);
-        Rect {
+        let x2 = min(self.right(), other.right());
+        let y2 = min(self.bottom(), other.bottom());
+        if x2 <= x1 || y2 <= y1 {
+            return None;
+        }
+        Some(Rect {
             x: x1,
             y: y1,
             width: x2 - x1,
             height: y2 - y1,
-        }
+        })
     }
 
     pub fn intersects(self, other: Rect) -> bool {
-        self.x < other.x + other.width
-            && self.x + self.width > other.x
-            && self.y < other.y + other.height
-            && self.y + self.height > other.y
+        self.x < other.right()
+            && self.right() > other.x
+            && self.y < other.bottom()
+            && self.bottom() > other.y
+    }
+
+    /// Returns `true` if `other` lies entirely within `self`.
+    pub fn contains(self, other: Rect) -> bool {
+        self.x <= other.x
+            && self.y <= other.y
+            && self.right() >= other.right()
+            && self.bottom() >= other.bottom()
     }
 }
 
@@ -465,6 +625,72 @@ impl Rect {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_constraint_conversions() {
+        assert_eq!(Constraint::from(5), Constraint::Length(5));
+        assert_eq!(Constraint::from((1, 3)), Constraint::Ratio(1, 3));
+        assert_eq!(Constraint::from(0.333), Constraint::Percentage(33));
+        assert_eq!(
+            Layout::new(Direction::Horizontal, [(1, 2), (1, 2)]),
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        );
+    }
+
+    #[test]
+    fn test_fill_shares_the_remaining_space() {
+        let target = Rect::new(0, 0, 100, 1);
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(10),
+                Constraint::Fill(1),
+                Constraint::Percentage(30),
+                Constraint::Fill(2),
+            ])
+            .split(target);
+        let widths = chunks.iter().map(|r| r.width).collect::<Vec<_>>();
+        assert_eq!(widths, vec![10, 20, 30, 40]);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(4)])
+            .split(Rect::new(0, 0, 1, 10));
+        let heights = chunks.iter().map(|r| r.height).collect::<Vec<_>>();
+        assert_eq!(heights, vec![6, 4]);
+    }
+
+    #[test]
+    fn test_fit_shares_the_space_left_by_the_fill_constraints() {
+        let target = Rect::new(0, 0, 100, 1);
+        let widths = |constraints: Vec<Constraint>| {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(constraints)
+                .split(target)
+                .iter()
+                .map(|r| r.width)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            widths(vec![
+                Constraint::Fit,
+                Constraint::Length(20),
+                Constraint::Fit
+            ]),
+            vec![40, 20, 40]
+        );
+        assert_eq!(
+            widths(vec![
+                Constraint::Fit,
+                Constraint::Length(20),
+                Constraint::Fill(1)
+            ]),
+            vec![0, 20, 80]
+        );
+    }
+
     #[test]
     fn test_vertical_split_by_height() {
         let target = Rect {
@@ -533,4 +759,70 @@ mod tests {
         assert_eq!(rect.width, 300);
         assert_eq!(rect.height, 100);
     }
+
+    fn sample_rects() -> Vec<Rect> {
+        let mut rects = Vec::new();
+        for x in 0..4 {
+            for y in 0..4 {
+                for width in 0..4 {
+                    for height in 0..4 {
+                        rects.push(Rect::new(x, y, width, height));
+                    }
+                }
+            }
+        }
+        rects
+    }
+
+    #[test]
+    fn test_rect_intersection() {
+        let a = Rect::new(0, 0, 4, 4);
+        assert_eq!(
+            a.intersection(Rect::new(2, 1, 4, 4)),
+            Some(Rect::new(2, 1, 2, 3))
+        );
+        assert_eq!(
+            a.intersection(Rect::new(1, 1, 2, 2)),
+            Some(Rect::new(1, 1, 2, 2))
+        );
+        // Touching edges do not overlap
+        assert_eq!(a.intersection(Rect::new(4, 0, 2, 2)), None);
+        assert_eq!(a.intersection(Rect::new(10, 10, 2, 2)), None);
+    }
+
+    #[test]
+    fn test_rect_intersection_properties() {
+        let rects = sample_rects();
+        for &a in &rects {
+            for &b in &rects {
+                let i = a.intersection(b);
+                assert_eq!(i, b.intersection(a));
+                if let Some(i) = i {
+                    assert!(a.contains(i) && b.contains(i));
+                    assert!(i.area() > 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rect_union() {
+        let a = Rect::new(1, 2, 3, 4);
+        let b = Rect::new(5, 0, 2, 2);
+        assert_eq!(a.union(b), Rect::new(1, 0, 6, 6));
+        assert_eq!(a.union(a), a);
+    }
+
+    #[test]
+    fn test_rect_union_properties() {
+        let rects = sample_rects();
+        for &a in &rects {
+            for &b in &rects {
+                let u = a.union(b);
+                assert_eq!(u, b.union(a));
+                assert!(u.contains(a));
+                assert!(u.contains(b));
+            }
+        }
+    }
 }
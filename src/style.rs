@@ -1,7 +1,15 @@
 //! `style` contains the primitives used to control how your user interface will look.
 
+use crate::layout::{Direction, Rect};
 use core::fmt;
-use std::ops;
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    error::Error,
+    ops,
+    str::FromStr,
+    sync::{Arc, Mutex, MutexGuard, Once},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27,6 +35,692 @@ pub enum Color {
     Indexed(u8),
 }
 
+impl Color {
+    /// Creates a RGB color from a hue in degrees and a saturation and lightness between 0.0 and
+    /// 1.0. Out of range values are wrapped (hue) or clamped (saturation and lightness).
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::Color;
+    /// assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::Rgb(255, 0, 0));
+    /// assert_eq!(Color::from_hsl(210.0, 0.5, 0.25), Color::Rgb(32, 64, 96));
+    /// ```
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Color {
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        Color::from_hue_chroma(h, chroma, l - chroma / 2.0)
+    }
+
+    /// Creates a RGB color from a hue in degrees and a saturation and value between 0.0 and 1.0.
+    /// Out of range values are wrapped (hue) or clamped (saturation and value).
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::Color;
+    /// assert_eq!(Color::from_hsv(120.0, 1.0, 1.0), Color::Rgb(0, 255, 0));
+    /// assert_eq!(Color::from_hsv(30.0, 1.0, 1.0), Color::Rgb(255, 128, 0));
+    /// ```
+    pub fn from_hsv(h: f64, s: f64, v: f64) -> Color {
+        let s = s.clamp(0.0, 1.0);
+        let v = v.clamp(0.0, 1.0);
+        let chroma = v * s;
+        Color::from_hue_chroma(h, chroma, v - chroma)
+    }
+
+    fn from_hue_chroma(h: f64, chroma: f64, min: f64) -> Color {
+        let h = h.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let channel = |c: f64| ((c + min) * 255.0).round() as u8;
+        Color::Rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// Converts the color to the closest one that can be displayed with the given color depth.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::{Color, ColorDepth};
+    /// let orange = Color::Rgb(255, 136, 0);
+    /// assert_eq!(orange.downgrade(ColorDepth::TrueColor), orange);
+    /// assert_eq!(orange.downgrade(ColorDepth::Indexed256), Color::Indexed(208));
+    /// assert_eq!(orange.downgrade(ColorDepth::Ansi16), Color::LightYellow);
+    /// ```
+    pub fn downgrade(self, depth: ColorDepth) -> Color {
+        match (depth, self) {
+            (ColorDepth::TrueColor, _) => self,
+            (ColorDepth::Indexed256, Color::Rgb(r, g, b)) => {
+                Color::Indexed(Color::rgb_to_indexed(r, g, b))
+            }
+            (ColorDepth::Ansi16, Color::Indexed(i)) if i < 16 => ANSI_COLORS[i as usize].0,
+            (ColorDepth::Ansi16, Color::Indexed(i)) => {
+                let (r, g, b) = Color::indexed_to_rgb(i);
+                nearest_ansi_color(r, g, b)
+            }
+            (ColorDepth::Ansi16, Color::Rgb(r, g, b)) => nearest_ansi_color(r, g, b),
+            _ => self,
+        }
+    }
+
+    /// Interpolates linearly between two colors, `t` going from `0.0` (`self`) to `1.0`
+    /// (`other`).
+    ///
+    /// Named and indexed colors are converted to RGB using the default xterm palette. As
+    /// [`Color::Reset`] has no RGB value, it switches to the other color halfway.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::Color;
+    /// let black = Color::Rgb(0, 0, 0);
+    /// assert_eq!(black.lerp(Color::Rgb(200, 100, 50), 0.5), Color::Rgb(100, 50, 25));
+    /// assert_eq!(black.lerp(Color::White, 0.25), Color::Rgb(64, 64, 64));
+    /// assert_eq!(Color::Reset.lerp(Color::Red, 0.4), Color::Reset);
+    /// assert_eq!(Color::Reset.lerp(Color::Red, 0.6), Color::Red);
+    /// ```
+    pub fn lerp(self, other: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        match (self.rgb(), other.rgb()) {
+            _ if t == 0.0 => self,
+            _ if t == 1.0 => other,
+            (Some((r1, g1, b1)), Some((r2, g2, b2))) => {
+                let channel =
+                    |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+                Color::Rgb(channel(r1, r2), channel(g1, g2), channel(b1, b2))
+            }
+            _ if t < 0.5 => self,
+            _ => other,
+        }
+    }
+
+    /// Returns the foreground color, black or white, that is the most readable on top of `self`
+    /// used as background color, following the WCAG definition of the contrast ratio.
+    ///
+    /// Named and indexed colors are converted to RGB using the default xterm palette.
+    /// [`Color::Reset`] gives [`Color::Reset`] as the actual background color is unknown.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::Color;
+    /// assert_eq!(Color::Yellow.contrast_fg(), Color::Black);
+    /// assert_eq!(Color::Blue.contrast_fg(), Color::White);
+    /// assert_eq!(Color::Rgb(255, 136, 0).contrast_fg(), Color::Black);
+    /// assert_eq!(Color::Indexed(17).contrast_fg(), Color::White);
+    /// assert_eq!(Color::Reset.contrast_fg(), Color::Reset);
+    /// ```
+    pub fn contrast_fg(self) -> Color {
+        match self.rgb() {
+            // The contrast ratios with black and white are equal for a luminance of about 0.179
+            Some(rgb) if relative_luminance(rgb) > 0.179 => Color::Black,
+            Some(_) => Color::White,
+            None => Color::Reset,
+        }
+    }
+
+    /// Returns the RGB value of a color of the xterm 256 colors palette: the 16 named colors,
+    /// the 6x6x6 color cube and the 24 levels of gray.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::Color;
+    /// assert_eq!(Color::indexed_to_rgb(9), (255, 0, 0));
+    /// assert_eq!(Color::indexed_to_rgb(67), (95, 135, 175));
+    /// assert_eq!(Color::indexed_to_rgb(244), (128, 128, 128));
+    /// ```
+    pub fn indexed_to_rgb(i: u8) -> (u8, u8, u8) {
+        match i {
+            0..=15 => ANSI_COLORS[i as usize].1,
+            16..=231 => {
+                let i = i - 16;
+                (
+                    CUBE_LEVELS[(i / 36) as usize],
+                    CUBE_LEVELS[(i / 6 % 6) as usize],
+                    CUBE_LEVELS[(i % 6) as usize],
+                )
+            }
+            _ => {
+                let level = 8 + 10 * (i - 232);
+                (level, level, level)
+            }
+        }
+    }
+
+    /// Returns the index of the closest color of the xterm 256 colors palette, ignoring the first
+    /// 16 colors as their value depends on the terminal theme.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::Color;
+    /// assert_eq!(Color::rgb_to_indexed(255, 0, 0), 196);
+    /// assert_eq!(Color::rgb_to_indexed(90, 140, 170), 67);
+    /// assert_eq!(Color::rgb_to_indexed(130, 128, 126), 244);
+    /// ```
+    pub fn rgb_to_indexed(r: u8, g: u8, b: u8) -> u8 {
+        let level = |c: u8| {
+            (0..6)
+                .min_by_key(|&i| (i32::from(CUBE_LEVELS[i]) - i32::from(c)).abs())
+                .unwrap() as u8
+        };
+        let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+        let average = ((u16::from(r) + u16::from(g) + u16::from(b)) / 3) as u8;
+        let gray = 232 + (average.saturating_sub(3) / 10).min(23);
+        if distance(Color::indexed_to_rgb(gray), (r, g, b))
+            < distance(Color::indexed_to_rgb(cube), (r, g, b))
+        {
+            gray
+        } else {
+            cube
+        }
+    }
+
+    /// Returns the RGB color associated to one of the 148 CSS named colors (e.g.
+    /// `"rebeccapurple"`), case insensitive and ignoring `-`, `_` and spaces.
+    ///
+    /// Note that CSS colors differ from the named colors of the terminal palette: `"red"` is
+    /// `Color::Rgb(255, 0, 0)` and `"gray"` is `Color::Rgb(128, 128, 128)`.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::Color;
+    /// assert_eq!(Color::from_name("rebeccapurple"), Some(Color::Rgb(102, 51, 153)));
+    /// assert_eq!(Color::from_name("Light Goldenrod Yellow"), Some(Color::Rgb(250, 250, 210)));
+    /// assert_eq!(Color::from_name("red"), Some(Color::Rgb(255, 0, 0)));
+    /// assert_eq!(Color::from_name("reddish"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Color> {
+        let name = normalize_color_name(name);
+        CSS_COLORS
+            .binary_search_by_key(&name.as_str(), |(n, _)| *n)
+            .ok()
+            .map(|i| {
+                let (r, g, b) = CSS_COLORS[i].1;
+                Color::Rgb(r, g, b)
+            })
+    }
+
+    /// Returns the RGB value of the color in the default xterm palette.
+    fn rgb(self) -> Option<(u8, u8, u8)> {
+        match self {
+            Color::Reset => None,
+            Color::Rgb(r, g, b) => Some((r, g, b)),
+            Color::Indexed(i) => Some(Color::indexed_to_rgb(i)),
+            named => ANSI_COLORS
+                .iter()
+                .find(|(color, _)| *color == named)
+                .map(|(_, rgb)| *rgb),
+        }
+    }
+}
+
+/// Number of colors a terminal is able to display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorDepth {
+    /// 24-bit RGB colors
+    TrueColor,
+    /// The 256 colors of the xterm palette
+    Indexed256,
+    /// The 16 named colors
+    Ansi16,
+}
+
+impl ColorDepth {
+    /// Guesses the color depth of the terminal from the `COLORTERM` and `TERM` environment
+    /// variables.
+    pub fn detect() -> ColorDepth {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+        let term = std::env::var("TERM").unwrap_or_default();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Indexed256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+}
+
+/// Whether the terminal has a light or a dark background, used to resolve [`AdaptiveColor`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Background {
+    Dark,
+    Light,
+}
+
+thread_local! {
+    static CURRENT_BACKGROUND: Cell<Background> = Cell::new(Background::Dark);
+}
+
+impl Background {
+    /// Guesses the background of the terminal from the `COLORFGBG` environment variable set by
+    /// some terminals (e.g. `"15;0"` for white text on a black background), defaulting to
+    /// [`Background::Dark`].
+    pub fn detect() -> Background {
+        let colorfgbg = std::env::var("COLORFGBG").unwrap_or_default();
+        match colorfgbg.rsplit(';').next().map(str::parse::<u8>) {
+            Some(Ok(7)) | Some(Ok(9..=15)) => Background::Light,
+            _ => Background::Dark,
+        }
+    }
+
+    /// Returns the background used to resolve adaptive colors on the current thread,
+    /// [`Background::Dark`] unless changed with [`Background::set_current`].
+    pub fn current() -> Background {
+        CURRENT_BACKGROUND.with(Cell::get)
+    }
+
+    /// Sets the background used to resolve adaptive colors on the current thread, e.g. from
+    /// [`Background::detect`] or from a setting of the application.
+    pub fn set_current(background: Background) {
+        CURRENT_BACKGROUND.with(|current| current.set(background));
+    }
+}
+
+/// A pair of colors suited to light and dark terminal backgrounds.
+///
+/// The color matching the [`Background::current`] is picked when the adaptive color is converted
+/// into a [`Color`]. As widgets are built on every frame, the same definition follows the
+/// background of the terminal.
+///
+/// ## Examples
+///
+/// ```rust
+/// # use tui::style::{AdaptiveColor, Background, Color, Style};
+/// let accent = AdaptiveColor {
+///     light: Color::Blue,
+///     dark: Color::LightBlue,
+/// };
+/// Background::set_current(Background::Light);
+/// assert_eq!(Style::DEFAULT.fg(accent.into()), Style::DEFAULT.fg(Color::Blue));
+/// Background::set_current(Background::Dark);
+/// assert_eq!(accent.resolve(), Color::LightBlue);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdaptiveColor {
+    pub light: Color,
+    pub dark: Color,
+}
+
+impl AdaptiveColor {
+    /// Returns the color matching the current [`Background`].
+    pub fn resolve(self) -> Color {
+        match Background::current() {
+            Background::Light => self.light,
+            Background::Dark => self.dark,
+        }
+    }
+}
+
+impl From<AdaptiveColor> for Color {
+    fn from(color: AdaptiveColor) -> Color {
+        color.resolve()
+    }
+}
+
+/// The 16 named colors with their index and the RGB value of the default xterm palette.
+const ANSI_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (128, 0, 0)),
+    (Color::Green, (0, 128, 0)),
+    (Color::Yellow, (128, 128, 0)),
+    (Color::Blue, (0, 0, 128)),
+    (Color::Magenta, (128, 0, 128)),
+    (Color::Cyan, (0, 128, 128)),
+    (Color::Gray, (192, 192, 192)),
+    (Color::DarkGray, (128, 128, 128)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (0, 0, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Levels of each channel in the 6x6x6 color cube of the 256 colors palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The CSS named colors, sorted by name.
+const CSS_COLORS: [(&str, (u8, u8, u8)); 148] = [
+    ("aliceblue", (240, 248, 255)),
+    ("antiquewhite", (250, 235, 215)),
+    ("aqua", (0, 255, 255)),
+    ("aquamarine", (127, 255, 212)),
+    ("azure", (240, 255, 255)),
+    ("beige", (245, 245, 220)),
+    ("bisque", (255, 228, 196)),
+    ("black", (0, 0, 0)),
+    ("blanchedalmond", (255, 235, 205)),
+    ("blue", (0, 0, 255)),
+    ("blueviolet", (138, 43, 226)),
+    ("brown", (165, 42, 42)),
+    ("burlywood", (222, 184, 135)),
+    ("cadetblue", (95, 158, 160)),
+    ("chartreuse", (127, 255, 0)),
+    ("chocolate", (210, 105, 30)),
+    ("coral", (255, 127, 80)),
+    ("cornflowerblue", (100, 149, 237)),
+    ("cornsilk", (255, 248, 220)),
+    ("crimson", (220, 20, 60)),
+    ("cyan", (0, 255, 255)),
+    ("darkblue", (0, 0, 139)),
+    ("darkcyan", (0, 139, 139)),
+    ("darkgoldenrod", (184, 134, 11)),
+    ("darkgray", (169, 169, 169)),
+    ("darkgreen", (0, 100, 0)),
+    ("darkgrey", (169, 169, 169)),
+    ("darkkhaki", (189, 183, 107)),
+    ("darkmagenta", (139, 0, 139)),
+    ("darkolivegreen", (85, 107, 47)),
+    ("darkorange", (255, 140, 0)),
+    ("darkorchid", (153, 50, 204)),
+    ("darkred", (139, 0, 0)),
+    ("darksalmon", (233, 150, 122)),
+    ("darkseagreen", (143, 188, 143)),
+    ("darkslateblue", (72, 61, 139)),
+    ("darkslategray", (47, 79, 79)),
+    ("darkslategrey", (47, 79, 79)),
+    ("darkturquoise", (0, 206, 209)),
+    ("darkviolet", (148, 0, 211)),
+    ("deeppink", (255, 20, 147)),
+    ("deepskyblue", (0, 191, 255)),
+    ("dimgray", (105, 105, 105)),
+    ("dimgrey", (105, 105, 105)),
+    ("dodgerblue", (30, 144, 255)),
+    ("firebrick", (178, 34, 34)),
+    ("floralwhite", (255, 250, 240)),
+    ("forestgreen", (34, 139, 34)),
+    ("fuchsia", (255, 0, 255)),
+    ("gainsboro", (220, 220, 220)),
+    ("ghostwhite", (248, 248, 255)),
+    ("gold", (255, 215, 0)),
+    ("goldenrod", (218, 165, 32)),
+    ("gray", (128, 128, 128)),
+    ("green", (0, 128, 0)),
+    ("greenyellow", (173, 255, 47)),
+    ("grey", (128, 128, 128)),
+    ("honeydew", (240, 255, 240)),
+    ("hotpink", (255, 105, 180)),
+    ("indianred", (205, 92, 92)),
+    ("indigo", (75, 0, 130)),
+    ("ivory", (255, 255, 240)),
+    ("khaki", (240, 230, 140)),
+    ("lavender", (230, 230, 250)),
+    ("lavenderblush", (255, 240, 245)),
+    ("lawngreen", (124, 252, 0)),
+    ("lemonchiffon", (255, 250, 205)),
+    ("lightblue", (173, 216, 230)),
+    ("lightcoral", (240, 128, 128)),
+    ("lightcyan", (224, 255, 255)),
+    ("lightgoldenrodyellow", (250, 250, 210)),
+    ("lightgray", (211, 211, 211)),
+    ("lightgreen", (144, 238, 144)),
+    ("lightgrey", (211, 211, 211)),
+    ("lightpink", (255, 182, 193)),
+    ("lightsalmon", (255, 160, 122)),
+    ("lightseagreen", (32, 178, 170)),
+    ("lightskyblue", (135, 206, 250)),
+    ("lightslategray", (119, 136, 153)),
+    ("lightslategrey", (119, 136, 153)),
+    ("lightsteelblue", (176, 196, 222)),
+    ("lightyellow", (255, 255, 224)),
+    ("lime", (0, 255, 0)),
+    ("limegreen", (50, 205, 50)),
+    ("linen", (250, 240, 230)),
+    ("magenta", (255, 0, 255)),
+    ("maroon", (128, 0, 0)),
+    ("mediumaquamarine", (102, 205, 170)),
+    ("mediumblue", (0, 0, 205)),
+    ("mediumorchid", (186, 85, 211)),
+    ("mediumpurple", (147, 112, 219)),
+    ("mediumseagreen", (60, 179, 113)),
+    ("mediumslateblue", (123, 104, 238)),
+    ("mediumspringgreen", (0, 250, 154)),
+    ("mediumturquoise", (72, 209, 204)),
+    ("mediumvioletred", (199, 21, 133)),
+    ("midnightblue", (25, 25, 112)),
+    ("mintcream", (245, 255, 250)),
+    ("mistyrose", (255, 228, 225)),
+    ("moccasin", (255, 228, 181)),
+    ("navajowhite", (255, 222, 173)),
+    ("navy", (0, 0, 128)),
+    ("oldlace", (253, 245, 230)),
+    ("olive", (128, 128, 0)),
+    ("olivedrab", (107, 142, 35)),
+    ("orange", (255, 165, 0)),
+    ("orangered", (255, 69, 0)),
+    ("orchid", (218, 112, 214)),
+    ("palegoldenrod", (238, 232, 170)),
+    ("palegreen", (152, 251, 152)),
+    ("paleturquoise", (175, 238, 238)),
+    ("palevioletred", (219, 112, 147)),
+    ("papayawhip", (255, 239, 213)),
+    ("peachpuff", (255, 218, 185)),
+    ("peru", (205, 133, 63)),
+    ("pink", (255, 192, 203)),
+    ("plum", (221, 160, 221)),
+    ("powderblue", (176, 224, 230)),
+    ("purple", (128, 0, 128)),
+    ("rebeccapurple", (102, 51, 153)),
+    ("red", (255, 0, 0)),
+    ("rosybrown", (188, 143, 143)),
+    ("royalblue", (65, 105, 225)),
+    ("saddlebrown", (139, 69, 19)),
+    ("salmon", (250, 128, 114)),
+    ("sandybrown", (244, 164, 96)),
+    ("seagreen", (46, 139, 87)),
+    ("seashell", (255, 245, 238)),
+    ("sienna", (160, 82, 45)),
+    ("silver", (192, 192, 192)),
+    ("skyblue", (135, 206, 235)),
+    ("slateblue", (106, 90, 205)),
+    ("slategray", (112, 128, 144)),
+    ("slategrey", (112, 128, 144)),
+    ("snow", (255, 250, 250)),
+    ("springgreen", (0, 255, 127)),
+    ("steelblue", (70, 130, 180)),
+    ("tan", (210, 180, 140)),
+    ("teal", (0, 128, 128)),
+    ("thistle", (216, 191, 216)),
+    ("tomato", (255, 99, 71)),
+    ("turquoise", (64, 224, 208)),
+    ("violet", (238, 130, 238)),
+    ("wheat", (245, 222, 179)),
+    ("white", (255, 255, 255)),
+    ("whitesmoke", (245, 245, 245)),
+    ("yellow", (255, 255, 0)),
+    ("yellowgreen", (154, 205, 50)),
+];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+/// Returns the relative luminance of a sRGB color, as defined by WCAG.
+fn relative_luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    let linear = |c: u8| {
+        let c = f64::from(c) / 255.0;
+        if c <= 0.039_28 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
+fn nearest_ansi_color(r: u8, g: u8, b: u8) -> Color {
+    ANSI_COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map(|(color, _)| *color)
+        .unwrap()
+}
+
+fn normalize_color_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// Error returned when a string cannot be parsed into a [`Color`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid color: {:?}", self.input)
+    }
+}
+
+impl Error for ParseColorError {}
+
+/// Parses a color from one of the following formats:
+///
+/// * a named color, case insensitive and ignoring `-`, `_` and spaces (`"lightcyan"`,
+///   `"Light-Cyan"`, `"dark gray"`),
+/// * a CSS named color that is not the name of a terminal color (`"rebeccapurple"`), see
+///   [`Color::from_name`],
+/// * an hexadecimal RGB color (`"#ff8800"` or `"#f80"`),
+/// * a functional RGB color (`"rgb(255, 136, 0)"`),
+/// * a color index (`"42"`).
+///
+/// ## Examples
+///
+/// ```rust
+/// # use tui::style::Color;
+/// assert_eq!("#ff8800".parse(), Ok(Color::Rgb(255, 136, 0)));
+/// assert_eq!("rgb(255,136,0)".parse(), Ok(Color::Rgb(255, 136, 0)));
+/// assert_eq!("lightcyan".parse(), Ok(Color::LightCyan));
+/// assert_eq!("tomato".parse(), Ok(Color::Rgb(255, 99, 71)));
+/// assert_eq!("42".parse(), Ok(Color::Indexed(42)));
+/// assert!("#ff88".parse::<Color>().is_err());
+/// ```
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Color, ParseColorError> {
+        let err = || ParseColorError {
+            input: s.to_owned(),
+        };
+        let trimmed = s.trim();
+        if let Some(hex) = trimmed.strip_prefix('#') {
+            if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(err());
+            }
+            let channel = |i: usize, len: usize| {
+                let v = u8::from_str_radix(&hex[i * len..(i + 1) * len], 16).unwrap();
+                if len == 1 {
+                    v * 17
+                } else {
+                    v
+                }
+            };
+            return match hex.len() {
+                3 => Ok(Color::Rgb(channel(0, 1), channel(1, 1), channel(2, 1))),
+                6 => Ok(Color::Rgb(channel(0, 2), channel(1, 2), channel(2, 2))),
+                _ => Err(err()),
+            };
+        }
+        if let Some(args) = trimmed
+            .strip_prefix("rgb(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            let channels = args
+                .split(',')
+                .map(|c| c.trim().parse::<u8>())
+                .collect::<Result<Vec<u8>, _>>()
+                .map_err(|_| err())?;
+            return match channels[..] {
+                [r, g, b] => Ok(Color::Rgb(r, g, b)),
+                _ => Err(err()),
+            };
+        }
+        if let Ok(index) = trimmed.parse::<u8>() {
+            return Ok(Color::Indexed(index));
+        }
+        let name = normalize_color_name(trimmed);
+        let color = match name.as_str() {
+            "reset" => Color::Reset,
+            "black" => Color::Black,
+            "red" => Color::Red,
+            "green" => Color::Green,
+            "yellow" => Color::Yellow,
+            "blue" => Color::Blue,
+            "magenta" => Color::Magenta,
+            "cyan" => Color::Cyan,
+            "gray" | "grey" => Color::Gray,
+            "darkgray" | "darkgrey" => Color::DarkGray,
+            "lightred" => Color::LightRed,
+            "lightgreen" => Color::LightGreen,
+            "lightyellow" => Color::LightYellow,
+            "lightblue" => Color::LightBlue,
+            "lightmagenta" => Color::LightMagenta,
+            "lightcyan" => Color::LightCyan,
+            "white" => Color::White,
+            _ => return Color::from_name(&name).ok_or_else(err),
+        };
+        Ok(color)
+    }
+}
+
+/// Formats the color so that it can be parsed back with [`str::parse`].
+///
+/// Named colors are written in lower case, RGB colors in hexadecimal and indexed colors as a
+/// number.
+///
+/// ## Examples
+///
+/// ```rust
+/// # use tui::style::Color;
+/// assert_eq!(Color::LightCyan.to_string(), "lightcyan");
+/// assert_eq!(Color::Rgb(255, 136, 0).to_string(), "#ff8800");
+/// assert_eq!(Color::Indexed(42).to_string(), "42");
+/// ```
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Color::Reset => f.write_str("reset"),
+            Color::Black => f.write_str("black"),
+            Color::Red => f.write_str("red"),
+            Color::Green => f.write_str("green"),
+            Color::Yellow => f.write_str("yellow"),
+            Color::Blue => f.write_str("blue"),
+            Color::Magenta => f.write_str("magenta"),
+            Color::Cyan => f.write_str("cyan"),
+            Color::Gray => f.write_str("gray"),
+            Color::DarkGray => f.write_str("darkgray"),
+            Color::LightRed => f.write_str("lightred"),
+            Color::LightGreen => f.write_str("lightgreen"),
+            Color::LightYellow => f.write_str("lightyellow"),
+            Color::LightBlue => f.write_str("lightblue"),
+            Color::LightMagenta => f.write_str("lightmagenta"),
+            Color::LightCyan => f.write_str("lightcyan"),
+            Color::White => f.write_str("white"),
+            Color::Rgb(r, g, b) => write!(f, "#{:02x}{:02x}{:02x}", r, g, b),
+            Color::Indexed(i) => write!(f, "{}", i),
+        }
+    }
+}
+
 /*  Modifier changes the way a piece of text is displayed.
  *
  *  They are bitflags so they can easily be composed.
@@ -111,6 +805,41 @@ impl fmt::Debug for Modifier {
             first = false;
             f.write_str("CROSSED_OUT")?;
         }
+        if Modifier::DOUBLE_UNDERLINED.contains(*self) {
+            if !first {
+                f.write_str(" | ")?;
+            }
+            first = false;
+            f.write_str("DOUBLE_UNDERLINED")?;
+        }
+        if Modifier::CURLY_UNDERLINED.contains(*self) {
+            if !first {
+                f.write_str(" | ")?;
+            }
+            first = false;
+            f.write_str("CURLY_UNDERLINED")?;
+        }
+        if Modifier::DOTTED_UNDERLINED.contains(*self) {
+            if !first {
+                f.write_str(" | ")?;
+            }
+            first = false;
+            f.write_str("DOTTED_UNDERLINED")?;
+        }
+        if Modifier::DASHED_UNDERLINED.contains(*self) {
+            if !first {
+                f.write_str(" | ")?;
+            }
+            first = false;
+            f.write_str("DASHED_UNDERLINED")?;
+        }
+        if Modifier::OVERLINED.contains(*self) {
+            if !first {
+                f.write_str(" | ")?;
+            }
+            first = false;
+            f.write_str("OVERLINED")?;
+        }
         let extra_bits = self.bits & !Modifier::ALL.bits();
         if extra_bits != 0 {
             if !first {
@@ -156,13 +885,32 @@ impl Modifier {
     pub const CROSSED_OUT: Modifier = Modifier {
         bits: 0b0001_0000_0000,
     };
+    /// Falls back to [`Modifier::UNDERLINED`] on terminals that do not support it.
+    pub const DOUBLE_UNDERLINED: Modifier = Modifier {
+        bits: 0b0010_0000_0000,
+    };
+    /// Falls back to [`Modifier::UNDERLINED`] on terminals that do not support it.
+    pub const CURLY_UNDERLINED: Modifier = Modifier {
+        bits: 0b0100_0000_0000,
+    };
+    /// Falls back to [`Modifier::UNDERLINED`] on terminals that do not support it.
+    pub const DOTTED_UNDERLINED: Modifier = Modifier {
+        bits: 0b1000_0000_0000,
+    };
+    /// Falls back to [`Modifier::UNDERLINED`] on terminals that do not support it.
+    pub const DASHED_UNDERLINED: Modifier = Modifier {
+        bits: 0b0001_0000_0000_0000,
+    };
+    pub const OVERLINED: Modifier = Modifier {
+        bits: 0b0010_0000_0000_0000,
+    };
 
     ///   Returns an empty set of flags.
     pub const EMPTY: Modifier = Modifier { bits: 0 };
 
     ///   Returns the set containing all flags.
     pub const ALL: Modifier = Modifier {
-        bits: 0b0001_1111_1111,
+        bits: 0b0011_1111_1111_1111,
     };
 
     ///   Returns the raw value of the flags currently stored.
@@ -404,6 +1152,222 @@ impl ops::Not for Modifier {
     }
 }
 
+/// Names of the modifiers as used by the textual representation of a [`Style`].
+const MODIFIER_NAMES: [(Modifier, &str); 14] = [
+    (Modifier::BOLD, "bold"),
+    (Modifier::DIM, "dim"),
+    (Modifier::ITALIC, "italic"),
+    (Modifier::UNDERLINED, "underlined"),
+    (Modifier::SLOW_BLINK, "slow_blink"),
+    (Modifier::RAPID_BLINK, "rapid_blink"),
+    (Modifier::REVERSED, "reversed"),
+    (Modifier::HIDDEN, "hidden"),
+    (Modifier::CROSSED_OUT, "crossed_out"),
+    (Modifier::DOUBLE_UNDERLINED, "double_underlined"),
+    (Modifier::CURLY_UNDERLINED, "curly_underlined"),
+    (Modifier::DOTTED_UNDERLINED, "dotted_underlined"),
+    (Modifier::DASHED_UNDERLINED, "dashed_underlined"),
+    (Modifier::OVERLINED, "overlined"),
+];
+
+fn modifier_from_name(name: &str) -> Option<Modifier> {
+    let name = name.replace('-', "_").to_lowercase();
+    MODIFIER_NAMES
+        .iter()
+        .find(|(_, n)| *n == name)
+        .map(|(m, _)| *m)
+}
+
+/// Error returned when a string cannot be parsed into a [`Modifier`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseModifierError {
+    input: String,
+}
+
+impl fmt::Display for ParseModifierError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid modifier: {:?}", self.input)
+    }
+}
+
+impl Error for ParseModifierError {}
+
+/// Parses a set of modifiers from their names separated by `|`, case insensitive and accepting
+/// `-` in place of `_`. An empty string is parsed as [`Modifier::EMPTY`].
+///
+/// ## Examples
+///
+/// ```rust
+/// # use tui::style::Modifier;
+/// assert_eq!("bold|italic".parse(), Ok(Modifier::BOLD | Modifier::ITALIC));
+/// assert_eq!("Crossed-Out | DIM".parse(), Ok(Modifier::CROSSED_OUT | Modifier::DIM));
+/// assert_eq!("".parse(), Ok(Modifier::EMPTY));
+/// assert!("bold|".parse::<Modifier>().is_err());
+/// ```
+impl FromStr for Modifier {
+    type Err = ParseModifierError;
+
+    fn from_str(s: &str) -> Result<Modifier, ParseModifierError> {
+        if s.trim().is_empty() {
+            return Ok(Modifier::EMPTY);
+        }
+        s.split('|')
+            .map(|name| modifier_from_name(name.trim()))
+            .try_fold(Modifier::EMPTY, |acc, m| m.map(|m| acc | m))
+            .ok_or_else(|| ParseModifierError {
+                input: s.to_owned(),
+            })
+    }
+}
+
+/// Formats the modifiers so that they can be parsed back with [`str::parse`].
+///
+/// ## Examples
+///
+/// ```rust
+/// # use tui::style::Modifier;
+/// assert_eq!((Modifier::BOLD | Modifier::ITALIC).to_string(), "bold|italic");
+/// assert_eq!(Modifier::EMPTY.to_string(), "");
+/// ```
+impl fmt::Display for Modifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names = MODIFIER_NAMES
+            .iter()
+            .filter(|(m, _)| self.contains(*m))
+            .map(|(_, name)| *name)
+            .collect::<Vec<&str>>();
+        f.write_str(&names.join("|"))
+    }
+}
+
+/// The target of a hyperlink, displayed as clickable text by terminals supporting OSC 8
+/// hyperlinks.
+///
+/// Hyperlinks are interned so that they can be part of a [`Style`], which is `Copy`. The URLs of
+/// the last [`MAX_HYPERLINKS`] distinct hyperlinks are kept for the whole process: the hyperlinks
+/// whose URL was forgotten since they were created no longer link anything, and never link
+/// another URL.
+/// [`Hyperlink::NONE`] plays the role [`Color::Reset`] plays for colors and removes the link of
+/// the styled cells.
+///
+/// ## Examples
+///
+/// ```rust
+/// # use tui::style::{Hyperlink, Style};
+/// let link = Hyperlink::new("https://example.com");
+/// assert_eq!(link, Hyperlink::new("https://example.com"));
+/// assert_eq!(link.url().as_deref(), Some("https://example.com"));
+/// assert_eq!(Hyperlink::NONE.url(), None);
+///
+/// let style = Style::DEFAULT.hyperlink("https://example.com");
+/// assert_eq!(style.hyperlink, Some(link));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hyperlink {
+    /// Number of the URL among the URLs interned by the process, from 1 so that 0 is the absence
+    /// of hyperlink
+    id: u64,
+}
+
+/// Number of distinct URLs kept for the hyperlinks
+pub const MAX_HYPERLINKS: usize = 4096;
+
+/// URLs of the hyperlinks, the URL numbered `id` being kept at position `(id - 1) %
+/// MAX_HYPERLINKS` until replaced by the URL numbered `id + MAX_HYPERLINKS`
+#[derive(Default)]
+struct Hyperlinks {
+    /// URL and number of each position
+    urls: Vec<(Arc<str>, u64)>,
+    ids: HashMap<Arc<str>, u64>,
+    last_id: u64,
+}
+
+impl Hyperlinks {
+    fn intern(&mut self, url: &str) -> Hyperlink {
+        if let Some(id) = self.ids.get(url) {
+            return Hyperlink { id: *id };
+        }
+        let url: Arc<str> = Arc::from(url);
+        self.last_id += 1;
+        let id = self.last_id;
+        let index = ((id - 1) % MAX_HYPERLINKS as u64) as usize;
+        if index < self.urls.len() {
+            let (old_url, _) = std::mem::replace(&mut self.urls[index], (url.clone(), id));
+            self.ids.remove(&old_url);
+        } else {
+            self.urls.push((url.clone(), id));
+        }
+        self.ids.insert(url, id);
+        Hyperlink { id }
+    }
+
+    fn url(&self, hyperlink: Hyperlink) -> Option<Arc<str>> {
+        let index = (hyperlink.id.checked_sub(1)? % MAX_HYPERLINKS as u64) as usize;
+        match self.urls.get(index) {
+            Some((url, id)) if *id == hyperlink.id => Some(url.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the hyperlinks of the process.
+fn hyperlinks() -> MutexGuard<'static, Hyperlinks> {
+    static INIT: Once = Once::new();
+    static mut HYPERLINKS: Option<Mutex<Hyperlinks>> = None;
+    // SAFETY: `HYPERLINKS` is only written once, before any read, synchronized by `INIT`
+    let hyperlinks = unsafe {
+        INIT.call_once(|| HYPERLINKS = Some(Mutex::new(Hyperlinks::default())));
+        (*std::ptr::addr_of!(HYPERLINKS)).as_ref()
+    };
+    match hyperlinks.expect("hyperlinks are initialized").lock() {
+        Ok(guard) => guard,
+        // The hyperlinks are left consistent whenever a lock is released
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+impl Hyperlink {
+    /// The absence of hyperlink.
+    pub const NONE: Hyperlink = Hyperlink { id: 0 };
+
+    /// Returns the hyperlink pointing to the given URL. An empty URL gives [`Hyperlink::NONE`].
+    pub fn new(url: &str) -> Hyperlink {
+        if url.is_empty() {
+            return Hyperlink::NONE;
+        }
+        hyperlinks().intern(url)
+    }
+
+    /// Returns the URL of the hyperlink, or `None` for [`Hyperlink::NONE`] and the hyperlinks
+    /// whose URL was forgotten.
+    pub fn url(&self) -> Option<Arc<str>> {
+        if *self == Hyperlink::NONE {
+            return None;
+        }
+        hyperlinks().url(*self)
+    }
+}
+
+impl fmt::Debug for Hyperlink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Hyperlink")
+            .field("url", &self.url().as_deref().unwrap_or_default())
+            .finish()
+    }
+}
+
+impl Default for Hyperlink {
+    fn default() -> Hyperlink {
+        Hyperlink::NONE
+    }
+}
+
+impl<'a> From<&'a str> for Hyperlink {
+    fn from(url: &'a str) -> Hyperlink {
+        Hyperlink::new(url)
+    }
+}
+
 /// Style let you control the main characteristics of the displayed elements.
 ///
 /// ```rust
@@ -419,7 +1383,7 @@ impl ops::Not for Modifier {
 /// just S3.
 ///
 /// ```rust
-/// # use tui::style::{Color, Modifier, Style};
+/// # use tui::style::{Color, Hyperlink, Modifier, Style};
 /// # use tui::buffer::Buffer;
 /// # use tui::layout::Rect;
 /// let styles = [
@@ -435,6 +1399,11 @@ impl ops::Not for Modifier {
 ///     Style {
 ///         fg: Some(Color::Yellow),
 ///         bg: Some(Color::Red),
+///         underline_color: Some(Color::Reset),
+///         hyperlink: Some(Hyperlink::NONE),
+///         fg_alpha: None,
+///         bg_alpha: None,
+///         underline_alpha: None,
 ///         add_modifier: Modifier::BOLD,
 ///         sub_modifier: Modifier::EMPTY,
 ///     },
@@ -446,7 +1415,7 @@ impl ops::Not for Modifier {
 /// reset all properties until that point use [`Style::reset`].
 ///
 /// ```
-/// # use tui::style::{Color, Modifier, Style};
+/// # use tui::style::{Color, Hyperlink, Modifier, Style};
 /// # use tui::buffer::Buffer;
 /// # use tui::layout::Rect;
 /// let styles = [
@@ -461,6 +1430,11 @@ impl ops::Not for Modifier {
 ///     Style {
 ///         fg: Some(Color::Yellow),
 ///         bg: Some(Color::Reset),
+///         underline_color: Some(Color::Reset),
+///         hyperlink: Some(Hyperlink::NONE),
+///         fg_alpha: None,
+///         bg_alpha: None,
+///         underline_alpha: None,
 ///         add_modifier: Modifier::EMPTY,
 ///         sub_modifier: Modifier::EMPTY,
 ///     },
@@ -468,10 +1442,17 @@ impl ops::Not for Modifier {
 /// );
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     pub fg: Option<Color>,
     pub bg: Option<Color>,
+    pub underline_color: Option<Color>,
+    pub hyperlink: Option<Hyperlink>,
+    /// Opacity of the foreground color, see [`Style::blend`]
+    pub fg_alpha: Option<u8>,
+    /// Opacity of the background color, see [`Style::blend`]
+    pub bg_alpha: Option<u8>,
+    /// Opacity of the underline color, see [`Style::blend`]
+    pub underline_alpha: Option<u8>,
     pub add_modifier: Modifier,
     pub sub_modifier: Modifier,
 }
@@ -480,6 +1461,11 @@ impl Style {
     pub const DEFAULT: Style = Style {
         fg: None,
         bg: None,
+        underline_color: None,
+        hyperlink: None,
+        fg_alpha: None,
+        bg_alpha: None,
+        underline_alpha: None,
         add_modifier: Modifier::EMPTY,
         sub_modifier: Modifier::EMPTY,
     };
@@ -488,6 +1474,11 @@ impl Style {
     pub const RESET: Style = Style {
         fg: Some(Color::Reset),
         bg: Some(Color::Reset),
+        underline_color: Some(Color::Reset),
+        hyperlink: Some(Hyperlink::NONE),
+        fg_alpha: Some(u8::MAX),
+        bg_alpha: Some(u8::MAX),
+        underline_alpha: Some(u8::MAX),
         add_modifier: Modifier::EMPTY,
         sub_modifier: Modifier::ALL,
     };
@@ -526,6 +1517,122 @@ impl Style {
         }
     }
 
+    /// Clears the foreground color so that it is inherited when the style is applied.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::{Color, Style};
+    /// let style = Style::DEFAULT.fg(Color::Blue).bg(Color::Red).remove_fg();
+    /// assert_eq!(style, Style::DEFAULT.bg(Color::Red));
+    /// ```
+    pub fn remove_fg(self) -> Style {
+        Style { fg: None, ..self }
+    }
+
+    /// Clears the background color so that it is inherited when the style is applied.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::{Color, Style};
+    /// let style = Style::DEFAULT.fg(Color::Blue).bg(Color::Red).remove_bg();
+    /// assert_eq!(style, Style::DEFAULT.fg(Color::Blue));
+    /// ```
+    pub fn remove_bg(self) -> Style {
+        Style { bg: None, ..self }
+    }
+
+    /// Changes the color of the underline.
+    ///
+    /// The underline itself is enabled with [`Modifier::UNDERLINED`]. Terminals that do not
+    /// support colored underlines use the foreground color instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::{Color, Modifier, Style};
+    /// let style = Style::DEFAULT
+    ///     .add_modifier(Modifier::UNDERLINED)
+    ///     .underline_color(Color::Red);
+    /// assert_eq!(style.underline_color, Some(Color::Red));
+    /// ```
+    pub fn underline_color(self, color: Color) -> Style {
+        Style {
+            underline_color: Some(color),
+            ..self
+        }
+    }
+
+    /// Sets the foreground color to the most readable one on top of the background color, see
+    /// [`Color::contrast_fg`]. Nothing changes if the background color is not set.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::{Color, Style};
+    /// let style = Style::DEFAULT.bg(Color::LightYellow).auto_fg();
+    /// assert_eq!(style.fg, Some(Color::Black));
+    /// assert_eq!(Style::DEFAULT.auto_fg(), Style::DEFAULT);
+    /// ```
+    pub fn auto_fg(self) -> Style {
+        match self.bg {
+            Some(bg) => self.fg(bg.contrast_fg()),
+            None => self,
+        }
+    }
+
+    /// Changes the hyperlink target.
+    ///
+    /// Use [`Hyperlink::NONE`] to remove the link of the styled cells.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::{Hyperlink, Style};
+    /// let style = Style::DEFAULT.hyperlink("https://example.com");
+    /// let diff = Style::DEFAULT.hyperlink(Hyperlink::NONE);
+    /// assert_eq!(style.patch(diff), Style::DEFAULT.hyperlink(Hyperlink::NONE));
+    /// ```
+    pub fn hyperlink<L>(self, link: L) -> Style
+    where
+        L: Into<Hyperlink>,
+    {
+        Style {
+            hyperlink: Some(link.into()),
+            ..self
+        }
+    }
+
+    /// Makes the colors of the style translucent: when applied to a cell, they are mixed with the
+    /// colors already in the cell, `0` keeping the cell colors and `255` replacing them.
+    ///
+    /// This lets overlays such as popups or shadows tint what is underneath. As the actual value of
+    /// [`Color::Reset`] is unknown, blending with it switches to the other color halfway. Each
+    /// color keeps its own alpha: when the style is [patched](Style::patch) with a style setting a
+    /// color, the alpha of that color is replaced by the one of the latter, the color being opaque
+    /// if it has no alpha.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::Rect;
+    /// # use tui::style::{Color, Style};
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+    /// buffer.set_style(buffer.area, Style::DEFAULT.bg(Color::Rgb(200, 200, 200)));
+    /// buffer.set_style(buffer.area, Style::DEFAULT.bg(Color::Rgb(0, 0, 0)).blend(64));
+    /// assert_eq!(buffer.get(0, 0).bg, Color::Rgb(150, 150, 150));
+    /// ```
+    pub fn blend(self, alpha: u8) -> Style {
+        Style {
+            fg_alpha: Some(alpha),
+            bg_alpha: Some(alpha),
+            underline_alpha: Some(alpha),
+            ..self
+        }
+    }
+
     /// Changes the text emphasis.
     ///
     /// When applied, it adds the given modifier to the `Style` modifiers.
@@ -587,6 +1694,16 @@ impl Style {
         Style {
             fg: other.fg.or(self.fg),
             bg: other.bg.or(self.bg),
+            underline_color: other.underline_color.or(self.underline_color),
+            hyperlink: other.hyperlink.or(self.hyperlink),
+            // The colors set by `other` are opaque unless it sets their alpha too
+            fg_alpha: patch_alpha(self.fg_alpha, other.fg, other.fg_alpha),
+            bg_alpha: patch_alpha(self.bg_alpha, other.bg, other.bg_alpha),
+            underline_alpha: patch_alpha(
+                self.underline_alpha,
+                other.underline_color,
+                other.underline_alpha,
+            ),
             add_modifier: self
                 .add_modifier
                 .remove(other.sub_modifier)
@@ -597,17 +1714,760 @@ impl Style {
                 .insert(other.sub_modifier),
         }
     }
+
+    /// Patches the style with the given one if there is one.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use tui::style::{Color, Style};
+    /// let style = Style::DEFAULT.fg(Color::Yellow);
+    /// assert_eq!(style.patch_option(None), style);
+    /// assert_eq!(
+    ///     style.patch_option(Some(Style::DEFAULT.bg(Color::Red))),
+    ///     Style::DEFAULT.fg(Color::Yellow).bg(Color::Red)
+    /// );
+    /// ```
+    pub fn patch_option(self, other: Option<Style>) -> Style {
+        match other {
+            Some(other) => self.patch(other),
+            None => self,
+        }
+    }
+
+    /// Interpolates between two styles, `t` going from `0.0` (`self`) to `1.0` (`other`).
+    ///
+    /// Colors set in both styles are interpolated with [`Color::lerp`]. Colors set in only one of
+    /// them, as well as hyperlinks, opacities and modifiers, switch from one style to the other halfway.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::style::{Color, Modifier, Style};
+    /// let from = Style::DEFAULT.fg(Color::Rgb(0, 0, 0)).bg(Color::Black);
+    /// let to = Style::DEFAULT
+    ///     .fg(Color::Rgb(100, 200, 0))
+    ///     .add_modifier(Modifier::BOLD);
+    /// assert_eq!(
+    ///     from.lerp(to, 0.25),
+    ///     Style::DEFAULT.fg(Color::Rgb(25, 50, 0)).bg(Color::Black)
+    /// );
+    /// assert_eq!(
+    ///     from.lerp(to, 0.75),
+    ///     Style::DEFAULT
+    ///         .fg(Color::Rgb(75, 150, 0))
+    ///         .add_modifier(Modifier::BOLD)
+    /// );
+    /// ```
+    pub fn lerp(self, other: Style, t: f64) -> Style {
+        let t = t.clamp(0.0, 1.0);
+        let color = |a: Option<Color>, b: Option<Color>| match (a, b) {
+            (Some(a), Some(b)) => Some(a.lerp(b, t)),
+            _ if t < 0.5 => a,
+            _ => b,
+        };
+        let switched = if t < 0.5 { self } else { other };
+        Style {
+            fg: color(self.fg, other.fg),
+            bg: color(self.bg, other.bg),
+            underline_color: color(self.underline_color, other.underline_color),
+            hyperlink: switched.hyperlink,
+            fg_alpha: switched.fg_alpha,
+            bg_alpha: switched.bg_alpha,
+            underline_alpha: switched.underline_alpha,
+            add_modifier: switched.add_modifier,
+            sub_modifier: switched.sub_modifier,
+        }
+    }
+}
+
+/// Returns the alpha of a color after patching a style with another one possibly setting the color
+/// and its alpha.
+fn patch_alpha(
+    alpha: Option<u8>,
+    other_color: Option<Color>,
+    other_alpha: Option<u8>,
+) -> Option<u8> {
+    match other_color {
+        Some(_) => other_alpha,
+        None => other_alpha.or(alpha),
+    }
+}
+
+/// Error returned when a string cannot be parsed into a [`Style`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStyleError {
+    input: String,
+}
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid style: {:?}", self.input)
+    }
+}
+
+impl Error for ParseStyleError {}
+
+/// Splits a style on whitespace, keeping functional colors such as `rgb(0, 0, 0)` in one piece.
+fn style_tokens(s: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut in_parens = false;
+    for word in s.split_whitespace() {
+        match tokens.last_mut() {
+            Some(last) if in_parens => last.push_str(word),
+            _ => tokens.push(word.to_owned()),
+        }
+        let last = tokens.last().unwrap();
+        in_parens = last.contains('(') && !last.ends_with(')');
+    }
+    tokens
+}
+
+/// Parses a style from a whitespace separated list of:
+///
+/// * modifier names (`"bold"`, `"crossed_out"`, ...), added to the style,
+/// * modifier names prefixed with `not` (`"not bold"`), removed from the style,
+/// * a color, used as the foreground color,
+/// * a color prefixed with `on` (`"on black"`), used as the background color,
+/// * a color prefixed with `underline` (`"underline red"`), used as the underline color.
+/// * an opacity between `0` and `255` prefixed with `alpha` (`"alpha 128"`), see
+///   [`Style::blend`], or with `fg_alpha`, `bg_alpha` or `underline_alpha` for a single color,
+/// * an URL prefixed with `link` (`"link https://example.com"`), used as the hyperlink target,
+///   `"link none"` removing the hyperlink.
+///
+/// Colors follow the formats accepted by [`Color`]'s [`FromStr`] implementation, without spaces
+/// in their names (`"darkgray"` or `"dark-gray"`). An empty string is parsed as
+/// [`Style::DEFAULT`].
+///
+/// ## Examples
+///
+/// ```rust
+/// # use tui::style::{Color, Modifier, Style};
+/// assert_eq!(
+///     "bold yellow on black".parse(),
+///     Ok(Style::DEFAULT
+///         .fg(Color::Yellow)
+///         .bg(Color::Black)
+///         .add_modifier(Modifier::BOLD)),
+/// );
+/// assert_eq!(
+///     "not italic #ff8800 underline rgb(0, 0, 255)".parse(),
+///     Ok(Style::DEFAULT
+///         .fg(Color::Rgb(255, 136, 0))
+///         .underline_color(Color::Rgb(0, 0, 255))
+///         .remove_modifier(Modifier::ITALIC)),
+/// );
+/// assert!("bold on".parse::<Style>().is_err());
+/// ```
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Style, ParseStyleError> {
+        let err = || ParseStyleError {
+            input: s.to_owned(),
+        };
+        let tokens = style_tokens(s);
+        let mut tokens = tokens.iter();
+        let mut style = Style::DEFAULT;
+        while let Some(token) = tokens.next() {
+            let mut argument = || tokens.next().ok_or_else(err);
+            match token.to_lowercase().as_str() {
+                "on" => style.bg = Some(argument()?.parse().map_err(|_| err())?),
+                "underline" => {
+                    style.underline_color = Some(argument()?.parse().map_err(|_| err())?)
+                }
+                "alpha" => style = style.blend(argument()?.parse().map_err(|_| err())?),
+                "fg_alpha" => style.fg_alpha = Some(argument()?.parse().map_err(|_| err())?),
+                "bg_alpha" => style.bg_alpha = Some(argument()?.parse().map_err(|_| err())?),
+                "underline_alpha" => {
+                    style.underline_alpha = Some(argument()?.parse().map_err(|_| err())?)
+                }
+                "link" => {
+                    style.hyperlink = Some(match argument()?.as_str() {
+                        "none" => Hyperlink::NONE,
+                        url => Hyperlink::new(url),
+                    })
+                }
+                "not" => {
+                    let modifier = modifier_from_name(argument()?).ok_or_else(err)?;
+                    style = style.remove_modifier(modifier);
+                }
+                _ => match modifier_from_name(token) {
+                    Some(modifier) => style = style.add_modifier(modifier),
+                    None => style.fg = Some(token.parse().map_err(|_| err())?),
+                },
+            }
+        }
+        Ok(style)
+    }
+}
+
+/// Formats the style so that it can be parsed back with [`str::parse`].
+///
+/// ## Examples
+///
+/// ```rust
+/// # use tui::style::{Color, Modifier, Style};
+/// let style = Style::DEFAULT
+///     .fg(Color::Yellow)
+///     .bg(Color::Rgb(0, 0, 0))
+///     .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+///     .remove_modifier(Modifier::DIM);
+/// assert_eq!(style.to_string(), "bold italic not dim yellow on #000000");
+/// assert_eq!(Style::DEFAULT.to_string(), "");
+/// ```
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut words = Vec::new();
+        for (modifier, name) in MODIFIER_NAMES.iter() {
+            if self.add_modifier.contains(*modifier) {
+                words.push(name.to_string());
+            }
+        }
+        for (modifier, name) in MODIFIER_NAMES.iter() {
+            if self.sub_modifier.contains(*modifier) {
+                words.push(format!("not {}", name));
+            }
+        }
+        if let Some(fg) = self.fg {
+            words.push(fg.to_string());
+        }
+        if let Some(bg) = self.bg {
+            words.push(format!("on {}", bg));
+        }
+        if let Some(color) = self.underline_color {
+            words.push(format!("underline {}", color));
+        }
+        match (self.fg_alpha, self.bg_alpha, self.underline_alpha) {
+            (Some(fg), Some(bg), Some(underline)) if fg == bg && bg == underline => {
+                words.push(format!("alpha {}", fg));
+            }
+            (fg, bg, underline) => {
+                let alphas = [("fg", fg), ("bg", bg), ("underline", underline)];
+                for (name, alpha) in alphas.iter() {
+                    if let Some(alpha) = alpha {
+                        words.push(format!("{}_alpha {}", name, alpha));
+                    }
+                }
+            }
+        }
+        if let Some(link) = self.hyperlink {
+            words.push(format!("link {}", link.url().as_deref().unwrap_or("none")));
+        }
+        f.write_str(&words.join(" "))
+    }
+}
+
+/// Styles are serialized in their textual form (e.g. `"bold yellow on black"`), see [`Style`]'s
+/// [`FromStr`] implementation.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Style {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Styles can be deserialized either from their textual form or from a map of their fields.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Style {
+    fn deserialize<D>(deserializer: D) -> Result<Style, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(default)]
+        struct StyleFields {
+            fg: Option<Color>,
+            bg: Option<Color>,
+            underline_color: Option<Color>,
+            hyperlink: Option<String>,
+            /// Alpha of the colors whose alpha is not given
+            alpha: Option<u8>,
+            fg_alpha: Option<u8>,
+            bg_alpha: Option<u8>,
+            underline_alpha: Option<u8>,
+            add_modifier: Modifier,
+            sub_modifier: Modifier,
+        }
+
+        impl Default for StyleFields {
+            fn default() -> StyleFields {
+                StyleFields {
+                    fg: None,
+                    bg: None,
+                    underline_color: None,
+                    hyperlink: None,
+                    alpha: None,
+                    fg_alpha: None,
+                    bg_alpha: None,
+                    underline_alpha: None,
+                    add_modifier: Modifier::EMPTY,
+                    sub_modifier: Modifier::EMPTY,
+                }
+            }
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum StyleRepr {
+            Text(String),
+            Fields(StyleFields),
+        }
+
+        match StyleRepr::deserialize(deserializer)? {
+            StyleRepr::Text(s) => s.parse().map_err(serde::de::Error::custom),
+            StyleRepr::Fields(fields) => Ok(Style {
+                fg: fields.fg,
+                bg: fields.bg,
+                underline_color: fields.underline_color,
+                hyperlink: fields.hyperlink.as_deref().map(Hyperlink::new),
+                fg_alpha: fields.fg_alpha.or(fields.alpha),
+                bg_alpha: fields.bg_alpha.or(fields.alpha),
+                underline_alpha: fields.underline_alpha.or(fields.alpha),
+                add_modifier: fields.add_modifier,
+                sub_modifier: fields.sub_modifier,
+            }),
+        }
+    }
+}
+
+/// A linear gradient going through two or more evenly spaced colors.
+///
+/// Gradients are used to fill an area with a background color interpolated for every cell, see
+/// [`Buffer::set_gradient`](crate::buffer::Buffer::set_gradient). Colors are interpolated with
+/// [`Color::lerp`].
+///
+/// ## Examples
+///
+/// ```rust
+/// # use tui::style::{Color, Gradient};
+/// # use tui::layout::{Direction, Rect};
+/// let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0), Color::Rgb(200, 200, 0)]);
+/// assert_eq!(gradient.color_at(0.0), Color::Rgb(0, 0, 0));
+/// assert_eq!(gradient.color_at(0.25), Color::Rgb(100, 0, 0));
+/// assert_eq!(gradient.color_at(0.75), Color::Rgb(200, 100, 0));
+///
+/// let area = Rect::new(0, 0, 3, 5);
+/// let vertical = gradient.direction(Direction::Vertical);
+/// assert_eq!(vertical.color_in(area, 0, 4), Color::Rgb(200, 200, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gradient {
+    colors: Vec<Color>,
+    direction: Direction,
+}
+
+impl Gradient {
+    /// Creates a horizontal gradient going through the given colors from left to right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no color is given.
+    pub fn new<I>(colors: I) -> Gradient
+    where
+        I: IntoIterator<Item = Color>,
+    {
+        let colors = colors.into_iter().collect::<Vec<Color>>();
+        assert!(!colors.is_empty(), "A gradient needs at least one color");
+        Gradient {
+            colors,
+            direction: Direction::Horizontal,
+        }
+    }
+
+    /// Sets the direction of the gradient: left to right for [`Direction::Horizontal`] and top to
+    /// bottom for [`Direction::Vertical`].
+    pub fn direction(mut self, direction: Direction) -> Gradient {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns the color at the given position, going from `0.0` (first color) to `1.0` (last
+    /// color).
+    pub fn color_at(&self, t: f64) -> Color {
+        let segments = self.colors.len() - 1;
+        let position = t.clamp(0.0, 1.0) * segments as f64;
+        let index = (position.floor() as usize).min(segments.saturating_sub(1));
+        match self.colors.get(index + 1) {
+            Some(next) => self.colors[index].lerp(*next, position - index as f64),
+            None => self.colors[index],
+        }
+    }
+
+    /// Returns the color of the cell at (x, y) when the gradient spans the given area.
+    pub fn color_in(&self, area: Rect, x: u16, y: u16) -> Color {
+        let (offset, length) = match self.direction {
+            Direction::Horizontal => (x.saturating_sub(area.left()), area.width),
+            Direction::Vertical => (y.saturating_sub(area.top()), area.height),
+        };
+        if length <= 1 {
+            return self.color_at(0.0);
+        }
+        self.color_at(f64::from(offset) / f64::from(length - 1))
+    }
+}
+
+/// A set of styles identified by semantic names such as `"primary"`, `"error"` or
+/// `"selection"`.
+///
+/// A theme can be made current with [`Theme::set_current`] so that the styles are looked up
+/// indirectly through [`Styled::themed`]. As widgets are rebuilt on every frame, changing the
+/// current theme restyles the whole user interface on the next draw.
+///
+/// With the `serde` feature, a theme is (de)serialized as a map from names to styles in their
+/// textual form, so that color schemes can be shipped as configuration files in any format
+/// supported by serde, e.g. in TOML:
+///
+/// ```toml
+/// primary = "white on black"
+/// error = "bold lightred"
+/// selection = "reversed"
+/// ```
+///
+/// ## Examples
+///
+/// ```rust
+/// # use tui::style::{Color, Modifier, Style, Styled, Theme};
+/// # use tui::widgets::Block;
+/// let dark = Theme::new()
+///     .style("primary", Style::DEFAULT.fg(Color::White).bg(Color::Black))
+///     .style("error", Style::DEFAULT.fg(Color::LightRed).add_modifier(Modifier::BOLD));
+/// assert_eq!(dark.get("error"), Some(Style::DEFAULT.fg(Color::LightRed).add_modifier(Modifier::BOLD)));
+/// assert_eq!(dark.get("warning"), None);
+///
+/// Theme::set_current(dark);
+/// let block = Block::default().title("Error").themed("error");
+/// assert_eq!(Style::DEFAULT.themed("primary"), Style::DEFAULT.fg(Color::White).bg(Color::Black));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Theme {
+    styles: HashMap<String, Style>,
+}
+
+thread_local! {
+    static CURRENT_THEME: RefCell<Theme> = RefCell::new(Theme::default());
+}
+
+impl Theme {
+    /// Returns an empty theme.
+    pub fn new() -> Theme {
+        Theme::default()
+    }
+
+    /// Sets the style associated to the given name.
+    pub fn style<K>(mut self, key: K, style: Style) -> Theme
+    where
+        K: Into<String>,
+    {
+        self.insert(key, style);
+        self
+    }
+
+    /// Sets the style associated to the given name, returning the previous one if any.
+    pub fn insert<K>(&mut self, key: K, style: Style) -> Option<Style>
+    where
+        K: Into<String>,
+    {
+        self.styles.insert(key.into(), style)
+    }
+
+    /// Returns the style associated to the given name.
+    pub fn get(&self, key: &str) -> Option<Style> {
+        self.styles.get(key).copied()
+    }
+
+    /// Makes the theme the one used by [`Styled::themed`] on the current thread, returning the
+    /// previous one.
+    pub fn set_current(theme: Theme) -> Theme {
+        CURRENT_THEME.with(|current| current.replace(theme))
+    }
+
+    /// Returns the style associated to the given name in the current theme, or
+    /// [`Style::DEFAULT`] if there is none.
+    pub fn current_style(key: &str) -> Style {
+        CURRENT_THEME.with(|current| current.borrow().get(key).unwrap_or(Style::DEFAULT))
+    }
+}
+
+/// Types whose style can be patched, either directly or by looking up a style in the current
+/// [`Theme`].
+pub trait Styled: Sized {
+    /// Patches the style of `self` with the given one.
+    fn patch_style(self, style: Style) -> Self;
+
+    /// Patches the style of `self` with the style associated to `key` in the current [`Theme`].
+    /// Nothing changes if the key is not part of the theme.
+    fn themed(self, key: &str) -> Self {
+        self.patch_style(Theme::current_style(key))
+    }
+}
+
+impl Styled for Style {
+    fn patch_style(self, style: Style) -> Style {
+        self.patch(style)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn patched_colors_keep_their_own_alpha() {
+        let translucent = Style::DEFAULT.bg(Color::Black).blend(64);
+        let patched = translucent.patch(Style::DEFAULT.fg(Color::Red));
+        assert_eq!((patched.fg_alpha, patched.bg_alpha), (None, Some(64)));
+        let patched = translucent.patch(Style::DEFAULT.fg(Color::Red).blend(u8::MAX));
+        assert_eq!(
+            (patched.fg_alpha, patched.bg_alpha),
+            (Some(u8::MAX), Some(u8::MAX))
+        );
+        let patched = translucent.patch(Style::DEFAULT.add_modifier(Modifier::BOLD));
+        assert_eq!((patched.fg_alpha, patched.bg_alpha), (Some(64), Some(64)));
+        let patched = Style::DEFAULT.fg(Color::Red).patch(translucent);
+        assert_eq!((patched.fg_alpha, patched.bg_alpha), (Some(64), Some(64)));
+
+        let mut cell = crate::buffer::Cell::default();
+        cell.set_fg(Color::Rgb(0, 0, 0))
+            .set_bg(Color::Rgb(200, 200, 200))
+            .set_style(
+                Style::DEFAULT
+                    .bg(Color::Rgb(0, 0, 0))
+                    .blend(64)
+                    .patch(Style::DEFAULT.fg(Color::Rgb(100, 100, 100))),
+            );
+        assert_eq!(cell.fg, Color::Rgb(100, 100, 100));
+        assert_eq!(cell.bg, Color::Rgb(150, 150, 150));
+    }
+
+    #[test]
+    fn hyperlinks_forget_their_oldest_urls() {
+        let mut hyperlinks = Hyperlinks::default();
+        let first = hyperlinks.intern("https://example.com/0");
+        assert_eq!(first, hyperlinks.intern("https://example.com/0"));
+        for i in 1..=MAX_HYPERLINKS {
+            hyperlinks.intern(&format!("https://example.com/{}", i));
+        }
+        assert_eq!(hyperlinks.url(first), None);
+        let last = hyperlinks.intern(&format!("https://example.com/{}", MAX_HYPERLINKS));
+        assert_eq!(
+            hyperlinks.url(last).as_deref(),
+            Some(format!("https://example.com/{}", MAX_HYPERLINKS).as_str())
+        );
+        let again = hyperlinks.intern("https://example.com/0");
+        assert_ne!(first, again);
+        assert_eq!(hyperlinks.url(first), None);
+    }
+
+    #[test]
+    fn hyperlinks_are_shared_between_threads() {
+        let link = std::thread::spawn(|| Hyperlink::new("https://example.com/thread"))
+            .join()
+            .unwrap();
+        assert_eq!(link.url().as_deref(), Some("https://example.com/thread"));
+        assert_eq!(link, Hyperlink::new("https://example.com/thread"));
+    }
+
+    #[test]
+    fn modifier_from_str_and_display_round_trip() {
+        for bits in 0..=Modifier::ALL.bits() {
+            let modifier = Modifier::from_bits(bits).unwrap();
+            assert_eq!(modifier.to_string().parse(), Ok(modifier));
+        }
+        assert!("blod".parse::<Modifier>().is_err());
+        assert!("bold||italic".parse::<Modifier>().is_err());
+    }
+
+    #[test]
+    fn style_from_str_and_display_round_trip() {
+        let styles = [
+            Style::DEFAULT,
+            Style::RESET,
+            Style::DEFAULT.fg(Color::Indexed(42)),
+            Style::DEFAULT
+                .bg(Color::Rgb(1, 2, 3))
+                .underline_color(Color::LightMagenta),
+            Style::DEFAULT.hyperlink("https://example.com/?q=a+b"),
+            Style::DEFAULT.hyperlink(Hyperlink::NONE),
+            Style::DEFAULT.bg(Color::Black).blend(128),
+            Style::DEFAULT
+                .fg(Color::Red)
+                .bg(Color::Black)
+                .blend(128)
+                .patch(Style::DEFAULT.fg(Color::Blue)),
+            Style::DEFAULT
+                .add_modifier(Modifier::ALL - Modifier::HIDDEN)
+                .remove_modifier(Modifier::HIDDEN),
+        ];
+        for style in styles {
+            assert_eq!(style.to_string().parse(), Ok(style));
+        }
+        assert_eq!(
+            "  Bold   dark-gray ON rgb( 1 , 2 , 3 )  ".parse(),
+            Ok(Style::DEFAULT
+                .fg(Color::DarkGray)
+                .bg(Color::Rgb(1, 2, 3))
+                .add_modifier(Modifier::BOLD)),
+        );
+        assert!("not".parse::<Style>().is_err());
+        assert!("not red".parse::<Style>().is_err());
+        assert!("blod".parse::<Style>().is_err());
+        assert!("on rgb(1, 2".parse::<Style>().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn theme_deserializes_from_styles_in_textual_form() {
+        use serde::de::{value, Deserialize, IntoDeserializer};
+
+        let deserializer = value::MapDeserializer::<_, value::Error>::new(
+            [("primary", "white on black"), ("error", "bold lightred")].into_iter(),
+        );
+        let theme = Theme::deserialize(deserializer).unwrap();
+        assert_eq!(
+            theme,
+            Theme::new()
+                .style("primary", Style::DEFAULT.fg(Color::White).bg(Color::Black))
+                .style(
+                    "error",
+                    Style::DEFAULT
+                        .fg(Color::LightRed)
+                        .add_modifier(Modifier::BOLD)
+                )
+        );
+
+        let deserializer: value::StrDeserializer<value::Error> = "on".into_deserializer();
+        assert!(Style::deserialize(deserializer).is_err());
+    }
+
+    #[test]
+    fn color_from_hsl_and_hsv() {
+        assert_eq!(Color::from_hsl(0.0, 0.0, 0.0), Color::Rgb(0, 0, 0));
+        assert_eq!(Color::from_hsl(0.0, 0.0, 1.0), Color::Rgb(255, 255, 255));
+        assert_eq!(Color::from_hsl(240.0, 1.0, 0.5), Color::Rgb(0, 0, 255));
+        assert_eq!(Color::from_hsl(-60.0, 1.0, 0.5), Color::Rgb(255, 0, 255));
+        assert_eq!(Color::from_hsl(420.0, 1.0, 0.5), Color::Rgb(255, 255, 0));
+        assert_eq!(Color::from_hsv(180.0, 1.0, 0.5), Color::Rgb(0, 128, 128));
+        assert_eq!(Color::from_hsv(0.0, 0.0, 0.75), Color::Rgb(191, 191, 191));
+        assert_eq!(Color::from_hsv(300.0, 2.0, 2.0), Color::Rgb(255, 0, 255));
+    }
+
+    #[test]
+    fn color_downgrade() {
+        assert_eq!(
+            Color::Rgb(0, 0, 0).downgrade(ColorDepth::Indexed256),
+            Color::Indexed(16)
+        );
+        assert_eq!(
+            Color::Rgb(255, 255, 255).downgrade(ColorDepth::Indexed256),
+            Color::Indexed(231)
+        );
+        assert_eq!(
+            Color::Rgb(128, 128, 128).downgrade(ColorDepth::Indexed256),
+            Color::Indexed(244)
+        );
+        assert_eq!(
+            Color::Rgb(95, 135, 175).downgrade(ColorDepth::Indexed256),
+            Color::Indexed(67)
+        );
+        assert_eq!(
+            Color::Indexed(67).downgrade(ColorDepth::Indexed256),
+            Color::Indexed(67)
+        );
+        assert_eq!(
+            Color::Indexed(9).downgrade(ColorDepth::Ansi16),
+            Color::LightRed
+        );
+        assert_eq!(
+            Color::Indexed(21).downgrade(ColorDepth::Ansi16),
+            Color::LightBlue
+        );
+        assert_eq!(
+            Color::Indexed(236).downgrade(ColorDepth::Ansi16),
+            Color::Black
+        );
+        assert_eq!(
+            Color::Rgb(0, 100, 0).downgrade(ColorDepth::Ansi16),
+            Color::Green
+        );
+        assert_eq!(Color::Reset.downgrade(ColorDepth::Ansi16), Color::Reset);
+        assert_eq!(Color::Cyan.downgrade(ColorDepth::Indexed256), Color::Cyan);
+        for i in 16..=255 {
+            let (r, g, b) = Color::indexed_to_rgb(i);
+            assert_eq!(Color::rgb_to_indexed(r, g, b), i);
+        }
+    }
+
+    #[test]
+    fn themed_styles_follow_the_current_theme() {
+        let light = Theme::new().style("selection", Style::DEFAULT.bg(Color::White));
+        let dark = Theme::new().style("selection", Style::DEFAULT.bg(Color::Black));
+        let base = Style::DEFAULT.fg(Color::Red);
+
+        let previous = Theme::set_current(light.clone());
+        assert_eq!(
+            base.themed("selection"),
+            Style::DEFAULT.fg(Color::Red).bg(Color::White)
+        );
+        assert_eq!(base.themed("missing"), base);
+        assert_eq!(Theme::set_current(dark), light);
+        assert_eq!(
+            base.themed("selection"),
+            Style::DEFAULT.fg(Color::Red).bg(Color::Black)
+        );
+        Theme::set_current(previous);
+    }
+
+    #[test]
+    fn color_round_trips_through_its_string_representation() {
+        let colors = [
+            Color::Reset,
+            Color::DarkGray,
+            Color::LightMagenta,
+            Color::White,
+            Color::Rgb(0, 0, 0),
+            Color::Rgb(255, 136, 0),
+            Color::Indexed(0),
+            Color::Indexed(255),
+        ];
+        for color in colors {
+            assert_eq!(color.to_string().parse(), Ok(color));
+        }
+    }
+
+    #[test]
+    fn color_parses_alternative_forms() {
+        assert_eq!("#F80".parse(), Ok(Color::Rgb(255, 136, 0)));
+        assert_eq!(" rgb( 1 , 2,3 ) ".parse(), Ok(Color::Rgb(1, 2, 3)));
+        assert_eq!("Light_Cyan".parse(), Ok(Color::LightCyan));
+        assert_eq!("dark grey".parse(), Ok(Color::DarkGray));
+        assert_eq!("purple".parse(), Ok(Color::Rgb(128, 0, 128)));
+        for invalid in [
+            "",
+            "#ff880",
+            "#gg8800",
+            "rgb(256,0,0)",
+            "rgb(1,2)",
+            "purplish",
+            "256",
+        ] {
+            assert!(invalid.parse::<Color>().is_err(), "{:?}", invalid);
+        }
+    }
+
     fn styles() -> Vec<Style> {
         vec![
             Style::DEFAULT,
             Style::DEFAULT.fg(Color::Yellow),
             Style::DEFAULT.bg(Color::Yellow),
+            Style::DEFAULT.underline_color(Color::Yellow),
             Style::DEFAULT.add_modifier(Modifier::BOLD),
             Style::DEFAULT.remove_modifier(Modifier::BOLD),
             Style::DEFAULT.add_modifier(Modifier::ITALIC),
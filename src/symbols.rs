@@ -103,6 +103,9 @@ pub mod line {
     pub const DOUBLE_HORIZONTAL: &str = "═";
     pub const THICK_HORIZONTAL: &str = "━";
 
+    pub const HORIZONTAL_LEFT_HALF: &str = "╴";
+    pub const THICK_HORIZONTAL_LEFT_HALF: &str = "╸";
+
     pub const TOP_RIGHT: &str = "┐";
     pub const ROUNDED_TOP_RIGHT: &str = "╮";
     pub const DOUBLE_TOP_RIGHT: &str = "╗";
@@ -143,7 +146,7 @@ pub mod line {
     pub const DOUBLE_CROSS: &str = "╬";
     pub const THICK_CROSS: &str = "╋";
 
-    #[derive(Debug, Clone)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
     pub struct Set {
         pub vertical: &'static str,
         pub horizontal: &'static str,
@@ -207,6 +210,251 @@ pub mod line {
         horizontal_up: THICK_HORIZONTAL_UP,
         cross: THICK_CROSS,
     };
+
+    /// Directions in which the line symbols are connected, in the order of the fields of [`Set`].
+    const CONNECTIONS: [u8; 11] = {
+        const UP: u8 = 1;
+        const DOWN: u8 = 2;
+        const LEFT: u8 = 4;
+        const RIGHT: u8 = 8;
+        [
+            UP | DOWN,
+            LEFT | RIGHT,
+            LEFT | DOWN,
+            RIGHT | DOWN,
+            UP | LEFT,
+            UP | RIGHT,
+            UP | DOWN | LEFT,
+            UP | DOWN | RIGHT,
+            LEFT | RIGHT | DOWN,
+            LEFT | RIGHT | UP,
+            UP | DOWN | LEFT | RIGHT,
+        ]
+    };
+
+    impl Set {
+        fn symbols(&self) -> [&'static str; 11] {
+            [
+                self.vertical,
+                self.horizontal,
+                self.top_right,
+                self.top_left,
+                self.bottom_right,
+                self.bottom_left,
+                self.vertical_left,
+                self.vertical_right,
+                self.horizontal_down,
+                self.horizontal_up,
+                self.cross,
+            ]
+        }
+    }
+
+    /// Returns the set a symbol belongs to, rounded corners being part of the normal lines, and
+    /// the directions the symbol is connected to.
+    fn connections(symbol: &str) -> Option<(Set, u8)> {
+        let corners = ROUNDED.symbols();
+        [NORMAL, DOUBLE, THICK]
+            .iter()
+            .find_map(|set| {
+                set.symbols()
+                    .iter()
+                    .position(|s| *s == symbol)
+                    .map(|i| (set.clone(), CONNECTIONS[i]))
+            })
+            .or_else(|| {
+                corners
+                    .iter()
+                    .position(|s| *s == symbol)
+                    .map(|i| (NORMAL, CONNECTIONS[i]))
+            })
+    }
+
+    /// Returns the symbol joining two line symbols of the same weight drawn in the same cell, e.g.
+    /// a T-junction for a vertical line and a corner. Returns `None` if one of the symbols is not
+    /// a line or if their weights differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::symbols::line;
+    /// assert_eq!(line::merge("│", "┌"), Some("├"));
+    /// assert_eq!(line::merge("┤", "├"), Some("┼"));
+    /// assert_eq!(line::merge("═", "╝"), Some("╩"));
+    /// assert_eq!(line::merge("│", "╭"), Some("├"));
+    /// assert_eq!(line::merge("┃", "┌"), None);
+    /// assert_eq!(line::merge("x", "┌"), None);
+    /// ```
+    pub fn merge(existing: &str, symbol: &'static str) -> Option<&'static str> {
+        let (set, a) = connections(existing)?;
+        let (other, b) = connections(symbol)?;
+        if set != other {
+            return None;
+        }
+        let merged = a | b;
+        if merged == b {
+            // nothing to join, which keeps rounded corners
+            return Some(symbol);
+        }
+        CONNECTIONS
+            .iter()
+            .position(|c| *c == merged)
+            .map(|i| set.symbols()[i])
+    }
+}
+
+/// Symbols used to draw the border of a [`Block`](crate::widgets::Block).
+pub mod border {
+    use super::line;
+
+    /// The symbols of the four sides and the four corners of a border. Unlike a [`line::Set`],
+    /// opposite sides may use different symbols, e.g. to draw borders with half blocks.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Set {
+        pub top_left: &'static str,
+        pub top_right: &'static str,
+        pub bottom_left: &'static str,
+        pub bottom_right: &'static str,
+        pub vertical_left: &'static str,
+        pub vertical_right: &'static str,
+        pub horizontal_top: &'static str,
+        pub horizontal_bottom: &'static str,
+    }
+
+    impl Set {
+        /// Returns the border drawn with the lines and corners of a line set.
+        pub const fn from_line_set(set: line::Set) -> Set {
+            Set {
+                top_left: set.top_left,
+                top_right: set.top_right,
+                bottom_left: set.bottom_left,
+                bottom_right: set.bottom_right,
+                vertical_left: set.vertical,
+                vertical_right: set.vertical,
+                horizontal_top: set.horizontal,
+                horizontal_bottom: set.horizontal,
+            }
+        }
+    }
+
+    impl From<line::Set> for Set {
+        fn from(set: line::Set) -> Set {
+            Set::from_line_set(set)
+        }
+    }
+
+    pub const PLAIN: Set = Set::from_line_set(line::NORMAL);
+
+    pub const ROUNDED: Set = Set::from_line_set(line::ROUNDED);
+
+    pub const DOUBLE: Set = Set::from_line_set(line::DOUBLE);
+
+    pub const THICK: Set = Set::from_line_set(line::THICK);
+
+    /// A border made of quadrants drawn outside of the content area.
+    ///
+    /// ```text
+    /// ▛▀▀▀▀▜
+    /// ▌text▐
+    /// ▙▄▄▄▄▟
+    /// ```
+    pub const QUADRANT_OUTSIDE: Set = Set {
+        top_left: "▛",
+        top_right: "▜",
+        bottom_left: "▙",
+        bottom_right: "▟",
+        vertical_left: "▌",
+        vertical_right: "▐",
+        horizontal_top: "▀",
+        horizontal_bottom: "▄",
+    };
+
+    /// A border made of quadrants drawn inside of the block area, e.g. to make the block look
+    /// like a filled box with a background color.
+    ///
+    /// ```text
+    /// ▗▄▄▄▄▖
+    /// ▐text▌
+    /// ▝▀▀▀▀▘
+    /// ```
+    pub const QUADRANT_INSIDE: Set = Set {
+        top_left: "▗",
+        top_right: "▖",
+        bottom_left: "▝",
+        bottom_right: "▘",
+        vertical_left: "▐",
+        vertical_right: "▌",
+        horizontal_top: "▄",
+        horizontal_bottom: "▀",
+    };
+}
+
+/// Symbols used to draw a [`Scrollbar`](crate::widgets::Scrollbar).
+pub mod scrollbar {
+    use super::{block, line};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Set {
+        pub track: &'static str,
+        pub thumb: &'static str,
+        pub begin: &'static str,
+        pub end: &'static str,
+    }
+
+    pub const VERTICAL: Set = Set {
+        track: line::VERTICAL,
+        thumb: block::FULL,
+        begin: "↑",
+        end: "↓",
+    };
+
+    pub const DOUBLE_VERTICAL: Set = Set {
+        track: line::DOUBLE_VERTICAL,
+        thumb: block::FULL,
+        begin: "▲",
+        end: "▼",
+    };
+
+    pub const HORIZONTAL: Set = Set {
+        track: line::HORIZONTAL,
+        thumb: block::FULL,
+        begin: "←",
+        end: "→",
+    };
+
+    pub const DOUBLE_HORIZONTAL: Set = Set {
+        track: line::DOUBLE_HORIZONTAL,
+        thumb: block::FULL,
+        begin: "◄",
+        end: "►",
+    };
+}
+
+/// Frames of a [`Throbber`](crate::widgets::Throbber), shown one after the other.
+pub mod throbber {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Set {
+        pub frames: &'static [&'static str],
+    }
+
+    pub const DOTS: Set = Set {
+        frames: &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+    };
+
+    pub const LINE: Set = Set {
+        frames: &["-", "\\", "|", "/"],
+    };
+
+    pub const ARC: Set = Set {
+        frames: &["◜", "◠", "◝", "◞", "◡", "◟"],
+    };
+
+    pub const BOUNCING_BAR: Set = Set {
+        frames: &[
+            "[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]", "[   =]", "[    ]",
+            "[   =]", "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]", "[=   ]",
+        ],
+    };
 }
 
 pub const DOT: &str = "•";
@@ -222,7 +470,7 @@ pub mod braille {
 }
 
 /// Marker to use when plotting data points
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Marker {
     /// One point per cell in shape of dot
     Dot,
@@ -1,7 +1,8 @@
 use crate::{
     backend::Backend,
-    buffer::Buffer,
+    buffer::{Buffer, BufferSnapshot},
     layout::Rect,
+    style::ColorDepth,
     widgets::{StatefulWidget, Widget},
 };
 use std::io;
@@ -219,13 +220,55 @@ where
         &mut self.backend
     }
 
+    /// Consumes the terminal and returns a new one drawing to the given backend.
+    ///
+    /// Pending output is flushed to the current backend before it is dropped. The viewport is
+    /// preserved but the internal buffers are reset so that the next draw call outputs the whole
+    /// frame to the new backend.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tui::Terminal;
+    /// # use tui::backend::TestBackend;
+    /// let terminal = Terminal::new(TestBackend::new(10, 10)).unwrap();
+    /// let terminal = terminal.swap_backend(TestBackend::new(10, 10)).unwrap();
+    /// ```
+    pub fn swap_backend<B2>(mut self, backend: B2) -> io::Result<Terminal<B2>>
+    where
+        B2: Backend,
+    {
+        self.backend.flush()?;
+        Terminal::with_options(
+            backend,
+            TerminalOptions {
+                viewport: self.viewport.clone(),
+            },
+        )
+    }
+
     /// Obtains a difference between the previous and the current buffer and passes it to the
     /// current backend for drawing.
     pub fn flush(&mut self) -> io::Result<()> {
         let previous_buffer = &self.buffers[1 - self.current];
         let current_buffer = &self.buffers[self.current];
         let updates = previous_buffer.diff(current_buffer);
-        self.backend.draw(updates.into_iter())
+        let color_depth = self.backend.color_depth();
+        if color_depth == ColorDepth::TrueColor {
+            return self.backend.draw(updates.into_iter());
+        }
+        let updates = updates
+            .into_iter()
+            .map(|(x, y, cell)| {
+                let mut cell = cell.clone();
+                cell.fg = cell.fg.downgrade(color_depth);
+                cell.bg = cell.bg.downgrade(color_depth);
+                cell.underline_color = cell.underline_color.downgrade(color_depth);
+                (x, y, cell)
+            })
+            .collect::<Vec<_>>();
+        self.backend
+            .draw(updates.iter().map(|(x, y, cell)| (*x, *y, cell)))
     }
 
     /// Updates the Terminal so that internal buffers match the requested size. Requested size will
@@ -317,6 +360,17 @@ where
         Ok(())
     }
 
+    /// Restores a snapshot of a previously rendered frame into the current buffer.
+    ///
+    /// The terminal is cleared so that the whole snapshot is output on the next call to
+    /// [`Terminal::flush`] or [`Terminal::draw`].
+    pub fn restore_snapshot(&mut self, snapshot: &BufferSnapshot) -> io::Result<()> {
+        self.buffers[self.current]
+            .restore_from(snapshot)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        self.clear()
+    }
+
     /// Queries the real size of the backend.
     pub fn size(&self) -> io::Result<Rect> {
         self.backend.size()
@@ -51,7 +51,7 @@ use std::borrow::Cow;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
-use crate::style::Style;
+use crate::style::{Style, Styled};
 
 /// A grapheme associated to a style.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -135,6 +135,11 @@ impl<'a> Span<'a> {
     ///             style: Style {
     ///                 fg: Some(Color::Yellow),
     ///                 bg: Some(Color::Black),
+    ///                 underline_color: None,
+    ///                 hyperlink: None,
+    ///                 fg_alpha: None,
+    ///                 bg_alpha: None,
+    ///                 underline_alpha: None,
     ///                 add_modifier: Modifier::EMPTY,
     ///                 sub_modifier: Modifier::EMPTY,
     ///             },
@@ -144,6 +149,11 @@ impl<'a> Span<'a> {
     ///             style: Style {
     ///                 fg: Some(Color::Yellow),
     ///                 bg: Some(Color::Black),
+    ///                 underline_color: None,
+    ///                 hyperlink: None,
+    ///                 fg_alpha: None,
+    ///                 bg_alpha: None,
+    ///                 underline_alpha: None,
     ///                 add_modifier: Modifier::EMPTY,
     ///                 sub_modifier: Modifier::EMPTY,
     ///             },
@@ -153,6 +163,11 @@ impl<'a> Span<'a> {
     ///             style: Style {
     ///                 fg: Some(Color::Yellow),
     ///                 bg: Some(Color::Black),
+    ///                 underline_color: None,
+    ///                 hyperlink: None,
+    ///                 fg_alpha: None,
+    ///                 bg_alpha: None,
+    ///                 underline_alpha: None,
     ///                 add_modifier: Modifier::EMPTY,
     ///                 sub_modifier: Modifier::EMPTY,
     ///             },
@@ -162,6 +177,11 @@ impl<'a> Span<'a> {
     ///             style: Style {
     ///                 fg: Some(Color::Yellow),
     ///                 bg: Some(Color::Black),
+    ///                 underline_color: None,
+    ///                 hyperlink: None,
+    ///                 fg_alpha: None,
+    ///                 bg_alpha: None,
+    ///                 underline_alpha: None,
     ///                 add_modifier: Modifier::EMPTY,
     ///                 sub_modifier: Modifier::EMPTY,
     ///             },
@@ -183,6 +203,13 @@ impl<'a> Span<'a> {
     }
 }
 
+impl<'a> Styled for Span<'a> {
+    fn patch_style(mut self, style: Style) -> Span<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
 impl<'a> From<String> for Span<'a> {
     fn from(s: String) -> Span<'a> {
         Span::raw(s)
@@ -352,6 +379,35 @@ impl<'a> Text<'a> {
         self.lines.len()
     }
 
+    /// Appends a line to the text.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::text::{Spans, Text};
+    /// let text = Text::from("The first line").push(Spans::from("The second line"));
+    /// assert_eq!(2, text.height());
+    /// ```
+    pub fn push(mut self, line: Spans<'a>) -> Text<'a> {
+        self.lines.push(line);
+        self
+    }
+
+    /// Appends a span to the text as a new line.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use tui::text::{Span, Text};
+    /// # use tui::style::{Color, Style};
+    /// let text = Text::from("The first line")
+    ///     .push_span(Span::styled("The second line", Style::DEFAULT.fg(Color::Red)));
+    /// assert_eq!(2, text.height());
+    /// ```
+    pub fn push_span(self, span: Span<'a>) -> Text<'a> {
+        self.push(Spans::from(span))
+    }
+
     /// Apply a new style to existing text.
     ///
     /// # Examples
@@ -1,13 +1,73 @@
 use crate::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Direction, Rect},
     style::Style,
     symbols,
+    text::Span,
     widgets::{Block, Widget},
 };
-use std::cmp::min;
+use std::{fmt, rc::Rc};
 use unicode_width::UnicodeWidthStr;
 
+/// How the series of a [`BarChart`] are laid out in each category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarMode {
+    /// One bar per series, side by side
+    Grouped,
+    /// A single bar per category, made of the values of the series stacked on top of each other
+    Stacked,
+}
+
+/// How values are mapped to the length of the bars of a [`BarChart`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarScale {
+    Linear,
+    /// The length of a bar is proportional to `log10(value + 1)`, which keeps small values
+    /// visible next to values several orders of magnitude larger
+    Log10,
+}
+
+/// Function formatting the values printed on the bars of a [`BarChart`]
+#[derive(Clone)]
+struct ValueFormatter<'a>(Rc<dyn Fn(u64) -> String + 'a>);
+
+impl<'a> fmt::Debug for ValueFormatter<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ValueFormatter(..)")
+    }
+}
+
+/// A named series of values for a [`BarChart`], one value per category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BarSeries<'a> {
+    name: &'a str,
+    data: &'a [u64],
+    /// Style of the bars, patched over the bar style of the chart
+    style: Style,
+}
+
+impl<'a> BarSeries<'a> {
+    pub fn new(name: &'a str, data: &'a [u64]) -> BarSeries<'a> {
+        BarSeries {
+            name,
+            data,
+            style: Style::DEFAULT,
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> BarSeries<'a> {
+        self.style = style;
+        self
+    }
+}
+
+/// The bars of one category, each made of one or more (value, style) segments stacked from the
+/// bottom.
+struct Group<'a> {
+    label: &'a str,
+    bars: Vec<Vec<(u64, Style)>>,
+}
+
 /// Display multiple bars in a single widgets
 ///
 /// # Examples
@@ -25,6 +85,21 @@ use unicode_width::UnicodeWidthStr;
 ///     .data(&[("B0", 0), ("B1", 2), ("B2", 4), ("B3", 3)])
 ///     .max(4);
 /// ```
+///
+/// Several series can be compared by grouping or stacking them in each category:
+///
+/// ```
+/// # use tui::widgets::{BarChart, BarMode, BarSeries};
+/// # use tui::style::{Style, Color};
+/// BarChart::default()
+///     .categories(&["Q1", "Q2", "Q3"])
+///     .series(vec![
+///         BarSeries::new("North", &[4, 6, 5]).style(Style::DEFAULT.fg(Color::Blue)),
+///         BarSeries::new("South", &[3, 2, 7]).style(Style::DEFAULT.fg(Color::Green)),
+///     ])
+///     .mode(BarMode::Stacked)
+///     .legend(true);
+/// ```
 #[derive(Debug, Clone)]
 pub struct BarChart<'a> {
     /// Block to wrap the widget in
@@ -35,6 +110,10 @@ pub struct BarChart<'a> {
     bar_gap: u16,
     /// Set of symbols used to display the data
     bar_set: symbols::bar::Set,
+    /// Set of symbols used to display the data when the bars are horizontal
+    horizontal_bar_set: symbols::block::Set,
+    /// Direction in which the bars grow
+    direction: Direction,
     /// Style of the bars
     bar_style: Style,
     /// Style of the values printed at the bottom of each bar
@@ -45,11 +124,20 @@ pub struct BarChart<'a> {
     style: Style,
     /// Slice of (label, value) pair to plot on the chart
     data: &'a [(&'a str, u64)],
+    /// Labels of the categories of the series
+    categories: &'a [&'a str],
+    /// Series to plot on the chart, in place of the data
+    series: Vec<BarSeries<'a>>,
+    mode: BarMode,
+    /// Display a legend listing the series on the first line of the chart
+    legend: bool,
     /// Value necessary for a bar to reach the maximum height (if no value is specified,
     /// the maximum value in the data is taken as reference)
     max: Option<u64>,
-    /// Values to display on the bar (computed when the data is passed to the widget)
-    values: Vec<String>,
+    /// How the values are mapped to the length of the bars
+    scale: BarScale,
+    /// Function formatting the values printed on the bars
+    value_formatter: Option<ValueFormatter<'a>>,
 }
 
 impl<'a> Default for BarChart<'a> {
@@ -57,12 +145,19 @@ impl<'a> Default for BarChart<'a> {
         BarChart {
             block: None,
             max: None,
+            scale: BarScale::Linear,
+            value_formatter: None,
             data: &[],
-            values: Vec::new(),
+            categories: &[],
+            series: Vec::new(),
+            mode: BarMode::Grouped,
+            legend: false,
             bar_style: Style::DEFAULT,
             bar_width: 1,
             bar_gap: 1,
             bar_set: symbols::bar::NINE_LEVELS,
+            horizontal_bar_set: symbols::block::NINE_LEVELS,
+            direction: Direction::Vertical,
             value_style: Style::DEFAULT,
             label_style: Style::DEFAULT,
             style: Style::DEFAULT,
@@ -73,10 +168,30 @@ impl<'a> Default for BarChart<'a> {
 impl<'a> BarChart<'a> {
     pub fn data(mut self, data: &'a [(&'a str, u64)]) -> BarChart<'a> {
         self.data = data;
-        self.values = Vec::with_capacity(self.data.len());
-        for &(_, v) in self.data {
-            self.values.push(format!("{}", v));
-        }
+        self
+    }
+
+    /// Set the labels of the categories, the n-th label being displayed under the n-th values
+    /// of the series.
+    pub fn categories(mut self, categories: &'a [&'a str]) -> BarChart<'a> {
+        self.categories = categories;
+        self
+    }
+
+    /// Set several series to plot instead of the data.
+    pub fn series(mut self, series: Vec<BarSeries<'a>>) -> BarChart<'a> {
+        self.series = series;
+        self
+    }
+
+    pub fn mode(mut self, mode: BarMode) -> BarChart<'a> {
+        self.mode = mode;
+        self
+    }
+
+    /// Display a legend listing the series on the first line of the chart.
+    pub fn legend(mut self, legend: bool) -> BarChart<'a> {
+        self.legend = legend;
         self
     }
 
@@ -85,11 +200,38 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    /// Set the value necessary for a bar to reach the maximum length. Bars of greater values are
+    /// cut at the maximum length.
     pub fn max(mut self, max: u64) -> BarChart<'a> {
         self.max = Some(max);
         self
     }
 
+    /// Set how the values are mapped to the length of the bars, linearly by default.
+    pub fn scale(mut self, scale: BarScale) -> BarChart<'a> {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the function formatting the values printed on the bars, e.g. to add units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::{BarChart, BarScale};
+    /// BarChart::default()
+    ///     .data(&[("GET", 12), ("POST", 1_500), ("PUT", 230_000)])
+    ///     .scale(BarScale::Log10)
+    ///     .value_formatter(|value| format!("{}ms", value));
+    /// ```
+    pub fn value_formatter<F>(mut self, formatter: F) -> BarChart<'a>
+    where
+        F: Fn(u64) -> String + 'a,
+    {
+        self.value_formatter = Some(ValueFormatter(Rc::new(formatter)));
+        self
+    }
+
     pub fn bar_style(mut self, style: Style) -> BarChart<'a> {
         self.bar_style = style;
         self
@@ -110,6 +252,19 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    pub fn horizontal_bar_set(mut self, bar_set: symbols::block::Set) -> BarChart<'a> {
+        self.horizontal_bar_set = bar_set;
+        self
+    }
+
+    /// Set the direction in which the bars grow. With [`Direction::Horizontal`], the bars grow
+    /// from left to right, with the labels of the categories on their left, `bar_width` is the
+    /// number of rows of each bar and `bar_gap` the number of rows between categories.
+    pub fn direction(mut self, direction: Direction) -> BarChart<'a> {
+        self.direction = direction;
+        self
+    }
+
     pub fn value_style(mut self, style: Style) -> BarChart<'a> {
         self.value_style = style;
         self
@@ -126,11 +281,215 @@ impl<'a> BarChart<'a> {
     }
 }
 
+impl<'a> BarChart<'a> {
+    fn groups(&self) -> Vec<Group<'a>> {
+        if self.series.is_empty() {
+            return self
+                .data
+                .iter()
+                .map(|&(label, value)| Group {
+                    label,
+                    bars: vec![vec![(value, self.bar_style)]],
+                })
+                .collect();
+        }
+        let len = self.series.iter().map(|s| s.data.len()).max().unwrap_or(0);
+        (0..len)
+            .map(|i| {
+                let values = self.series.iter().map(|s| {
+                    let value = s.data.get(i).copied().unwrap_or(0);
+                    (value, self.bar_style.patch(s.style))
+                });
+                Group {
+                    label: self.categories.get(i).copied().unwrap_or(""),
+                    bars: match self.mode {
+                        BarMode::Grouped => values.map(|value| vec![value]).collect(),
+                        BarMode::Stacked => vec![values.collect()],
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Value mapped according to the scale, to be compared with the scaled maximum.
+    fn scaled(&self, value: u64) -> u64 {
+        match self.scale {
+            BarScale::Linear => value,
+            BarScale::Log10 => ((value as f64 + 1.0).log10() * 1_000_000.0).round() as u64,
+        }
+    }
+
+    fn symbol(&self, eighths: u64) -> &'static str {
+        if self.direction == Direction::Horizontal {
+            let set = &self.horizontal_bar_set;
+            return match eighths {
+                0 => set.empty,
+                1 => set.one_eighth,
+                2 => set.one_quarter,
+                3 => set.three_eighths,
+                4 => set.half,
+                5 => set.five_eighths,
+                6 => set.three_quarters,
+                7 => set.seven_eighths,
+                _ => set.full,
+            };
+        }
+        match eighths {
+            0 => self.bar_set.empty,
+            1 => self.bar_set.one_eighth,
+            2 => self.bar_set.one_quarter,
+            3 => self.bar_set.three_eighths,
+            4 => self.bar_set.half,
+            5 => self.bar_set.five_eighths,
+            6 => self.bar_set.three_quarters,
+            7 => self.bar_set.seven_eighths,
+            _ => self.bar_set.full,
+        }
+    }
+
+    /// Draws a bar in the given area, growing from its bottom or from its left depending on the
+    /// direction. The boundaries between stacked segments are rounded to whole cells, so that
+    /// only the end of the bar is drawn with a partial symbol.
+    fn render_bar(&self, segments: &[(u64, Style)], max: u64, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        let horizontal = self.direction == Direction::Horizontal;
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                buf.get_mut(x, y)
+                    .set_symbol(self.symbol(0))
+                    .set_style(self.bar_style);
+            }
+        }
+        let max = std::cmp::max(self.scaled(max), 1);
+        let length = u64::from(if horizontal { area.width } else { area.height });
+        let total: u64 = segments.iter().map(|s| s.0).sum();
+        let top = std::cmp::min(self.scaled(total) * length * 8 / max, length * 8);
+        let mut cumulated = 0;
+        let mut start = 0;
+        for (k, &(value, style)) in segments.iter().enumerate() {
+            cumulated += value;
+            let end = if k + 1 == segments.len() {
+                top
+            } else {
+                let scaled = self.scaled(cumulated);
+                std::cmp::min((scaled * length * 2 + max) / (2 * max) * 8, top)
+            };
+            for step in start / 8..(end + 7) / 8 {
+                let symbol = self.symbol(end - step * 8);
+                let line = if horizontal {
+                    Rect::new(area.left() + step as u16, area.top(), 1, area.height)
+                } else {
+                    Rect::new(area.left(), area.bottom() - 1 - step as u16, area.width, 1)
+                };
+                for y in line.top()..line.bottom() {
+                    for x in line.left()..line.right() {
+                        buf.get_mut(x, y).set_symbol(symbol).set_style(style);
+                    }
+                }
+            }
+            start = std::cmp::max(start, end);
+        }
+
+        let value = match &self.value_formatter {
+            Some(ValueFormatter(formatter)) => formatter(total),
+            None => format!("{}", total),
+        };
+        let width = value.width() as u16;
+        if total == 0 {
+            return;
+        }
+        if horizontal {
+            if width <= area.width {
+                let y = area.top() + (area.height - 1) / 2;
+                buf.set_string(area.left(), y, value, self.value_style);
+            }
+        } else if width < area.width {
+            buf.set_string(
+                area.left() + (area.width - width) / 2,
+                area.bottom() - 1,
+                value,
+                self.value_style,
+            );
+        }
+    }
+
+    fn render_vertical(&self, groups: &[Group], max: u64, chart_area: Rect, buf: &mut Buffer) {
+        if chart_area.height < 2 {
+            return;
+        }
+        let mut x = chart_area.left();
+        for group in groups {
+            let group_width = group.bars.len() as u16 * self.bar_width;
+            if x + group_width + self.bar_gap > chart_area.right() {
+                break;
+            }
+            for (i, bar) in group.bars.iter().enumerate() {
+                let bar_area = Rect::new(
+                    x + i as u16 * self.bar_width,
+                    chart_area.top(),
+                    self.bar_width,
+                    chart_area.height - 1,
+                );
+                self.render_bar(bar, max, bar_area, buf);
+            }
+            buf.set_stringn(
+                x,
+                chart_area.bottom() - 1,
+                group.label,
+                group_width as usize,
+                self.label_style,
+            );
+            x += group_width + self.bar_gap;
+        }
+    }
+
+    /// Draws the bars from left to right, each category being labeled on the left of its first
+    /// row. The labels take at most half of the width.
+    fn render_horizontal(&self, groups: &[Group], max: u64, chart_area: Rect, buf: &mut Buffer) {
+        let label_width = groups
+            .iter()
+            .map(|g| g.label.width() as u16)
+            .max()
+            .unwrap_or(0)
+            .min(chart_area.width / 2);
+        let bars_left = chart_area.left() + label_width + u16::from(label_width > 0);
+        if bars_left >= chart_area.right() {
+            return;
+        }
+        let mut y = chart_area.top();
+        for group in groups {
+            let group_height = group.bars.len() as u16 * self.bar_width;
+            if y + group_height > chart_area.bottom() {
+                break;
+            }
+            for (i, bar) in group.bars.iter().enumerate() {
+                let bar_area = Rect::new(
+                    bars_left,
+                    y + i as u16 * self.bar_width,
+                    chart_area.right() - bars_left,
+                    self.bar_width,
+                );
+                self.render_bar(bar, max, bar_area, buf);
+            }
+            buf.set_stringn(
+                chart_area.left(),
+                y,
+                group.label,
+                label_width as usize,
+                self.label_style,
+            );
+            y += group_height + self.bar_gap;
+        }
+    }
+}
+
 impl<'a> Widget for BarChart<'a> {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, self.style);
 
-        let chart_area = match self.block.take() {
+        let mut chart_area = match self.block.take() {
             Some(b) => {
                 let inner_area = b.inner(area);
                 b.render(area, buf);
@@ -139,81 +498,39 @@ impl<'a> Widget for BarChart<'a> {
             None => area,
         };
 
-        if chart_area.height < 2 {
-            return;
-        }
-
-        let max = self
-            .max
-            .unwrap_or_else(|| self.data.iter().map(|t| t.1).max().unwrap_or_default());
-        let max_index = min(
-            (chart_area.width / (self.bar_width + self.bar_gap)) as usize,
-            self.data.len(),
-        );
-        let mut data = self
-            .data
-            .iter()
-            .take(max_index)
-            .map(|&(l, v)| {
-                (
-                    l,
-                    v * u64::from(chart_area.height - 1) * 8 / std::cmp::max(max, 1),
-                )
-            })
-            .collect::<Vec<(&str, u64)>>();
-        for j in (0..chart_area.height - 1).rev() {
-            for (i, d) in data.iter_mut().enumerate() {
-                let symbol = match d.1 {
-                    0 => self.bar_set.empty,
-                    1 => self.bar_set.one_eighth,
-                    2 => self.bar_set.one_quarter,
-                    3 => self.bar_set.three_eighths,
-                    4 => self.bar_set.half,
-                    5 => self.bar_set.five_eighths,
-                    6 => self.bar_set.three_quarters,
-                    7 => self.bar_set.seven_eighths,
-                    _ => self.bar_set.full,
-                };
-
-                for x in 0..self.bar_width {
-                    buf.get_mut(
-                        chart_area.left() + i as u16 * (self.bar_width + self.bar_gap) + x,
-                        chart_area.top() + j,
-                    )
-                    .set_symbol(symbol)
-                    .set_style(self.bar_style);
-                }
-
-                if d.1 > 8 {
-                    d.1 -= 8;
-                } else {
-                    d.1 = 0;
+        if self.legend && !self.series.is_empty() && chart_area.height > 0 {
+            let mut x = chart_area.left();
+            let y = chart_area.top();
+            for (i, series) in self.series.iter().enumerate() {
+                let separator = if i > 0 { "  " } else { "" };
+                let marker = Span::styled("■ ", self.bar_style.patch(series.style));
+                for span in [Span::raw(separator), marker, Span::raw(series.name)] {
+                    if x >= chart_area.right() {
+                        break;
+                    }
+                    x = buf.set_span(x, y, &span, chart_area.right() - x).0;
                 }
             }
+            chart_area.y += 1;
+            chart_area.height -= 1;
         }
 
-        for (i, &(label, value)) in self.data.iter().take(max_index).enumerate() {
-            if value != 0 {
-                let value_label = &self.values[i];
-                let width = value_label.width() as u16;
-                if width < self.bar_width {
-                    buf.set_string(
-                        chart_area.left()
-                            + i as u16 * (self.bar_width + self.bar_gap)
-                            + (self.bar_width - width) / 2,
-                        chart_area.bottom() - 2,
-                        value_label,
-                        self.value_style,
-                    );
-                }
-            }
-            buf.set_stringn(
-                chart_area.left() + i as u16 * (self.bar_width + self.bar_gap),
-                chart_area.bottom() - 1,
-                label,
-                self.bar_width as usize,
-                self.label_style,
-            );
+        if chart_area.area() == 0 {
+            return;
+        }
+
+        let groups = self.groups();
+        let max = self.max.unwrap_or_else(|| {
+            groups
+                .iter()
+                .flat_map(|g| g.bars.iter())
+                .map(|bar| bar.iter().map(|s| s.0).sum())
+                .max()
+                .unwrap_or_default()
+        });
+        match self.direction {
+            Direction::Vertical => self.render_vertical(&groups, max, chart_area, buf),
+            Direction::Horizontal => self.render_horizontal(&groups, max, chart_area, buf),
         }
     }
 }
@@ -0,0 +1,252 @@
+use crate::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Style, Styled},
+    text::Text,
+    widgets::{Block, Widget},
+};
+
+/// Size of the pixels of the font of a [`BigText`], relative to a cell of the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixelSize {
+    /// A pixel is a whole cell
+    Full,
+    /// A pixel is the upper or lower half of a cell
+    HalfHeight,
+    /// A pixel is the left or right half of a cell
+    HalfWidth,
+    /// A pixel is a quarter of a cell
+    Quadrant,
+}
+
+impl PixelSize {
+    /// Number of pixels per cell, horizontally and vertically
+    fn pixels_per_cell(self) -> (u16, u16) {
+        match self {
+            PixelSize::Full => (1, 1),
+            PixelSize::HalfHeight => (1, 2),
+            PixelSize::HalfWidth => (2, 1),
+            PixelSize::Quadrant => (2, 2),
+        }
+    }
+}
+
+const GLYPH_WIDTH: u16 = 3;
+const GLYPH_HEIGHT: u16 = 5;
+
+/// Glyphs of the characters from `' '` to `'_'`, as rows of 3 pixels from the top, the most
+/// significant bit being the leftmost pixel
+const GLYPHS: [[u8; GLYPH_HEIGHT as usize]; 64] = [
+    [0b000, 0b000, 0b000, 0b000, 0b000], // ' '
+    [0b010, 0b010, 0b010, 0b000, 0b010], // '!'
+    [0b101, 0b101, 0b000, 0b000, 0b000], // '"'
+    [0b101, 0b111, 0b101, 0b111, 0b101], // '#'
+    [0b011, 0b110, 0b010, 0b011, 0b110], // '$'
+    [0b101, 0b001, 0b010, 0b100, 0b101], // '%'
+    [0b010, 0b101, 0b010, 0b101, 0b011], // '&'
+    [0b010, 0b010, 0b000, 0b000, 0b000], // '\''
+    [0b001, 0b010, 0b010, 0b010, 0b001], // '('
+    [0b100, 0b010, 0b010, 0b010, 0b100], // ')'
+    [0b000, 0b101, 0b010, 0b101, 0b000], // '*'
+    [0b000, 0b010, 0b111, 0b010, 0b000], // '+'
+    [0b000, 0b000, 0b000, 0b010, 0b100], // ','
+    [0b000, 0b000, 0b111, 0b000, 0b000], // '-'
+    [0b000, 0b000, 0b000, 0b000, 0b010], // '.'
+    [0b001, 0b001, 0b010, 0b100, 0b100], // '/'
+    [0b111, 0b101, 0b101, 0b101, 0b111], // '0'
+    [0b010, 0b110, 0b010, 0b010, 0b111], // '1'
+    [0b111, 0b001, 0b111, 0b100, 0b111], // '2'
+    [0b111, 0b001, 0b111, 0b001, 0b111], // '3'
+    [0b101, 0b101, 0b111, 0b001, 0b001], // '4'
+    [0b111, 0b100, 0b111, 0b001, 0b111], // '5'
+    [0b111, 0b100, 0b111, 0b101, 0b111], // '6'
+    [0b111, 0b001, 0b001, 0b001, 0b001], // '7'
+    [0b111, 0b101, 0b111, 0b101, 0b111], // '8'
+    [0b111, 0b101, 0b111, 0b001, 0b111], // '9'
+    [0b000, 0b010, 0b000, 0b010, 0b000], // ':'
+    [0b000, 0b010, 0b000, 0b010, 0b100], // ';'
+    [0b001, 0b010, 0b100, 0b010, 0b001], // '<'
+    [0b000, 0b111, 0b000, 0b111, 0b000], // '='
+    [0b100, 0b010, 0b001, 0b010, 0b100], // '>'
+    [0b111, 0b001, 0b010, 0b000, 0b010], // '?'
+    [0b010, 0b101, 0b111, 0b100, 0b011], // '@'
+    [0b010, 0b101, 0b111, 0b101, 0b101], // 'A'
+    [0b110, 0b101, 0b110, 0b101, 0b110], // 'B'
+    [0b011, 0b100, 0b100, 0b100, 0b011], // 'C'
+    [0b110, 0b101, 0b101, 0b101, 0b110], // 'D'
+    [0b111, 0b100, 0b110, 0b100, 0b111], // 'E'
+    [0b111, 0b100, 0b110, 0b100, 0b100], // 'F'
+    [0b011, 0b100, 0b101, 0b101, 0b011], // 'G'
+    [0b101, 0b101, 0b111, 0b101, 0b101], // 'H'
+    [0b111, 0b010, 0b010, 0b010, 0b111], // 'I'
+    [0b001, 0b001, 0b001, 0b101, 0b010], // 'J'
+    [0b101, 0b101, 0b110, 0b101, 0b101], // 'K'
+    [0b100, 0b100, 0b100, 0b100, 0b111], // 'L'
+    [0b101, 0b111, 0b111, 0b101, 0b101], // 'M'
+    [0b110, 0b101, 0b101, 0b101, 0b101], // 'N'
+    [0b010, 0b101, 0b101, 0b101, 0b010], // 'O'
+    [0b110, 0b101, 0b110, 0b100, 0b100], // 'P'
+    [0b010, 0b101, 0b101, 0b111, 0b011], // 'Q'
+    [0b110, 0b101, 0b110, 0b101, 0b101], // 'R'
+    [0b011, 0b100, 0b010, 0b001, 0b110], // 'S'
+    [0b111, 0b010, 0b010, 0b010, 0b010], // 'T'
+    [0b101, 0b101, 0b101, 0b101, 0b111], // 'U'
+    [0b101, 0b101, 0b101, 0b101, 0b010], // 'V'
+    [0b101, 0b101, 0b111, 0b111, 0b101], // 'W'
+    [0b101, 0b101, 0b010, 0b101, 0b101], // 'X'
+    [0b101, 0b101, 0b010, 0b010, 0b010], // 'Y'
+    [0b111, 0b001, 0b010, 0b100, 0b111], // 'Z'
+    [0b110, 0b100, 0b100, 0b100, 0b110], // '['
+    [0b100, 0b100, 0b010, 0b001, 0b001], // '\\'
+    [0b011, 0b001, 0b001, 0b001, 0b011], // ']'
+    [0b010, 0b101, 0b000, 0b000, 0b000], // '^'
+    [0b000, 0b000, 0b000, 0b000, 0b111], // '_'
+];
+
+/// Block characters indexed by their painted quadrants (1: upper left, 2: upper right, 4: lower
+/// left, 8: lower right)
+const QUADRANTS: [&str; 16] = [
+    " ", "▘", "▝", "▀", "▖", "▌", "▞", "▛", "▗", "▚", "▐", "▜", "▄", "▙", "▟", "█",
+];
+
+fn glyph(c: char) -> &'static [u8; GLYPH_HEIGHT as usize] {
+    let c = c.to_ascii_uppercase();
+    match c {
+        ' '..='_' => &GLYPHS[c as usize - ' ' as usize],
+        _ => &GLYPHS['?' as usize - ' ' as usize],
+    }
+}
+
+/// A widget drawing text with a bitmap font, each character being 3 pixels wide and 5 pixels
+/// high.
+///
+/// The font covers the ASCII letters, digits and punctuation, lowercase letters being drawn as
+/// uppercase ones and the other characters as `?`. Characters are one pixel apart, and lines one
+/// pixel apart too. The pixels are drawn with block characters, see [`PixelSize`].
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{BigText, PixelSize};
+/// # use tui::layout::Alignment;
+/// # use tui::style::{Style, Color};
+/// BigText::new("12:45")
+///     .pixel_size(PixelSize::Quadrant)
+///     .alignment(Alignment::Center)
+///     .style(Style::DEFAULT.fg(Color::Cyan));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BigText<'a> {
+    block: Option<Block<'a>>,
+    text: Text<'a>,
+    /// Style of the widget area, under the styles of the spans of the text
+    style: Style,
+    pixel_size: PixelSize,
+    alignment: Alignment,
+}
+
+impl<'a> BigText<'a> {
+    pub fn new<T>(text: T) -> BigText<'a>
+    where
+        T: Into<Text<'a>>,
+    {
+        BigText {
+            block: None,
+            text: text.into(),
+            style: Style::DEFAULT,
+            pixel_size: PixelSize::Full,
+            alignment: Alignment::Left,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> BigText<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> BigText<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn pixel_size(mut self, pixel_size: PixelSize) -> BigText<'a> {
+        self.pixel_size = pixel_size;
+        self
+    }
+
+    pub fn alignment(mut self, alignment: Alignment) -> BigText<'a> {
+        self.alignment = alignment;
+        self
+    }
+}
+
+impl<'a> Styled for BigText<'a> {
+    fn patch_style(mut self, style: Style) -> BigText<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> Widget for BigText<'a> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let text_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+
+        let (pixels_x, pixels_y) = self.pixel_size.pixels_per_cell();
+        let line_height = (GLYPH_HEIGHT + 1 + pixels_y - 1) / pixels_y;
+        for (i, line) in self.text.lines.iter().enumerate() {
+            let top = text_area.top() as usize + i * line_height as usize;
+            if top >= text_area.bottom() as usize {
+                break;
+            }
+            let glyphs: Vec<(&[u8; GLYPH_HEIGHT as usize], Style)> = line
+                .0
+                .iter()
+                .flat_map(|span| span.content.chars().map(move |c| (glyph(c), span.style)))
+                .collect();
+            // Whether the pixel at the given position of the line is painted, with its style
+            let pixel = |x: u16, y: u16| {
+                let (glyph, style) = glyphs.get(usize::from(x / (GLYPH_WIDTH + 1)))?;
+                let column = x % (GLYPH_WIDTH + 1);
+                if column == GLYPH_WIDTH || y >= GLYPH_HEIGHT {
+                    return None;
+                }
+                let row = glyph[usize::from(y)];
+                (row >> (GLYPH_WIDTH - 1 - column) & 1 == 1).then(|| *style)
+            };
+            let line_pixels = (glyphs.len() as u16 * (GLYPH_WIDTH + 1)).saturating_sub(1);
+            let line_width = (line_pixels + pixels_x - 1) / pixels_x;
+            let left = match self.alignment {
+                Alignment::Left => 0,
+                Alignment::Center => text_area.width.saturating_sub(line_width) / 2,
+                Alignment::Right => text_area.width.saturating_sub(line_width),
+            };
+            for row in 0..line_height.min(text_area.bottom() - top as u16) {
+                for column in 0..line_width.min(text_area.width.saturating_sub(left)) {
+                    let mut quadrants = 0;
+                    let mut cell_style = None;
+                    for (bit, (dx, dy)) in [(0, 0), (1, 0), (0, 1), (1, 1)].iter().enumerate() {
+                        let x = column * pixels_x + dx % pixels_x;
+                        let y = row * pixels_y + dy % pixels_y;
+                        if let Some(style) = pixel(x, y) {
+                            quadrants |= 1 << bit;
+                            cell_style.get_or_insert(style);
+                        }
+                    }
+                    if let Some(style) = cell_style {
+                        buf.get_mut(text_area.left() + left + column, top as u16 + row)
+                            .set_symbol(QUADRANTS[quadrants])
+                            .set_style(style);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
-    buffer::Buffer,
+    buffer::{Buffer, Cell},
     layout::{Alignment, Rect},
-    style::Style,
-    symbols::line,
+    style::{Gradient, Style, Styled},
+    symbols::{border, line},
     text::{Span, Spans},
     widgets::{Borders, Widget},
 };
@@ -24,6 +24,137 @@ impl BorderType {
             BorderType::Thick => line::THICK,
         }
     }
+
+    pub fn border_symbols(border_type: BorderType) -> border::Set {
+        match border_type {
+            BorderType::Plain => border::PLAIN,
+            BorderType::Rounded => border::ROUNDED,
+            BorderType::Double => border::DOUBLE,
+            BorderType::Thick => border::THICK,
+        }
+    }
+}
+
+/// A side or a corner of the border of a [`Block`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorderPart {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Space left between the borders of a [`Block`] and its inner area.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::layout::Rect;
+/// # use tui::widgets::{Block, Borders, Padding};
+/// let block = Block::default()
+///     .borders(Borders::ALL)
+///     .padding(Padding::horizontal(2));
+/// assert_eq!(block.inner(Rect::new(0, 0, 10, 4)), Rect::new(3, 1, 4, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Padding {
+    pub left: u16,
+    pub right: u16,
+    pub top: u16,
+    pub bottom: u16,
+}
+
+impl Padding {
+    pub const ZERO: Padding = Padding {
+        left: 0,
+        right: 0,
+        top: 0,
+        bottom: 0,
+    };
+
+    pub const fn new(left: u16, right: u16, top: u16, bottom: u16) -> Padding {
+        Padding {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    /// Same padding on every side.
+    pub const fn uniform(value: u16) -> Padding {
+        Padding::new(value, value, value, value)
+    }
+
+    /// Padding on the left and right sides only.
+    pub const fn horizontal(value: u16) -> Padding {
+        Padding::new(value, value, 0, 0)
+    }
+
+    /// Padding on the top and bottom sides only.
+    pub const fn vertical(value: u16) -> Padding {
+        Padding::new(0, 0, value, value)
+    }
+}
+
+/// The border of a [`Block`] in which a [`Title`] is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TitlePosition {
+    Top,
+    Bottom,
+}
+
+/// A title of a [`Block`], added with [`Block::add_title`].
+///
+/// # Examples
+///
+/// ```
+/// # use tui::layout::Alignment;
+/// # use tui::widgets::{Block, Borders, Title, TitlePosition};
+/// Block::default()
+///     .borders(Borders::ALL)
+///     .add_title("Files")
+///     .add_title(Title::from("[q] quit").alignment(Alignment::Right))
+///     .add_title(
+///         Title::from("3 selected")
+///             .alignment(Alignment::Right)
+///             .position(TitlePosition::Bottom),
+///     );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Title<'a> {
+    pub content: Spans<'a>,
+    pub alignment: Alignment,
+    pub position: TitlePosition,
+}
+
+impl<'a> Title<'a> {
+    pub fn alignment(mut self, alignment: Alignment) -> Title<'a> {
+        self.alignment = alignment;
+        self
+    }
+
+    pub fn position(mut self, position: TitlePosition) -> Title<'a> {
+        self.position = position;
+        self
+    }
+}
+
+impl<'a, T> From<T> for Title<'a>
+where
+    T: Into<Spans<'a>>,
+{
+    fn from(content: T) -> Title<'a> {
+        Title {
+            content: content.into(),
+            alignment: Alignment::Left,
+            position: TitlePosition::Top,
+        }
+    }
 }
 
 /// Base widget to be used with all upper level ones. It may be used to display a box border around
@@ -48,15 +179,32 @@ pub struct Block<'a> {
     /// Title alignment. The default is top left of the block, but one can choose to place
     /// title in the top middle, or top right of the block
     title_alignment: Alignment,
+    /// Optional title placed in the bottom border of the block
+    title_bottom: Option<Spans<'a>>,
+    /// Alignment of the bottom title
+    title_bottom_alignment: Alignment,
+    /// Additional titles, each with its own alignment and position
+    titles: Vec<Title<'a>>,
     /// Visible borders
     borders: Borders,
     /// Border style
     border_style: Style,
-    /// Type of the border. The default is plain lines but one can choose to have rounded corners
-    /// or doubled lines instead.
-    border_type: BorderType,
+    /// Symbols of the border. The default is plain lines but one can choose to have rounded
+    /// corners, doubled lines or any custom set instead.
+    border_set: border::Set,
+    /// Styles patching the border style of some sides or corners, indexed by [`BorderPart`]
+    border_part_styles: [Option<Style>; 8],
+    /// Symbols replacing the ones of the border type for some sides or corners, indexed by
+    /// [`BorderPart`]
+    border_part_symbols: [Option<border::Set>; 8],
+    /// Whether the border symbols are joined with the lines already drawn in the buffer
+    merge_borders: bool,
+    /// Space between the borders and the inner area
+    padding: Padding,
     /// Widget style
     style: Style,
+    /// Optional gradient filling the background of the whole block
+    gradient: Option<Gradient>,
 }
 
 impl<'a> Default for Block<'a> {
@@ -64,10 +212,18 @@ impl<'a> Default for Block<'a> {
         Block {
             title: None,
             title_alignment: Alignment::Left,
+            title_bottom: None,
+            title_bottom_alignment: Alignment::Left,
+            titles: Vec::new(),
             borders: Borders::NONE,
             border_style: Style::DEFAULT,
-            border_type: BorderType::Plain,
+            border_set: border::PLAIN,
+            border_part_styles: [None; 8],
+            border_part_symbols: Default::default(),
+            merge_borders: false,
+            padding: Padding::ZERO,
             style: Style::DEFAULT,
+            gradient: None,
         }
     }
 }
@@ -98,6 +254,35 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Sets a title rendered in the bottom border.
+    ///
+    /// The title is only displayed if the block has a bottom border.
+    pub fn title_bottom<T>(mut self, title: T) -> Block<'a>
+    where
+        T: Into<Spans<'a>>,
+    {
+        self.title_bottom = Some(title.into());
+        self
+    }
+
+    pub fn title_bottom_alignment(mut self, alignment: Alignment) -> Block<'a> {
+        self.title_bottom_alignment = alignment;
+        self
+    }
+
+    /// Adds a title to the block. Titles sharing a border are laid out without overlapping: left
+    /// aligned titles from the left, right aligned titles from the right and centered titles in
+    /// the remaining space, in the order they were added.
+    ///
+    /// Bottom titles are only displayed if the block has a bottom border.
+    pub fn add_title<T>(mut self, title: T) -> Block<'a>
+    where
+        T: Into<Title<'a>>,
+    {
+        self.titles.push(title.into());
+        self
+    }
+
     pub fn border_style(mut self, style: Style) -> Block<'a> {
         self.border_style = style;
         self
@@ -108,13 +293,86 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Patches the border style of a single side or corner, e.g. to highlight the left edge of
+    /// the focused pane.
+    pub fn border_part_style(mut self, part: BorderPart, style: Style) -> Block<'a> {
+        self.border_part_styles[part as usize] = Some(style);
+        self
+    }
+
+    /// Draws a single side or corner with the matching symbol of the given set instead of the
+    /// one of the block, e.g. to mix border types.
+    pub fn border_part_symbols<S>(mut self, part: BorderPart, symbols: S) -> Block<'a>
+    where
+        S: Into<border::Set>,
+    {
+        self.border_part_symbols[part as usize] = Some(symbols.into());
+        self
+    }
+
+    /// Joins the border symbols with the lines already drawn where the border is rendered,
+    /// producing T-junctions and crosses instead of overwriting them.
+    ///
+    /// Combined with [`Layout::collapse_borders`](crate::layout::Layout::collapse_borders),
+    /// adjacent blocks share single lines as borders:
+    ///
+    /// ```
+    /// # use tui::backend::TestBackend;
+    /// # use tui::buffer::Buffer;
+    /// # use tui::layout::{Constraint, Direction, Layout};
+    /// # use tui::widgets::{Block, Borders};
+    /// # use tui::Terminal;
+    /// # let mut terminal = Terminal::new(TestBackend::new(9, 3)).unwrap();
+    /// terminal.draw(|f| {
+    ///     let chunks = Layout::new(Direction::Horizontal, [Constraint::Length(4), Constraint::Fill(1)])
+    ///         .collapse_borders(true)
+    ///         .split(f.size());
+    ///     for chunk in chunks {
+    ///         let block = Block::default().borders(Borders::ALL).merge_borders(true);
+    ///         f.render_widget(block, chunk);
+    ///     }
+    /// })?;
+    /// terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+    ///     "┌───┬───┐",
+    ///     "│   │   │",
+    ///     "└───┴───┘",
+    /// ]));
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn merge_borders(mut self, merge_borders: bool) -> Block<'a> {
+        self.merge_borders = merge_borders;
+        self
+    }
+
+    /// Shrinks the [inner area](Block::inner) of the block by the given padding, inside the
+    /// borders and titles.
+    pub fn padding(mut self, padding: Padding) -> Block<'a> {
+        self.padding = padding;
+        self
+    }
+
+    /// Fills the background of the block, borders included, with the given gradient. It takes
+    /// precedence over the background color of [`Block::style`].
+    pub fn gradient(mut self, gradient: Gradient) -> Block<'a> {
+        self.gradient = Some(gradient);
+        self
+    }
+
     pub fn borders(mut self, flag: Borders) -> Block<'a> {
         self.borders = flag;
         self
     }
 
     pub fn border_type(mut self, border_type: BorderType) -> Block<'a> {
-        self.border_type = border_type;
+        self.border_set = BorderType::border_symbols(border_type);
+        self
+    }
+
+    /// Draws the border with a custom set of symbols, such as
+    /// [`border::QUADRANT_OUTSIDE`](crate::symbols::border::QUADRANT_OUTSIDE). It replaces the
+    /// symbols of [`Block::border_type`].
+    pub fn border_set(mut self, border_set: border::Set) -> Block<'a> {
+        self.border_set = border_set;
         self
     }
 
@@ -125,7 +383,12 @@ impl<'a> Block<'a> {
             inner.x = inner.x.saturating_add(1).min(inner.right());
             inner.width = inner.width.saturating_sub(1);
         }
-        if self.borders.intersects(Borders::TOP) || self.title.is_some() {
+        let has_top_title = self.title.is_some()
+            || self
+                .titles
+                .iter()
+                .any(|title| title.position == TitlePosition::Top);
+        if self.borders.intersects(Borders::TOP) || has_top_title {
             inner.y = inner.y.saturating_add(1).min(inner.bottom());
             inner.height = inner.height.saturating_sub(1);
         }
@@ -135,104 +398,208 @@ impl<'a> Block<'a> {
         if self.borders.intersects(Borders::BOTTOM) {
             inner.height = inner.height.saturating_sub(1);
         }
+        inner.x = inner.x.saturating_add(self.padding.left).min(inner.right());
+        inner.width = inner.width.saturating_sub(self.padding.left);
+        inner.y = inner.y.saturating_add(self.padding.top).min(inner.bottom());
+        inner.height = inner.height.saturating_sub(self.padding.top);
+        inner.width = inner.width.saturating_sub(self.padding.right);
+        inner.height = inner.height.saturating_sub(self.padding.bottom);
         inner
     }
 }
 
+impl<'a> Styled for Block<'a> {
+    fn patch_style(mut self, style: Style) -> Block<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
 impl<'a> Widget for Block<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         if area.area() == 0 {
             return;
         }
         buf.set_style(area, self.style);
-        let symbols = BorderType::line_symbols(self.border_type);
+        if let Some(ref gradient) = self.gradient {
+            buf.set_gradient(area, gradient);
+        }
+        let style = |part: BorderPart| {
+            self.border_style
+                .patch_option(self.border_part_styles[part as usize])
+        };
+        let set =
+            |part: BorderPart| self.border_part_symbols[part as usize].unwrap_or(self.border_set);
 
-        // Sides
+        let draw = |cell: &mut Cell, symbol: &'static str, style: Style| {
+            let symbol = if self.merge_borders {
+                line::merge(&cell.symbol, symbol).unwrap_or(symbol)
+            } else {
+                symbol
+            };
+            cell.set_symbol(symbol).set_style(style);
+        };
+
+        // Sides, leaving out the cells of the corners
+        let has = |border| u16::from(self.borders.intersects(border));
+        let rows = area.top() + has(Borders::TOP)..area.bottom() - has(Borders::BOTTOM);
+        let columns = area.left() + has(Borders::LEFT)..area.right() - has(Borders::RIGHT);
         if self.borders.intersects(Borders::LEFT) {
-            for y in area.top()..area.bottom() {
-                buf.get_mut(area.left(), y)
-                    .set_symbol(symbols.vertical)
-                    .set_style(self.border_style);
+            for y in rows.clone() {
+                draw(
+                    buf.get_mut(area.left(), y),
+                    set(BorderPart::Left).vertical_left,
+                    style(BorderPart::Left),
+                );
             }
         }
         if self.borders.intersects(Borders::TOP) {
-            for x in area.left()..area.right() {
-                buf.get_mut(x, area.top())
-                    .set_symbol(symbols.horizontal)
-                    .set_style(self.border_style);
+            for x in columns.clone() {
+                draw(
+                    buf.get_mut(x, area.top()),
+                    set(BorderPart::Top).horizontal_top,
+                    style(BorderPart::Top),
+                );
             }
         }
         if self.borders.intersects(Borders::RIGHT) {
             let x = area.right() - 1;
-            for y in area.top()..area.bottom() {
-                buf.get_mut(x, y)
-                    .set_symbol(symbols.vertical)
-                    .set_style(self.border_style);
+            for y in rows {
+                draw(
+                    buf.get_mut(x, y),
+                    set(BorderPart::Right).vertical_right,
+                    style(BorderPart::Right),
+                );
             }
         }
         if self.borders.intersects(Borders::BOTTOM) {
             let y = area.bottom() - 1;
-            for x in area.left()..area.right() {
-                buf.get_mut(x, y)
-                    .set_symbol(symbols.horizontal)
-                    .set_style(self.border_style);
+            for x in columns {
+                draw(
+                    buf.get_mut(x, y),
+                    set(BorderPart::Bottom).horizontal_bottom,
+                    style(BorderPart::Bottom),
+                );
             }
         }
 
         // Corners
         if self.borders.contains(Borders::RIGHT | Borders::BOTTOM) {
-            buf.get_mut(area.right() - 1, area.bottom() - 1)
-                .set_symbol(symbols.bottom_right)
-                .set_style(self.border_style);
+            draw(
+                buf.get_mut(area.right() - 1, area.bottom() - 1),
+                set(BorderPart::BottomRight).bottom_right,
+                style(BorderPart::BottomRight),
+            );
         }
         if self.borders.contains(Borders::RIGHT | Borders::TOP) {
-            buf.get_mut(area.right() - 1, area.top())
-                .set_symbol(symbols.top_right)
-                .set_style(self.border_style);
+            draw(
+                buf.get_mut(area.right() - 1, area.top()),
+                set(BorderPart::TopRight).top_right,
+                style(BorderPart::TopRight),
+            );
         }
         if self.borders.contains(Borders::LEFT | Borders::BOTTOM) {
-            buf.get_mut(area.left(), area.bottom() - 1)
-                .set_symbol(symbols.bottom_left)
-                .set_style(self.border_style);
+            draw(
+                buf.get_mut(area.left(), area.bottom() - 1),
+                set(BorderPart::BottomLeft).bottom_left,
+                style(BorderPart::BottomLeft),
+            );
         }
         if self.borders.contains(Borders::LEFT | Borders::TOP) {
-            buf.get_mut(area.left(), area.top())
-                .set_symbol(symbols.top_left)
-                .set_style(self.border_style);
+            draw(
+                buf.get_mut(area.left(), area.top()),
+                set(BorderPart::TopLeft).top_left,
+                style(BorderPart::TopLeft),
+            );
         }
 
-        // Title
-        if let Some(title) = self.title {
-            let left_border_dx = if self.borders.intersects(Borders::LEFT) {
-                1
-            } else {
-                0
-            };
+        // Titles
+        self.render_titles(self.titles(TitlePosition::Top), area.top(), area, buf);
+        if self.borders.intersects(Borders::BOTTOM) {
+            let y = area.bottom() - 1;
+            self.render_titles(self.titles(TitlePosition::Bottom), y, area, buf);
+        }
+    }
+}
 
-            let right_border_dx = if self.borders.intersects(Borders::RIGHT) {
-                1
-            } else {
-                0
-            };
+impl<'a> Block<'a> {
+    /// Returns the titles to display in the given border with their alignment.
+    fn titles(&self, position: TitlePosition) -> Vec<(&Spans<'a>, Alignment)> {
+        let title = match position {
+            TitlePosition::Top => self.title.as_ref().map(|t| (t, self.title_alignment)),
+            TitlePosition::Bottom => self
+                .title_bottom
+                .as_ref()
+                .map(|t| (t, self.title_bottom_alignment)),
+        };
+        title
+            .into_iter()
+            .chain(
+                self.titles
+                    .iter()
+                    .filter(|t| t.position == position)
+                    .map(|t| (&t.content, t.alignment)),
+            )
+            .collect()
+    }
 
-            let title_area_width = area
-                .width
-                .saturating_sub(left_border_dx)
-                .saturating_sub(right_border_dx);
-
-            let title_dx = match self.title_alignment {
-                Alignment::Left => left_border_dx,
-                Alignment::Center => area.width.saturating_sub(title.width() as u16) / 2,
-                Alignment::Right => area
-                    .width
-                    .saturating_sub(title.width() as u16)
-                    .saturating_sub(right_border_dx),
-            };
+    /// Renders the titles of a border, keeping one column between titles sharing an alignment.
+    fn render_titles(
+        &self,
+        titles: Vec<(&Spans, Alignment)>,
+        title_y: u16,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let left_border_dx = if self.borders.intersects(Borders::LEFT) {
+            1
+        } else {
+            0
+        };
 
-            let title_x = area.left() + title_dx;
-            let title_y = area.top();
+        let right_border_dx = if self.borders.intersects(Borders::RIGHT) {
+            1
+        } else {
+            0
+        };
+
+        // Free columns are the ones in [left, right)
+        let mut left = area.left() + left_border_dx.min(area.width);
+        let mut right = area.right().saturating_sub(right_border_dx).max(left);
+
+        for (title, _) in titles.iter().filter(|(_, a)| *a == Alignment::Left) {
+            if left >= right {
+                break;
+            }
+            let (x, _) = buf.set_spans(left, title_y, title, right - left);
+            left = (x + 1).min(right);
+        }
+
+        for (title, _) in titles.iter().filter(|(_, a)| *a == Alignment::Right) {
+            if left >= right {
+                break;
+            }
+            let x = right.saturating_sub(title.width() as u16).max(left);
+            buf.set_spans(x, title_y, title, right - x);
+            right = x.saturating_sub(1).max(left);
+        }
 
-            buf.set_spans(title_x, title_y, &title, title_area_width);
+        let centered = titles
+            .iter()
+            .filter(|(_, a)| *a == Alignment::Center)
+            .collect::<Vec<_>>();
+        let centered_width = centered
+            .iter()
+            .map(|(title, _)| title.width() as u16 + 1)
+            .sum::<u16>()
+            .saturating_sub(1);
+        let mut x = (area.left() + area.width.saturating_sub(centered_width) / 2).max(left);
+        for (title, _) in centered {
+            if x >= right {
+                break;
+            }
+            let (end, _) = buf.set_spans(x, title_y, title, right - x);
+            x = end + 1;
         }
     }
 }
@@ -570,4 +937,30 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn inner_takes_into_account_the_padding() {
+        let area = Rect::new(0, 0, 10, 6);
+        assert_eq!(
+            Block::default()
+                .padding(Padding::new(1, 2, 3, 0))
+                .inner(area),
+            Rect::new(1, 3, 7, 3)
+        );
+        assert_eq!(
+            Block::default()
+                .borders(Borders::ALL)
+                .padding(Padding::uniform(1))
+                .inner(area),
+            Rect::new(2, 2, 6, 2)
+        );
+        // padding larger than the area
+        assert_eq!(
+            Block::default()
+                .borders(Borders::ALL)
+                .padding(Padding::horizontal(10))
+                .inner(area),
+            Rect::new(9, 1, 0, 4)
+        );
+    }
 }
@@ -0,0 +1,426 @@
+//! A calendar widget displaying one or more months as grids of days.
+//!
+//! The dates are represented by the minimal [`Date`] type of this module, which only knows about
+//! the proleptic Gregorian calendar.
+
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Widget},
+};
+use std::collections::HashMap;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Width of a month grid: seven days of two columns separated by one column.
+const MONTH_WIDTH: u16 = 20;
+/// Space between two month grids.
+const MONTH_GAP: u16 = 2;
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Days from monday, monday being 0.
+    pub fn number_from_monday(self) -> u8 {
+        self as u8
+    }
+
+    /// Two letters abbreviation of the day, as displayed in the calendar header.
+    pub fn short_name(self) -> &'static str {
+        match self {
+            Weekday::Monday => "Mo",
+            Weekday::Tuesday => "Tu",
+            Weekday::Wednesday => "We",
+            Weekday::Thursday => "Th",
+            Weekday::Friday => "Fr",
+            Weekday::Saturday => "Sa",
+            Weekday::Sunday => "Su",
+        }
+    }
+}
+
+/// A date of the proleptic Gregorian calendar.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::calendar::{Date, Weekday};
+/// let date = Date::new(2024, 2, 29).unwrap();
+/// assert_eq!(date.weekday(), Weekday::Thursday);
+/// assert_eq!(date.add_months(1), Date::new(2024, 3, 29).unwrap());
+/// assert!(Date::new(2023, 2, 29).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Returns `None` if the month or the day does not exist.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Date> {
+        if !(1..=12).contains(&month) || day < 1 || day > Date::days_in_month(year, month) {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Month from 1 (January) to 12 (December).
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    pub fn is_leap_year(year: i32) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
+    pub fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            2 if Date::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    pub fn weekday(self) -> Weekday {
+        // Sakamoto's method, giving 0 for sunday
+        const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let year = if self.month < 3 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let day = (year + year.div_euclid(4) - year.div_euclid(100)
+            + year.div_euclid(400)
+            + OFFSETS[usize::from(self.month - 1)]
+            + i32::from(self.day))
+        .rem_euclid(7);
+        Weekday::ALL[((day + 6) % 7) as usize]
+    }
+
+    /// Moves by the given number of months, the day being clamped to the length of the target
+    /// month.
+    pub fn add_months(self, months: i32) -> Date {
+        let index = self.year * 12 + i32::from(self.month) - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u8 + 1;
+        let day = self.day.min(Date::days_in_month(year, month));
+        Date { year, month, day }
+    }
+
+    /// First day of the month of this date.
+    fn first_of_month(self) -> Date {
+        Date { day: 1, ..self }
+    }
+}
+
+/// Provides the style of each date displayed by a [`Monthly`] calendar.
+pub trait DateStyler {
+    fn get_style(&self, date: Date) -> Style;
+}
+
+/// A [`DateStyler`] holding a style for some given dates.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::calendar::{CalendarEventStore, Date, DateStyler};
+/// # use tui::style::{Color, Style};
+/// let mut events = CalendarEventStore::default();
+/// let date = Date::new(2024, 1, 1).unwrap();
+/// events.add(date, Style::DEFAULT.fg(Color::Red));
+/// assert_eq!(events.get_style(date), Style::DEFAULT.fg(Color::Red));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CalendarEventStore(pub HashMap<Date, Style>);
+
+impl CalendarEventStore {
+    pub fn add(&mut self, date: Date, style: Style) {
+        self.0.insert(date, style);
+    }
+}
+
+impl DateStyler for CalendarEventStore {
+    fn get_style(&self, date: Date) -> Style {
+        self.0.get(&date).copied().unwrap_or(Style::DEFAULT)
+    }
+}
+
+/// A widget displaying the month of a date as a grid of days, one week per row.
+///
+/// Several consecutive months can be displayed by setting [`Monthly::months`]. They are then
+/// laid out as many per row as the area allows.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::calendar::{CalendarEventStore, Date, Monthly, Weekday};
+/// # use tui::style::{Color, Modifier, Style};
+/// let mut events = CalendarEventStore::default();
+/// events.add(Date::new(2024, 1, 15).unwrap(), Style::DEFAULT.fg(Color::Red));
+/// Monthly::new(Date::new(2024, 1, 1).unwrap(), events)
+///     .first_day_of_week(Weekday::Sunday)
+///     .show_month_header(Style::DEFAULT.add_modifier(Modifier::BOLD))
+///     .show_weekdays_header(Style::DEFAULT.add_modifier(Modifier::ITALIC))
+///     .months(3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Monthly<'a, S>
+where
+    S: DateStyler,
+{
+    block: Option<Block<'a>>,
+    /// A date within the first month to display
+    display_date: Date,
+    events: S,
+    first_day_of_week: Weekday,
+    default_style: Style,
+    /// Style of the days of the previous and next months, not displayed if `None`
+    surrounding_style: Option<Style>,
+    month_header_style: Option<Style>,
+    weekdays_header_style: Option<Style>,
+    months: u16,
+}
+
+impl<'a, S> Monthly<'a, S>
+where
+    S: DateStyler,
+{
+    pub fn new(display_date: Date, events: S) -> Monthly<'a, S> {
+        Monthly {
+            block: None,
+            display_date,
+            events,
+            first_day_of_week: Weekday::Monday,
+            default_style: Style::DEFAULT,
+            surrounding_style: None,
+            month_header_style: None,
+            weekdays_header_style: None,
+            months: 1,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Monthly<'a, S> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn first_day_of_week(mut self, weekday: Weekday) -> Monthly<'a, S> {
+        self.first_day_of_week = weekday;
+        self
+    }
+
+    /// Style of the days, patched with the one given by the [`DateStyler`].
+    pub fn default_style(mut self, style: Style) -> Monthly<'a, S> {
+        self.default_style = style;
+        self
+    }
+
+    /// Fill the first and last weeks with the days of the previous and next months.
+    pub fn show_surrounding(mut self, style: Style) -> Monthly<'a, S> {
+        self.surrounding_style = Some(style);
+        self
+    }
+
+    /// Display the month name and year above the grid.
+    pub fn show_month_header(mut self, style: Style) -> Monthly<'a, S> {
+        self.month_header_style = Some(style);
+        self
+    }
+
+    /// Display the abbreviated names of the days above the grid.
+    pub fn show_weekdays_header(mut self, style: Style) -> Monthly<'a, S> {
+        self.weekdays_header_style = Some(style);
+        self
+    }
+
+    /// Number of consecutive months to display, starting from the month of the display date.
+    pub fn months(mut self, months: u16) -> Monthly<'a, S> {
+        self.months = months.max(1);
+        self
+    }
+
+    fn header_height(&self) -> u16 {
+        u16::from(self.month_header_style.is_some())
+            + u16::from(self.weekdays_header_style.is_some())
+    }
+
+    /// Renders the month starting at `first`.
+    fn render_month(&self, first: Date, area: Rect, buf: &mut Buffer) {
+        let mut y = area.top();
+        if let Some(style) = self.month_header_style {
+            let title = format!(
+                "{} {}",
+                MONTH_NAMES[usize::from(first.month - 1)],
+                first.year
+            );
+            let x = area.left() + area.width.saturating_sub(title.len() as u16) / 2;
+            buf.set_stringn(x, y, &title, usize::from(area.width), style);
+            y += 1;
+        }
+        if y >= area.bottom() {
+            return;
+        }
+        if let Some(style) = self.weekdays_header_style {
+            let header = (0..7)
+                .map(|i| self.weekday_at(i).short_name())
+                .collect::<Vec<_>>()
+                .join(" ");
+            buf.set_stringn(area.left(), y, &header, usize::from(area.width), style);
+            y += 1;
+        }
+
+        let days = Date::days_in_month(first.year, first.month);
+        let previous = first.add_months(-1);
+        let previous_days = Date::days_in_month(previous.year, previous.month);
+        let next = first.add_months(1);
+        let lead = (7 + first.weekday().number_from_monday()
+            - self.first_day_of_week.number_from_monday())
+            % 7;
+        let cells = u16::from(lead) + u16::from(days);
+        let weeks = (cells + 6) / 7;
+        for week in 0..weeks {
+            if y >= area.bottom() {
+                return;
+            }
+            for weekday in 0..7u16 {
+                let index = (week * 7 + weekday) as i32 - i32::from(lead) + 1;
+                let (date, base_style) = if index < 1 {
+                    let day = (i32::from(previous_days) + index) as u8;
+                    (Date { day, ..previous }, self.surrounding_style)
+                } else if index > i32::from(days) {
+                    let day = (index - i32::from(days)) as u8;
+                    (Date { day, ..next }, self.surrounding_style)
+                } else {
+                    let day = index as u8;
+                    (Date { day, ..first }, Some(self.default_style))
+                };
+                let base_style = match base_style {
+                    Some(style) => style,
+                    None => continue,
+                };
+                let x = area.left() + weekday * 3;
+                if x + 2 > area.right() {
+                    break;
+                }
+                let style = base_style.patch(self.events.get_style(date));
+                buf.set_string(x, y, format!("{:>2}", date.day), style);
+            }
+            y += 1;
+        }
+    }
+
+    fn weekday_at(&self, column: u8) -> Weekday {
+        Weekday::ALL[usize::from((self.first_day_of_week.number_from_monday() + column) % 7)]
+    }
+}
+
+impl<'a, S> Widget for Monthly<'a, S>
+where
+    S: DateStyler,
+{
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+        if area.area() == 0 {
+            return;
+        }
+
+        let per_row = ((area.width + MONTH_GAP) / (MONTH_WIDTH + MONTH_GAP)).max(1);
+        // A month spans up to six weeks, separated from the next row of months by an empty line
+        let month_height = self.header_height() + 6;
+        let first = self.display_date.first_of_month();
+        for i in 0..self.months {
+            let x = area.left() + (i % per_row) * (MONTH_WIDTH + MONTH_GAP);
+            let y = area.top() + (i / per_row) * (month_height + 1);
+            if y >= area.bottom() {
+                break;
+            }
+            let month_area = Rect {
+                x,
+                y,
+                width: MONTH_WIDTH.min(area.right() - x),
+                height: month_height.min(area.bottom() - y),
+            };
+            self.render_month(first.add_months(i32::from(i)), month_area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_weekday() {
+        let cases = [
+            ((1970, 1, 1), Weekday::Thursday),
+            ((2000, 2, 29), Weekday::Tuesday),
+            ((2023, 12, 31), Weekday::Sunday),
+            ((2024, 1, 1), Weekday::Monday),
+            ((1, 1, 1), Weekday::Monday),
+        ];
+        for ((year, month, day), weekday) in cases {
+            assert_eq!(Date::new(year, month, day).unwrap().weekday(), weekday);
+        }
+    }
+
+    #[test]
+    fn date_add_months() {
+        let date = Date::new(2024, 1, 31).unwrap();
+        assert_eq!(date.add_months(1), Date::new(2024, 2, 29).unwrap());
+        assert_eq!(date.add_months(-1), Date::new(2023, 12, 31).unwrap());
+        assert_eq!(date.add_months(13), Date::new(2025, 2, 28).unwrap());
+        assert_eq!(date.add_months(-25), Date::new(2021, 12, 31).unwrap());
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style, Styled},
+    symbols,
+    text::Span,
+    widgets::{Axis, Block, Chart, Widget},
+};
+
+/// The open, high, low and close values of a period, displayed by a [`Candlestick`] chart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candle {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl Candle {
+    pub fn new(open: f64, high: f64, low: f64, close: f64) -> Candle {
+        Candle {
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+
+    /// Whether the value closed at or above its opening value.
+    pub fn is_up(&self) -> bool {
+        self.close >= self.open
+    }
+}
+
+/// A widget to display open/high/low/close data, one candle per period.
+///
+/// Each candle is made of a body spanning from the open to the close value, drawn with the up
+/// style if the value increased and the down style otherwise, and of a wick spanning from the low
+/// to the high value. The values are placed against a vertical [`Axis`], configured as for a
+/// [`Chart`]. When there are more candles than fit in the area, the most recent ones (at the end
+/// of the slice) are displayed.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Axis, Block, Borders, Candle, Candlestick, LabelFormat};
+/// # use tui::style::{Style, Color};
+/// let candles = [
+///     Candle::new(10.0, 12.5, 9.5, 12.0),
+///     Candle::new(12.0, 13.0, 10.0, 10.5),
+/// ];
+/// Candlestick::new(&candles)
+///     .block(Block::default().title("ACME").borders(Borders::ALL))
+///     .y_axis(Axis::default().bounds([9.0, 14.0]).auto_labels(LabelFormat::Decimal(1)))
+///     .up_style(Style::DEFAULT.fg(Color::Cyan))
+///     .candle_width(3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Candlestick<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    candles: &'a [Candle],
+    /// The vertical axis, on the left of the candles
+    y_axis: Axis<'a>,
+    /// Style of the candles closing at or above their opening value
+    up_style: Style,
+    /// Style of the candles closing below their opening value
+    down_style: Style,
+    candle_width: u16,
+    candle_gap: u16,
+}
+
+impl<'a> Candlestick<'a> {
+    pub fn new(candles: &'a [Candle]) -> Candlestick<'a> {
+        Candlestick {
+            block: None,
+            style: Style::DEFAULT,
+            candles,
+            y_axis: Axis::default(),
+            up_style: Style::DEFAULT.fg(Color::Green),
+            down_style: Style::DEFAULT.fg(Color::Red),
+            candle_width: 1,
+            candle_gap: 1,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Candlestick<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Candlestick<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn y_axis(mut self, axis: Axis<'a>) -> Candlestick<'a> {
+        self.y_axis = axis;
+        self
+    }
+
+    pub fn up_style(mut self, style: Style) -> Candlestick<'a> {
+        self.up_style = style;
+        self
+    }
+
+    pub fn down_style(mut self, style: Style) -> Candlestick<'a> {
+        self.down_style = style;
+        self
+    }
+
+    /// Set the width of the bodies of the candles. The wicks are drawn in their middle column.
+    pub fn candle_width(mut self, width: u16) -> Candlestick<'a> {
+        self.candle_width = width.max(1);
+        self
+    }
+
+    pub fn candle_gap(mut self, gap: u16) -> Candlestick<'a> {
+        self.candle_gap = gap;
+        self
+    }
+
+    /// Draws a candle whose left column is `x`.
+    fn render_candle(&self, candle: &Candle, x: u16, area: Rect, buf: &mut Buffer) {
+        let row = |value: f64| {
+            let offset = self.y_axis.offset(value, area.height).min(area.height - 1);
+            area.bottom() - 1 - offset
+        };
+        let style = if candle.is_up() {
+            self.up_style
+        } else {
+            self.down_style
+        };
+        let wick_x = x + self.candle_width / 2;
+        for y in row(candle.high)..=row(candle.low) {
+            buf.get_mut(wick_x, y)
+                .set_symbol(symbols::line::VERTICAL)
+                .set_style(style);
+        }
+        let (top, bottom) = if candle.is_up() {
+            (row(candle.close), row(candle.open))
+        } else {
+            (row(candle.open), row(candle.close))
+        };
+        for y in top..=bottom {
+            for x in x..x + self.candle_width {
+                let symbol = if candle.open == candle.close {
+                    symbols::line::HORIZONTAL
+                } else {
+                    symbols::block::FULL
+                };
+                buf.get_mut(x, y).set_symbol(symbol).set_style(style);
+            }
+        }
+    }
+}
+
+impl<'a> Styled for Candlestick<'a> {
+    fn patch_style(mut self, style: Style) -> Candlestick<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> Widget for Candlestick<'a> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+        if area.area() == 0 {
+            return;
+        }
+
+        self.y_axis.generate_labels(area.height, false);
+        let labels_width = self
+            .y_axis
+            .labels
+            .as_ref()
+            .map(|labels| labels.iter().map(Span::width).max().unwrap_or_default() as u16)
+            .unwrap_or_default()
+            .min(area.width / 3);
+        let mut candles_area = area;
+        if self.y_axis.labels.is_some() && labels_width + 1 < area.width {
+            let axis_x = area.left() + labels_width;
+            Chart::render_y_axis_labels(buf, &self.y_axis, area.left(), labels_width, area);
+            for y in area.top()..area.bottom() {
+                buf.get_mut(axis_x, y)
+                    .set_symbol(symbols::line::VERTICAL)
+                    .set_style(self.y_axis.style);
+            }
+            candles_area.x = axis_x + 1;
+            candles_area.width = area.right() - candles_area.x;
+        }
+
+        let step = self.candle_width.saturating_add(self.candle_gap);
+        let count = if self.candle_width > candles_area.width {
+            0
+        } else {
+            (candles_area.width.saturating_add(self.candle_gap) / step) as usize
+        };
+        let skipped = self.candles.len().saturating_sub(count);
+        for (i, candle) in self.candles[skipped..].iter().enumerate() {
+            let x = candles_area.left() + i as u16 * step;
+            self.render_candle(candle, x, candles_area, buf);
+        }
+
+        if let Some(title) = &self.y_axis.title {
+            let width = candles_area.width;
+            buf.set_spans(candles_area.left(), candles_area.top(), title, width);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{BezierCurve, Painter, Shape},
+};
+
+/// Shape to draw a circular arc centered on (x_center, y_center)
+///
+/// Angles are in degrees, counter-clockwise from the positive x axis. The arc goes from
+/// `start_angle` to `end_angle`, clockwise if `end_angle` is lower than `start_angle`. A filled
+/// arc is drawn as the circular sector between the arc and the center.
+#[derive(Debug, Clone)]
+pub struct Arc {
+    pub x_center: f64,
+    pub y_center: f64,
+    pub radius: f64,
+    pub start_angle: f64,
+    pub end_angle: f64,
+    pub filled: bool,
+    pub color: Color,
+}
+
+impl Arc {
+    /// Returns the cubic Bézier curves approximating the arc, each spanning at most 90 degrees.
+    pub fn segments(&self) -> Vec<BezierCurve> {
+        let sweep = self.end_angle - self.start_angle;
+        if !sweep.is_finite() {
+            return Vec::new();
+        }
+        let count = (sweep.abs() / 90.0).ceil().max(1.0) as usize;
+        let step = (sweep / count as f64).to_radians();
+        // Distance of the control points to the end points along the tangents
+        let k = 4.0 / 3.0 * (step / 4.0).tan() * self.radius;
+        let point = |angle: f64| {
+            (
+                self.x_center + self.radius * angle.cos(),
+                self.y_center + self.radius * angle.sin(),
+            )
+        };
+        (0..count)
+            .map(|i| {
+                let a = self.start_angle.to_radians() + step * i as f64;
+                let b = a + step;
+                let p0 = point(a);
+                let p3 = point(b);
+                BezierCurve {
+                    p0,
+                    p1: (p0.0 - k * a.sin(), p0.1 + k * a.cos()),
+                    p2: (p3.0 + k * b.sin(), p3.1 - k * b.cos()),
+                    p3,
+                    color: self.color,
+                }
+            })
+            .collect()
+    }
+
+    /// Whether the angle between the positive x axis and the direction of (x, y) from the center
+    /// is covered by the arc.
+    fn covers(&self, x: f64, y: f64) -> bool {
+        let sweep = self.end_angle - self.start_angle;
+        if sweep.abs() >= 360.0 {
+            return true;
+        }
+        let angle = (y - self.y_center).atan2(x - self.x_center).to_degrees();
+        if sweep >= 0.0 {
+            (angle - self.start_angle).rem_euclid(360.0) <= sweep
+        } else {
+            (self.start_angle - angle).rem_euclid(360.0) <= -sweep
+        }
+    }
+}
+
+impl Shape for Arc {
+    fn draw(&self, painter: &mut Painter) {
+        if self.filled {
+            let radius = self.radius.abs();
+            painter.paint_region(
+                [self.x_center - radius, self.x_center + radius],
+                [self.y_center - radius, self.y_center + radius],
+                true,
+                self.color,
+                |x, y| (x - self.x_center).hypot(y - self.y_center) <= radius && self.covers(x, y),
+            );
+            return;
+        }
+        for segment in self.segments() {
+            segment.draw(painter);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arc_segments_stay_close_to_the_circle() {
+        let arcs = [(0.0, 90.0), (30.0, 300.0), (180.0, -45.0), (10.0, 10.5)];
+        for (start_angle, end_angle) in arcs {
+            let arc = Arc {
+                x_center: 3.0,
+                y_center: -2.0,
+                radius: 10.0,
+                start_angle,
+                end_angle,
+                filled: false,
+                color: Color::Reset,
+            };
+            let segments = arc.segments();
+            let first = segments.first().unwrap().p0;
+            let last = segments.last().unwrap().p3;
+            let expected_first = (
+                3.0 + 10.0 * start_angle.to_radians().cos(),
+                -2.0 + 10.0 * start_angle.to_radians().sin(),
+            );
+            let expected_last = (
+                3.0 + 10.0 * end_angle.to_radians().cos(),
+                -2.0 + 10.0 * end_angle.to_radians().sin(),
+            );
+            assert!((first.0 - expected_first.0).hypot(first.1 - expected_first.1) < 1e-9);
+            assert!((last.0 - expected_last.0).hypot(last.1 - expected_last.1) < 1e-9);
+            for segment in segments {
+                for i in 0..=100 {
+                    let (x, y) = segment.point_at(f64::from(i) / 100.0);
+                    let distance = (x - 3.0).hypot(y + 2.0);
+                    // The degree-3 approximation of a quarter circle is off by less than 0.03%.
+                    assert!((distance - 10.0).abs() < 10.0 * 3e-4, "{}", distance);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn arcs_cover_the_angles_they_sweep() {
+        let arc = Arc {
+            x_center: 0.0,
+            y_center: 0.0,
+            radius: 1.0,
+            start_angle: 300.0,
+            end_angle: 390.0,
+            filled: true,
+            color: Color::Reset,
+        };
+        assert!(arc.covers(1.0, 0.0));
+        assert!(arc.covers(1.0, 0.5));
+        assert!(arc.covers(0.5, -0.5));
+        assert!(!arc.covers(0.0, 1.0));
+        assert!(!arc.covers(-1.0, 0.0));
+
+        let arc = Arc {
+            start_angle: 90.0,
+            end_angle: 0.0,
+            ..arc
+        };
+        assert!(arc.covers(1.0, 1.0));
+        assert!(!arc.covers(-1.0, 1.0));
+        assert!(!arc.covers(1.0, -1.0));
+    }
+}
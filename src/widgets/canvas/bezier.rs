@@ -0,0 +1,157 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{Painter, Shape},
+};
+
+/// Shape to draw a cubic Bézier curve from `p0` to `p3` with `p1` and `p2` as control points
+///
+/// The curve is subdivided until its pieces are flat at the resolution of the grid, so that
+/// sharp bends are drawn as precisely as nearly straight parts.
+#[derive(Debug, Clone)]
+pub struct BezierCurve {
+    pub p0: (f64, f64),
+    pub p1: (f64, f64),
+    pub p2: (f64, f64),
+    pub p3: (f64, f64),
+    pub color: Color,
+}
+
+impl BezierCurve {
+    /// Returns the point of the curve at `t`, where `t` goes from 0.0 (`p0`) to 1.0 (`p3`).
+    pub fn point_at(&self, t: f64) -> (f64, f64) {
+        let u = 1.0 - t;
+        let a = u * u * u;
+        let b = 3.0 * u * u * t;
+        let c = 3.0 * u * t * t;
+        let d = t * t * t;
+        (
+            a * self.p0.0 + b * self.p1.0 + c * self.p2.0 + d * self.p3.0,
+            a * self.p0.1 + b * self.p1.1 + c * self.p2.1 + d * self.p3.1,
+        )
+    }
+
+    /// Splits the curve in two halves at `t = 0.5`.
+    fn split(&self) -> (BezierCurve, BezierCurve) {
+        let mid = |a: (f64, f64), b: (f64, f64)| ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+        let p01 = mid(self.p0, self.p1);
+        let p12 = mid(self.p1, self.p2);
+        let p23 = mid(self.p2, self.p3);
+        let p012 = mid(p01, p12);
+        let p123 = mid(p12, p23);
+        let p0123 = mid(p012, p123);
+        (
+            BezierCurve {
+                p0: self.p0,
+                p1: p01,
+                p2: p012,
+                p3: p0123,
+                color: self.color,
+            },
+            BezierCurve {
+                p0: p0123,
+                p1: p123,
+                p2: p23,
+                p3: self.p3,
+                color: self.color,
+            },
+        )
+    }
+
+    /// Draws the curve as a straight line once its control points are within half a point of the
+    /// grid from the positions they would have on the line, and subdivides it otherwise.
+    /// Pieces whose control points are all outside of the canvas on one side are skipped.
+    fn draw_flattened(&self, painter: &mut Painter, scale: (f64, f64), depth: usize) {
+        let points = [self.p0, self.p1, self.p2, self.p3];
+        let [left, right] = painter.context.x_bounds;
+        let [bottom, top] = painter.context.y_bounds;
+        if points.iter().all(|p| p.0 < left)
+            || points.iter().all(|p| p.0 > right)
+            || points.iter().all(|p| p.1 < bottom)
+            || points.iter().all(|p| p.1 > top)
+        {
+            return;
+        }
+        let distance =
+            |a: (f64, f64), b: (f64, f64)| ((a.0 - b.0) * scale.0).hypot((a.1 - b.1) * scale.1);
+        let third =
+            |a: (f64, f64), b: (f64, f64)| ((2.0 * a.0 + b.0) / 3.0, (2.0 * a.1 + b.1) / 3.0);
+        let deviation = distance(self.p1, third(self.p0, self.p3))
+            .max(distance(self.p2, third(self.p3, self.p0)));
+        if deviation > 0.5 && depth < MAX_SUBDIVISIONS {
+            let (first, second) = self.split();
+            first.draw_flattened(painter, scale, depth + 1);
+            second.draw_flattened(painter, scale, depth + 1);
+            return;
+        }
+        // Only the part of the line within the canvas is sampled, so that there are at most as many
+        // samples as twice the diagonal of the grid
+        let (t0, t1) = match painter.clip(self.p0, self.p3) {
+            Some(range) => range,
+            None => return,
+        };
+        let (dx, dy) = (self.p3.0 - self.p0.0, self.p3.1 - self.p0.1);
+        let start = (self.p0.0 + t0 * dx, self.p0.1 + t0 * dy);
+        let end = (self.p0.0 + t1 * dx, self.p0.1 + t1 * dy);
+        // Two samples per grid point along the line leave no gap between the points
+        let samples = (distance(start, end) * 2.0).ceil().max(1.0) as usize;
+        for i in 0..=samples {
+            let t = i as f64 / samples as f64;
+            let x = start.0 + (end.0 - start.0) * t;
+            let y = start.1 + (end.1 - start.1) * t;
+            if let Some((x, y)) = painter.get_point(x, y) {
+                painter.paint(x, y, self.color);
+            }
+        }
+    }
+}
+
+/// Maximum number of times a curve is halved to draw it
+const MAX_SUBDIVISIONS: usize = 16;
+
+impl Shape for BezierCurve {
+    fn draw(&self, painter: &mut Painter) {
+        let (x_scale, y_scale) = painter.scale();
+        let points = [self.p0, self.p1, self.p2, self.p3];
+        if !x_scale.is_finite()
+            || !y_scale.is_finite()
+            || points.iter().any(|(x, y)| !x.is_finite() || !y.is_finite())
+        {
+            return;
+        }
+        self.draw_flattened(painter, (x_scale, y_scale), 0);
+    }
+}
+
+/// Shape to draw a quadratic Bézier curve from `p0` to `p2` with `p1` as control point
+#[derive(Debug, Clone)]
+pub struct QuadraticBezierCurve {
+    pub p0: (f64, f64),
+    pub p1: (f64, f64),
+    pub p2: (f64, f64),
+    pub color: Color,
+}
+
+impl QuadraticBezierCurve {
+    /// Returns the cubic Bézier curve drawing the same curve.
+    pub fn to_cubic(&self) -> BezierCurve {
+        let toward_p1 = |p: (f64, f64)| {
+            (
+                p.0 + 2.0 / 3.0 * (self.p1.0 - p.0),
+                p.1 + 2.0 / 3.0 * (self.p1.1 - p.1),
+            )
+        };
+        BezierCurve {
+            p0: self.p0,
+            p1: toward_p1(self.p0),
+            p2: toward_p1(self.p2),
+            p3: self.p2,
+            color: self.color,
+        }
+    }
+}
+
+impl Shape for QuadraticBezierCurve {
+    fn draw(&self, painter: &mut Painter) {
+        self.to_cubic().draw(painter);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{Painter, Shape},
+};
+
+/// Shape to draw an axis-aligned ellipse centered on (x, y), filled or as an outline
+///
+/// The points of the grid are painted according to their distance to the center, which stays
+/// accurate at low resolutions. An ellipse smaller than a point of the grid is drawn as a single
+/// point.
+#[derive(Debug, Clone)]
+pub struct Ellipse {
+    pub x: f64,
+    pub y: f64,
+    pub x_radius: f64,
+    pub y_radius: f64,
+    pub filled: bool,
+    pub color: Color,
+}
+
+impl Shape for Ellipse {
+    fn draw(&self, painter: &mut Painter) {
+        let (x_radius, y_radius) = (self.x_radius.abs(), self.y_radius.abs());
+        let painted = painter.paint_region(
+            [self.x - x_radius, self.x + x_radius],
+            [self.y - y_radius, self.y + y_radius],
+            self.filled,
+            self.color,
+            |x, y| {
+                let (dx, dy) = ((x - self.x) / x_radius, (y - self.y) / y_radius);
+                dx * dx + dy * dy <= 1.0
+            },
+        );
+        if !painted {
+            if let Some((x, y)) = painter.get_point(self.x, self.y) {
+                painter.paint(x, y, self.color);
+            }
+        }
+    }
+}
+
+/// Shape to draw a circle centered on (x, y), filled or as an outline
+#[derive(Debug, Clone)]
+pub struct Circle {
+    pub x: f64,
+    pub y: f64,
+    pub radius: f64,
+    pub filled: bool,
+    pub color: Color,
+}
+
+impl Shape for Circle {
+    fn draw(&self, painter: &mut Painter) {
+        Ellipse {
+            x: self.x,
+            y: self.y,
+            x_radius: self.radius,
+            y_radius: self.radius,
+            filled: self.filled,
+            color: self.color,
+        }
+        .draw(painter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        buffer::Buffer,
+        layout::Rect,
+        symbols,
+        widgets::{canvas::Canvas, Widget},
+    };
+
+    fn render<S: Shape>(shape: S) -> Buffer {
+        let area = Rect::new(0, 0, 7, 7);
+        let mut buf = Buffer::empty(area);
+        Canvas::default()
+            .marker(symbols::Marker::Dot)
+            .x_bounds([0.0, 6.0])
+            .y_bounds([0.0, 6.0])
+            .paint(|ctx| ctx.draw(&shape))
+            .render(area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn circles_can_be_filled_or_outlined() {
+        let circle = Circle {
+            x: 3.5,
+            y: 2.5,
+            radius: 3.0,
+            filled: false,
+            color: Color::Reset,
+        };
+        let expected = Buffer::with_lines(vec![
+            "   •   ",
+            " •• •• ",
+            " •   • ",
+            "•     •",
+            " •   • ",
+            " •• •• ",
+            "   •   ",
+        ]);
+        assert_eq!(render(circle.clone()), expected);
+
+        let expected = Buffer::with_lines(vec![
+            "   •   ",
+            " ••••• ",
+            " ••••• ",
+            "•••••••",
+            " ••••• ",
+            " ••••• ",
+            "   •   ",
+        ]);
+        assert_eq!(
+            render(Circle {
+                filled: true,
+                ..circle
+            }),
+            expected
+        );
+    }
+
+    #[test]
+    fn tiny_ellipses_are_drawn_as_a_point() {
+        let ellipse = Ellipse {
+            x: 1.0,
+            y: 5.9,
+            x_radius: 0.1,
+            y_radius: 0.2,
+            filled: false,
+            color: Color::Reset,
+        };
+        let expected = Buffer::with_lines(vec![
+            " •     ",
+            "       ",
+            "       ",
+            "       ",
+            "       ",
+            "       ",
+            "       ",
+        ]);
+        assert_eq!(render(ellipse), expected);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{
+    style::{Color, Style},
+    symbols,
+    widgets::canvas::{PaintedCell, Painter, Shape},
+};
+
+const UPPER_HALF: &str = "▀";
+
+/// How the pixels of an [`Image`] are mapped to the cells of the canvas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageMode {
+    /// Each cell shows two pixels stacked on top of each other, as a half block whose foreground
+    /// and background colors are the colors of the pixels. The points painted on the canvas are
+    /// drawn over the image.
+    HalfBlock,
+    /// The points of the grid whose pixels are at least as bright as the threshold are painted
+    /// with the color of their pixel. With the braille marker, each cell shows 2x4 pixels, but a
+    /// single color.
+    Threshold(u8),
+}
+
+/// Shape to draw a raster image in the rectangle whose bottom left corner is (x, y)
+///
+/// The pixels are given row by row from the top, with 3 bytes per pixel (red, green, blue) or 4
+/// if they have an alpha channel. The image is scaled to fill the rectangle, each point of the
+/// canvas taking the color of the nearest pixel. Pixels with an alpha below 128 are not drawn.
+#[derive(Debug, Clone)]
+pub struct Image<'a> {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub pixels: &'a [u8],
+    /// Number of pixels in a row of the image
+    pub pixels_per_row: usize,
+    pub alpha: bool,
+    pub mode: ImageMode,
+}
+
+impl<'a> Image<'a> {
+    /// Returns the red, green and blue components of the pixel at the given fractions of the
+    /// width and height of the image, from its top left corner, or `None` if it is transparent.
+    fn sample(&self, u: f64, v: f64) -> Option<(u8, u8, u8)> {
+        let channels = if self.alpha { 4 } else { 3 };
+        if self.pixels_per_row == 0 || !(0.0..1.0).contains(&u) || !(0.0..1.0).contains(&v) {
+            return None;
+        }
+        let rows = self.pixels.len() / channels / self.pixels_per_row;
+        let column = (u * self.pixels_per_row as f64) as usize;
+        let row = (v * rows as f64) as usize;
+        if row >= rows {
+            return None;
+        }
+        let index = (row * self.pixels_per_row + column) * channels;
+        let pixel = &self.pixels[index..index + channels];
+        if self.alpha && pixel[3] < 128 {
+            return None;
+        }
+        Some((pixel[0], pixel[1], pixel[2]))
+    }
+
+    /// Returns the indices of the first and last columns and rows of a grid covered by the
+    /// image, given the number of columns and rows per canvas unit. The indices may be out of
+    /// the grid.
+    fn extent(&self, painter: &Painter, scale: (f64, f64)) -> Option<([isize; 2], [isize; 2])> {
+        let left = painter.context.x_bounds[0];
+        let top = painter.context.y_bounds[1];
+        let index = |value: f64| value.floor() as isize;
+        let columns = [
+            index((self.x - left) * scale.0),
+            index((self.x + self.width - left) * scale.0),
+        ];
+        let rows = [
+            index((top - self.y - self.height) * scale.1),
+            index((top - self.y) * scale.1),
+        ];
+        if !scale.0.is_normal()
+            || !scale.1.is_normal()
+            || columns[0] > columns[1]
+            || rows[0] > rows[1]
+        {
+            return None;
+        }
+        Some((columns, rows))
+    }
+
+    fn draw_half_blocks(&self, painter: &mut Painter) {
+        let columns = painter.context.grid.width();
+        let rows = painter.context.grid.height();
+        let [left, right] = painter.context.x_bounds;
+        let [bottom, top] = painter.context.y_bounds;
+        // Cells per canvas unit, as for the labels
+        let scale = (
+            f64::from(columns.saturating_sub(1)) / (right - left).abs(),
+            f64::from(rows.saturating_sub(1)) / (top - bottom).abs(),
+        );
+        let (x_extent, y_extent) = match self.extent(painter, scale) {
+            Some(extent) => extent,
+            None => return,
+        };
+        let fraction = |extent: [isize; 2], index: u16, offset: f64| {
+            (index as f64 - extent[0] as f64 + offset) / (extent[1] - extent[0] + 1) as f64
+        };
+        for y in 0..rows {
+            for x in 0..columns {
+                let u = fraction(x_extent, x, 0.5);
+                let upper = self.sample(u, fraction(y_extent, y, 0.25));
+                let lower = self.sample(u, fraction(y_extent, y, 0.75));
+                let (symbol, style) = match (upper, lower) {
+                    (Some(upper), Some(lower)) => {
+                        (UPPER_HALF, Style::DEFAULT.fg(rgb(upper)).bg(rgb(lower)))
+                    }
+                    (Some(upper), None) => (UPPER_HALF, Style::DEFAULT.fg(rgb(upper))),
+                    (None, Some(lower)) => (symbols::bar::HALF, Style::DEFAULT.fg(rgb(lower))),
+                    (None, None) => continue,
+                };
+                painter.context.cells.push(PaintedCell {
+                    x,
+                    y,
+                    symbol,
+                    style,
+                });
+            }
+        }
+    }
+
+    fn draw_points(&self, painter: &mut Painter, threshold: u8) {
+        let (x_extent, y_extent) = match self.extent(painter, painter.scale()) {
+            Some(extent) => extent,
+            None => return,
+        };
+        let clamp = |extent: [isize; 2], max: f64| extent[0].max(0)..=extent[1].min(max as isize);
+        let fraction = |extent: [isize; 2], index: isize| {
+            (index as f64 - extent[0] as f64 + 0.5) / (extent[1] - extent[0] + 1) as f64
+        };
+        for y in clamp(y_extent, painter.resolution.1) {
+            for x in clamp(x_extent, painter.resolution.0) {
+                let pixel = match self.sample(fraction(x_extent, x), fraction(y_extent, y)) {
+                    Some(pixel) => pixel,
+                    None => continue,
+                };
+                if luminance(pixel) >= threshold {
+                    painter.paint(x as usize, y as usize, rgb(pixel));
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Shape for Image<'a> {
+    fn draw(&self, painter: &mut Painter) {
+        match self.mode {
+            ImageMode::HalfBlock => self.draw_half_blocks(painter),
+            ImageMode::Threshold(threshold) => self.draw_points(painter, threshold),
+        }
+    }
+}
+
+fn rgb((r, g, b): (u8, u8, u8)) -> Color {
+    Color::Rgb(r, g, b)
+}
+
+/// Perceived brightness of a color, from 0 to 255
+fn luminance((r, g, b): (u8, u8, u8)) -> u8 {
+    ((299 * u32::from(r) + 587 * u32::from(g) + 114 * u32::from(b)) / 1000) as u8
+}
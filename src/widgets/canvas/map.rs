@@ -2,21 +2,140 @@ use crate::{
     style::Color,
     widgets::canvas::{
         world::{WORLD_HIGH_RESOLUTION, WORLD_LOW_RESOLUTION},
-        Painter, Shape,
+        Line, Painter, Shape,
     },
 };
+use std::collections::HashSet;
 
 #[derive(Debug, Clone, Copy)]
 pub enum MapResolution {
     Low,
     High,
+    /// The high resolution data with the points of each border joined by lines, so that the
+    /// borders remain continuous in zoomed views
+    Detailed,
 }
 
 impl MapResolution {
     fn data(self) -> &'static [(f64, f64)] {
         match self {
             MapResolution::Low => &WORLD_LOW_RESOLUTION,
-            MapResolution::High => &WORLD_HIGH_RESOLUTION,
+            MapResolution::High | MapResolution::Detailed => &WORLD_HIGH_RESOLUTION,
+        }
+    }
+}
+
+/// Consecutive points of the world data farther apart than this (in degrees) end a border and
+/// start the next one
+const MAX_WORLD_GAP: f64 = 10.0;
+
+/// Longitude of the points of the world data on the antimeridian, where borders are cut
+const ANTIMERIDIAN: f64 = 179.99;
+
+/// Splits the world data, which lists the points of its borders one after the other, into the
+/// borders.
+///
+/// Most borders are rings, ending when they come back to a point already listed. The others end
+/// at a jump to a distant point or at the antimeridian, along which they are cut.
+fn world_borders(points: &[(f64, f64)]) -> Vec<&[(f64, f64)]> {
+    let mut borders = Vec::new();
+    let mut seen = HashSet::new();
+    let mut start = 0;
+    for (i, &(x, y)) in points.iter().enumerate() {
+        if i > start {
+            let (previous_x, previous_y) = points[i - 1];
+            let on_antimeridian = previous_x.abs() >= ANTIMERIDIAN && x.abs() >= ANTIMERIDIAN;
+            if on_antimeridian || (x - previous_x).hypot(y - previous_y) > MAX_WORLD_GAP {
+                borders.push(&points[start..i]);
+                start = i;
+            }
+        }
+        let repeated = !seen.insert((x.to_bits(), y.to_bits()));
+        if repeated && i > start {
+            borders.push(&points[start..=i]);
+            start = i + 1;
+        }
+    }
+    if start < points.len() {
+        borders.push(&points[start..]);
+    }
+    borders
+}
+
+/// Polylines drawn by a [`MapLines`], whose points are usually (longitude, latitude) pairs
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MapData {
+    lines: Vec<Vec<(f64, f64)>>,
+}
+
+impl MapData {
+    pub fn new(lines: Vec<Vec<(f64, f64)>>) -> MapData {
+        MapData { lines }
+    }
+
+    pub fn lines(&self) -> &[Vec<(f64, f64)>] {
+        &self.lines
+    }
+
+    /// Load the lines of a GeoJSON document, which may be a geometry, a feature or a feature
+    /// collection. The lines of `LineString` and `MultiLineString` geometries and the rings of
+    /// `Polygon` and `MultiPolygon` geometries are loaded, the other geometries are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::canvas::MapData;
+    /// let data = MapData::from_geojson(
+    ///     r#"{"type": "LineString", "coordinates": [[2.35, 48.85], [13.4, 52.52]]}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(data.lines(), [vec![(2.35, 48.85), (13.4, 52.52)]]);
+    /// ```
+    #[cfg(feature = "geojson")]
+    pub fn from_geojson(json: &str) -> Result<MapData, serde_json::Error> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let mut lines = Vec::new();
+        geojson::collect_lines(&value, &mut lines);
+        Ok(MapData { lines })
+    }
+}
+
+#[cfg(feature = "geojson")]
+mod geojson {
+    use serde_json::Value;
+
+    fn array(value: &Value) -> impl Iterator<Item = &Value> {
+        value.as_array().into_iter().flatten()
+    }
+
+    fn line(coordinates: &Value) -> Vec<(f64, f64)> {
+        array(coordinates)
+            .filter_map(|position| Some((position.get(0)?.as_f64()?, position.get(1)?.as_f64()?)))
+            .collect()
+    }
+
+    pub(super) fn collect_lines(value: &Value, lines: &mut Vec<Vec<(f64, f64)>>) {
+        let coordinates = &value["coordinates"];
+        match value["type"].as_str() {
+            Some("FeatureCollection") => {
+                for feature in array(&value["features"]) {
+                    collect_lines(feature, lines);
+                }
+            }
+            Some("Feature") => collect_lines(&value["geometry"], lines),
+            Some("GeometryCollection") => {
+                for geometry in array(&value["geometries"]) {
+                    collect_lines(geometry, lines);
+                }
+            }
+            Some("LineString") => lines.push(line(coordinates)),
+            Some("MultiLineString") | Some("Polygon") => lines.extend(array(coordinates).map(line)),
+            Some("MultiPolygon") => {
+                for polygon in array(coordinates) {
+                    lines.extend(array(polygon).map(line));
+                }
+            }
+            _ => {}
         }
     }
 }
@@ -39,10 +158,163 @@ impl Default for Map {
 
 impl Shape for Map {
     fn draw(&self, painter: &mut Painter) {
-        for (x, y) in self.resolution.data() {
-            if let Some((x, y)) = painter.get_point(*x, *y) {
-                painter.paint(x, y, self.color);
+        match self.resolution {
+            MapResolution::Low | MapResolution::High => {
+                for (x, y) in self.resolution.data() {
+                    if let Some((x, y)) = painter.get_point(*x, *y) {
+                        painter.paint(x, y, self.color);
+                    }
+                }
+            }
+            MapResolution::Detailed => {
+                for border in world_borders(self.resolution.data()) {
+                    for points in border.windows(2) {
+                        draw_segment(painter, points[0], points[1], self.color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Shape to draw the lines of some [`MapData`] with the given color
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::canvas::{Canvas, MapData, MapLines};
+/// # use tui::style::Color;
+/// let data = MapData::new(vec![vec![(2.35, 48.85), (13.4, 52.52)]]);
+/// Canvas::default()
+///     .x_bounds([-180.0, 180.0])
+///     .y_bounds([-90.0, 90.0])
+///     .paint(|ctx| {
+///         ctx.draw(&MapLines {
+///             data: &data,
+///             color: Color::Yellow,
+///         });
+///     });
+/// ```
+#[derive(Debug, Clone)]
+pub struct MapLines<'a> {
+    pub data: &'a MapData,
+    pub color: Color,
+}
+
+impl<'a> Shape for MapLines<'a> {
+    fn draw(&self, painter: &mut Painter) {
+        for line in self.data.lines() {
+            if let [(x, y)] = line[..] {
+                if let Some((x, y)) = painter.get_point(x, y) {
+                    painter.paint(x, y, self.color);
+                }
+            }
+            for points in line.windows(2) {
+                draw_segment(painter, points[0], points[1], self.color);
             }
         }
     }
 }
+
+/// Draws the part of the segment from `a` to `b` within the bounds of the canvas.
+fn draw_segment(painter: &mut Painter, a: (f64, f64), b: (f64, f64), color: Color) {
+    let (t0, t1) = match painter.clip(a, b) {
+        Some(range) => range,
+        None => return,
+    };
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    Line {
+        x1: a.0 + t0 * dx,
+        y1: a.1 + t0 * dy,
+        x2: a.0 + t1 * dx,
+        y2: a.1 + t1 * dy,
+        color,
+    }
+    .draw(painter);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        buffer::Buffer,
+        layout::Rect,
+        symbols,
+        widgets::{canvas::Canvas, Widget},
+    };
+
+    #[test]
+    fn map_clips_custom_lines_to_the_canvas() {
+        let data = MapData::new(vec![vec![(-10.0, 1.0), (10.0, 1.0)], vec![(3.0, 2.0)]]);
+        let area = Rect::new(0, 0, 5, 3);
+        let mut buf = Buffer::empty(area);
+        Canvas::default()
+            .marker(symbols::Marker::Dot)
+            .x_bounds([0.0, 4.0])
+            .y_bounds([0.0, 2.0])
+            .paint(|ctx| {
+                ctx.draw(&MapLines {
+                    data: &data,
+                    color: Color::Reset,
+                })
+            })
+            .render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["   • ", "•••••", "     "]));
+    }
+
+    #[test]
+    fn world_borders_end_with_their_rings() {
+        let points = [
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (0.0, 1.0),
+            (0.0, 0.0),
+            (2.0, 2.0),
+            (3.0, 2.0),
+            (2.0, 2.0),
+            (179.999, 5.0),
+            (179.999, 6.0),
+            (170.0, 6.0),
+            (-170.0, 6.0),
+        ];
+        assert_eq!(
+            world_borders(&points),
+            [
+                &points[0..4],
+                &points[4..7],
+                &points[7..8],
+                &points[8..10],
+                &points[10..11],
+            ]
+        );
+    }
+
+    #[cfg(feature = "geojson")]
+    #[test]
+    fn map_data_can_be_loaded_from_geojson() {
+        let json = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": {},
+                    "geometry": {
+                        "type": "MultiPolygon",
+                        "coordinates": [[[[0, 0], [1, 0], [1, 1], [0, 0]]], [[[5, 5], [6, 5], [5, 5]]]]
+                    }
+                },
+                {"type": "Feature", "geometry": {"type": "Point", "coordinates": [3, 3]}},
+                {"type": "Feature", "geometry": null}
+            ]
+        }"#;
+        let data = MapData::from_geojson(json).unwrap();
+        assert_eq!(
+            data.lines(),
+            [
+                vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)],
+                vec![(5.0, 5.0), (6.0, 5.0), (5.0, 5.0)],
+            ]
+        );
+        assert!(MapData::from_geojson("{").is_err());
+    }
+}
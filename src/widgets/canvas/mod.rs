@@ -1,13 +1,25 @@
+mod arc;
+mod bezier;
+mod ellipse;
+mod image;
 mod line;
 mod map;
 mod points;
+mod polygon;
 mod rectangle;
+mod spline;
 mod world;
 
+pub use self::arc::Arc;
+pub use self::bezier::{BezierCurve, QuadraticBezierCurve};
+pub use self::ellipse::{Circle, Ellipse};
+pub use self::image::{Image, ImageMode};
 pub use self::line::Line;
-pub use self::map::{Map, MapResolution};
+pub use self::map::{Map, MapData, MapLines, MapResolution};
 pub use self::points::Points;
+pub use self::polygon::Polygon;
 pub use self::rectangle::Rectangle;
+pub use self::spline::Spline;
 
 use crate::{
     buffer::Buffer,
@@ -15,7 +27,7 @@ use crate::{
     style::{Color, Style},
     symbols,
     text::Spans,
-    widgets::{Block, Widget},
+    widgets::{Block, StatefulWidget, Widget},
 };
 use std::fmt::Debug;
 
@@ -32,10 +44,43 @@ pub struct Label<'a> {
     spans: Spans<'a>,
 }
 
+/// Cell of the canvas drawn with its own symbol and style, below the painted points of its layer
+#[derive(Debug, Clone)]
+struct PaintedCell {
+    x: u16,
+    y: u16,
+    symbol: &'static str,
+    style: Style,
+}
+
 #[derive(Debug, Clone)]
 struct Layer {
     string: String,
     colors: Vec<Color>,
+    cells: Vec<PaintedCell>,
+    name: Option<String>,
+    z_index: i16,
+    visible: bool,
+}
+
+impl Layer {
+    fn new(string: String, colors: Vec<Color>) -> Layer {
+        Layer {
+            string,
+            colors,
+            cells: Vec::new(),
+            name: None,
+            z_index: 0,
+            visible: true,
+        }
+    }
+}
+
+/// Returns the layer with the given name, if any.
+fn named_layer<'l>(layers: &'l mut [Layer], name: &str) -> Option<&'l mut Layer> {
+    layers
+        .iter_mut()
+        .find(|layer| layer.name.as_deref() == Some(name))
 }
 
 trait Grid: Debug {
@@ -43,6 +88,7 @@ trait Grid: Debug {
     fn height(&self) -> u16;
     fn resolution(&self) -> (f64, f64);
     fn paint(&mut self, x: usize, y: usize, color: Color);
+    fn is_painted(&self, x: usize, y: usize) -> bool;
     fn save(&self) -> Layer;
     fn reset(&mut self);
 }
@@ -84,10 +130,10 @@ impl Grid for BrailleGrid {
     }
 
     fn save(&self) -> Layer {
-        Layer {
-            string: String::from_utf16(&self.cells).unwrap(),
-            colors: self.colors.clone(),
-        }
+        Layer::new(
+            String::from_utf16(&self.cells).unwrap(),
+            self.colors.clone(),
+        )
     }
 
     fn reset(&mut self) {
@@ -108,6 +154,13 @@ impl Grid for BrailleGrid {
             *c = color;
         }
     }
+
+    fn is_painted(&self, x: usize, y: usize) -> bool {
+        let index = y / 4 * self.width as usize + x / 2;
+        self.cells
+            .get(index)
+            .map_or(false, |c| c & symbols::braille::DOTS[y % 4][x % 2] != 0)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -146,10 +199,7 @@ impl Grid for CharGrid {
     }
 
     fn save(&self) -> Layer {
-        Layer {
-            string: self.cells.iter().collect(),
-            colors: self.colors.clone(),
-        }
+        Layer::new(self.cells.iter().collect(), self.colors.clone())
     }
 
     fn reset(&mut self) {
@@ -170,6 +220,11 @@ impl Grid for CharGrid {
             *c = color;
         }
     }
+
+    fn is_painted(&self, x: usize, y: usize) -> bool {
+        let index = y * self.width as usize + x;
+        self.cells.get(index).map_or(false, |c| *c != ' ')
+    }
 }
 
 #[derive(Debug)]
@@ -229,6 +284,167 @@ impl<'a, 'b> Painter<'a, 'b> {
     pub fn paint(&mut self, x: usize, y: usize, color: Color) {
         self.context.grid.paint(x, y, color);
     }
+
+    /// Paint the area of unpainted points of the grid around a point, bounded by the points
+    /// already painted on the current layer and by the edges of the grid
+    ///
+    /// # Examples:
+    /// ```
+    /// use tui::{style::Color, symbols, widgets::canvas::{Painter, Context}};
+    ///
+    /// let mut ctx = Context::new(4, 4, [0.0, 4.0], [0.0, 4.0], symbols::Marker::Dot);
+    /// let mut painter = Painter::from(&mut ctx);
+    /// for i in 0..4 {
+    ///     painter.paint(i, 3 - i, Color::Red);
+    /// }
+    /// painter.flood_fill(0, 0, Color::Blue);
+    /// ```
+    pub fn flood_fill(&mut self, x: usize, y: usize, color: Color) {
+        // A canvas with no width or height has no points to fill
+        if self.resolution.0 < 0.0 || self.resolution.1 < 0.0 {
+            return;
+        }
+        let columns = self.resolution.0 as usize + 1;
+        let rows = self.resolution.1 as usize + 1;
+        if x >= columns || y >= rows {
+            return;
+        }
+        let mut stack = vec![(x, y)];
+        while let Some((x, y)) = stack.pop() {
+            if self.context.grid.is_painted(x, y) {
+                continue;
+            }
+            self.paint(x, y, color);
+            if x > 0 {
+                stack.push((x - 1, y));
+            }
+            if x + 1 < columns {
+                stack.push((x + 1, y));
+            }
+            if y > 0 {
+                stack.push((x, y - 1));
+            }
+            if y + 1 < rows {
+                stack.push((x, y + 1));
+            }
+        }
+    }
+
+    /// Returns the range of `t` for which `a + t * (b - a)` is within the bounds of the canvas,
+    /// `t` going from 0.0 to 1.0, or `None` if the segment from `a` to `b` misses the canvas.
+    fn clip(&self, a: (f64, f64), b: (f64, f64)) -> Option<(f64, f64)> {
+        let [left, right] = self.context.x_bounds;
+        let [bottom, top] = self.context.y_bounds;
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        // Liang-Barsky clipping
+        let (mut t0, mut t1) = (0.0_f64, 1.0_f64);
+        for (p, q) in [
+            (-dx, a.0 - left),
+            (dx, right - a.0),
+            (-dy, a.1 - bottom),
+            (dy, top - a.1),
+        ] {
+            if p == 0.0 {
+                if q < 0.0 {
+                    return None;
+                }
+            } else if p < 0.0 {
+                t0 = t0.max(q / p);
+            } else {
+                t1 = t1.min(q / p);
+            }
+        }
+        if t0 > t1 {
+            None
+        } else {
+            Some((t0, t1))
+        }
+    }
+
+    /// Number of grid points per canvas unit along each axis
+    fn scale(&self) -> (f64, f64) {
+        let width = (self.context.x_bounds[1] - self.context.x_bounds[0]).abs();
+        let height = (self.context.y_bounds[1] - self.context.y_bounds[0]).abs();
+        (self.resolution.0 / width, self.resolution.1 / height)
+    }
+
+    /// Canvas y coordinate of the center of a row of the grid
+    fn row_center(&self, y: usize) -> f64 {
+        let (_, y_scale) = self.scale();
+        self.context.y_bounds[1] - (y as f64 + 0.5) / y_scale
+    }
+
+    /// Paints the points of a row of the grid whose centers are between the given x coordinates.
+    fn paint_span(&mut self, y: usize, x_bounds: [f64; 2], color: Color) {
+        let (x_scale, _) = self.scale();
+        if !x_scale.is_finite() || x_bounds[0].is_nan() || x_bounds[1].is_nan() {
+            return;
+        }
+        let left = self.context.x_bounds[0];
+        let first = ((x_bounds[0] - left) * x_scale - 0.5).ceil().max(0.0);
+        let last = ((x_bounds[1] - left) * x_scale - 0.5)
+            .floor()
+            .min(self.resolution.0);
+        if first > last {
+            return;
+        }
+        for x in first as usize..=last as usize {
+            self.paint(x, y, color);
+        }
+    }
+
+    /// Paints the points of the grid whose centers are in a region within the given bounds, or
+    /// only the points of its boundary if it is not filled. Returns whether any point was painted.
+    fn paint_region<F>(
+        &mut self,
+        x_bounds: [f64; 2],
+        y_bounds: [f64; 2],
+        filled: bool,
+        color: Color,
+        contains: F,
+    ) -> bool
+    where
+        F: Fn(f64, f64) -> bool,
+    {
+        // A canvas with no width or height has no points to paint
+        if self.resolution.0 < 0.0 || self.resolution.1 < 0.0 {
+            return false;
+        }
+        let (x_scale, y_scale) = self.scale();
+        if !x_scale.is_finite() || !y_scale.is_finite() {
+            return false;
+        }
+        let left = self.context.x_bounds[0];
+        let top = self.context.y_bounds[1];
+        let index = |value: f64, max: f64| value.floor().max(0.0).min(max) as isize;
+        let columns = index((x_bounds[0] - left) * x_scale, self.resolution.0)
+            ..=index((x_bounds[1] - left) * x_scale, self.resolution.0);
+        let rows = index((top - y_bounds[1]) * y_scale, self.resolution.1)
+            ..=index((top - y_bounds[0]) * y_scale, self.resolution.1);
+        let contains_point = |x: isize, y: isize| {
+            contains(
+                left + (x as f64 + 0.5) / x_scale,
+                top - (y as f64 + 0.5) / y_scale,
+            )
+        };
+        let mut painted = false;
+        for y in rows {
+            for x in columns.clone() {
+                if !contains_point(x, y) {
+                    continue;
+                }
+                let boundary = !contains_point(x - 1, y)
+                    || !contains_point(x + 1, y)
+                    || !contains_point(x, y - 1)
+                    || !contains_point(x, y + 1);
+                if filled || boundary {
+                    self.paint(x as usize, y as usize, color);
+                    painted = true;
+                }
+            }
+        }
+        painted
+    }
 }
 
 impl<'a, 'b> From<&'a mut Context<'b>> for Painter<'a, 'b> {
@@ -250,6 +466,8 @@ pub struct Context<'a> {
     dirty: bool,
     layers: Vec<Layer>,
     labels: Vec<Label<'a>>,
+    /// Cells painted in the layer being drawn
+    cells: Vec<PaintedCell>,
 }
 
 impl<'a> Context<'a> {
@@ -272,6 +490,7 @@ impl<'a> Context<'a> {
             dirty: false,
             layers: Vec::new(),
             labels: Vec::new(),
+            cells: Vec::new(),
         }
     }
 
@@ -287,9 +506,94 @@ impl<'a> Context<'a> {
 
     /// Go one layer above in the canvas.
     pub fn layer(&mut self) {
-        self.layers.push(self.grid.save());
+        let layer = self.save_layer();
+        self.layers.push(layer);
+    }
+
+    /// Returns the layer holding what was drawn since the previous one, and starts a new one.
+    fn save_layer(&mut self) -> Layer {
+        let mut layer = self.grid.save();
+        layer.cells = std::mem::take(&mut self.cells);
         self.grid.reset();
         self.dirty = false;
+        layer
+    }
+
+    /// Go one layer above in the canvas, naming the layer holding what was drawn since the
+    /// previous one. The layer replaces the layer of the same name, if any.
+    ///
+    /// Layers are composed by increasing z-index, layers of the same z-index in the order they
+    /// were created. When the canvas is rendered with a [`CanvasState`], the named layers are kept
+    /// for the next frames, so that they only need to be drawn again when they change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::canvas::{Canvas, Map, MapResolution, Line};
+    /// # use tui::style::Color;
+    /// Canvas::default()
+    ///     .x_bounds([-180.0, 180.0])
+    ///     .y_bounds([-90.0, 90.0])
+    ///     .paint(|ctx| {
+    ///         if !ctx.has_layer("map") {
+    ///             ctx.draw(&Map {
+    ///                 resolution: MapResolution::High,
+    ///                 color: Color::White,
+    ///             });
+    ///             ctx.named_layer("map", -1);
+    ///         }
+    ///         ctx.draw(&Line {
+    ///             x1: 0.0,
+    ///             y1: 10.0,
+    ///             x2: 10.0,
+    ///             y2: 10.0,
+    ///             color: Color::Red,
+    ///         });
+    ///     });
+    /// ```
+    pub fn named_layer<S>(&mut self, name: S, z_index: i16)
+    where
+        S: Into<String>,
+    {
+        let name = name.into();
+        let index = self
+            .layers
+            .iter()
+            .position(|layer| layer.name.as_deref() == Some(&name));
+        let mut layer = self.save_layer();
+        layer.name = Some(name);
+        layer.z_index = z_index;
+        match index {
+            Some(index) => self.layers[index] = layer,
+            None => self.layers.push(layer),
+        }
+    }
+
+    /// Whether a layer with the given name exists.
+    pub fn has_layer(&self, name: &str) -> bool {
+        self.layers
+            .iter()
+            .any(|layer| layer.name.as_deref() == Some(name))
+    }
+
+    /// Remove the layer with the given name.
+    pub fn clear_layer(&mut self, name: &str) {
+        self.layers
+            .retain(|layer| layer.name.as_deref() != Some(name));
+    }
+
+    /// Show or hide the layer with the given name.
+    pub fn set_layer_visible(&mut self, name: &str, visible: bool) {
+        if let Some(layer) = named_layer(&mut self.layers, name) {
+            layer.visible = visible;
+        }
+    }
+
+    /// Change the z-index of the layer with the given name.
+    pub fn set_layer_z_index(&mut self, name: &str, z_index: i16) {
+        if let Some(layer) = named_layer(&mut self.layers, name) {
+            layer.z_index = z_index;
+        }
     }
 
     /// Print a string on the canvas at the given position
@@ -426,11 +730,143 @@ where
     }
 }
 
+/// State of a [`Canvas`] keeping its viewport and its named layers from one frame to the next.
+///
+/// The viewport is the part of the canvas coordinate space which is displayed. Once set, directly
+/// or by zooming or panning, it replaces the bounds of the canvas.
+///
+/// The named layers are described in [`Context::named_layer`]. They are dropped when the size,
+/// the bounds or the marker of the canvas change.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::canvas::CanvasState;
+/// let mut state = CanvasState::default();
+/// state.set_bounds([-180.0, 180.0], [-90.0, 90.0]);
+/// state.zoom(2.0, (0.0, 0.0));
+/// assert_eq!(state.x_bounds(), Some([-90.0, 90.0]));
+/// state.pan(-10.0, 5.0);
+/// assert_eq!(state.x_bounds(), Some([-100.0, 80.0]));
+/// assert_eq!(state.y_bounds(), Some([-40.0, 50.0]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CanvasState {
+    layers: Vec<Layer>,
+    /// Size, bounds and marker of the canvas the layers were drawn on
+    key: Option<LayersKey>,
+    /// Horizontal and vertical bounds replacing the bounds of the canvas
+    viewport: Option<([f64; 2], [f64; 2])>,
+}
+
+type LayersKey = (u16, u16, [f64; 2], [f64; 2], symbols::Marker);
+
+impl CanvasState {
+    /// Horizontal bounds of the viewport, if it is set.
+    pub fn x_bounds(&self) -> Option<[f64; 2]> {
+        self.viewport.map(|(x_bounds, _)| x_bounds)
+    }
+
+    /// Vertical bounds of the viewport, if it is set.
+    pub fn y_bounds(&self) -> Option<[f64; 2]> {
+        self.viewport.map(|(_, y_bounds)| y_bounds)
+    }
+
+    pub fn set_bounds(&mut self, x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        self.viewport = Some((x_bounds, y_bounds));
+    }
+
+    /// Unset the viewport, so that the bounds of the canvas are displayed.
+    pub fn reset_bounds(&mut self) {
+        self.viewport = None;
+    }
+
+    /// Zoom in (with a factor greater than 1) or out around a point, which stays at the same
+    /// place in the viewport. If the viewport is not set, the bounds of the canvas last rendered
+    /// with this state are zoomed.
+    pub fn zoom(&mut self, factor: f64, center: (f64, f64)) {
+        if !factor.is_normal() || factor < 0.0 {
+            return;
+        }
+        let scale = |bounds: [f64; 2], center: f64| {
+            [
+                center + (bounds[0] - center) / factor,
+                center + (bounds[1] - center) / factor,
+            ]
+        };
+        if let Some((x_bounds, y_bounds)) = self.current_bounds() {
+            self.viewport = Some((scale(x_bounds, center.0), scale(y_bounds, center.1)));
+        }
+    }
+
+    /// Move the viewport by the given distances, in canvas units. If the viewport is not set, the
+    /// bounds of the canvas last rendered with this state are moved.
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        if let Some((x_bounds, y_bounds)) = self.current_bounds() {
+            self.viewport = Some((
+                [x_bounds[0] + dx, x_bounds[1] + dx],
+                [y_bounds[0] + dy, y_bounds[1] + dy],
+            ));
+        }
+    }
+
+    fn current_bounds(&self) -> Option<([f64; 2], [f64; 2])> {
+        self.viewport.or_else(|| {
+            self.key
+                .map(|(_, _, x_bounds, y_bounds, _)| (x_bounds, y_bounds))
+        })
+    }
+
+    /// Whether a layer with the given name is kept.
+    pub fn has_layer(&self, name: &str) -> bool {
+        self.layers
+            .iter()
+            .any(|layer| layer.name.as_deref() == Some(name))
+    }
+
+    /// Drop the layer with the given name, so that it is drawn again in the next frame.
+    pub fn clear_layer(&mut self, name: &str) {
+        self.layers
+            .retain(|layer| layer.name.as_deref() != Some(name));
+    }
+
+    /// Drop all the layers.
+    pub fn clear(&mut self) {
+        self.layers.clear();
+    }
+
+    /// Show or hide the layer with the given name.
+    pub fn set_layer_visible(&mut self, name: &str, visible: bool) {
+        if let Some(layer) = named_layer(&mut self.layers, name) {
+            layer.visible = visible;
+        }
+    }
+
+    /// Change the z-index of the layer with the given name.
+    pub fn set_layer_z_index(&mut self, name: &str, z_index: i16) {
+        if let Some(layer) = named_layer(&mut self.layers, name) {
+            layer.z_index = z_index;
+        }
+    }
+}
+
 impl<'a, F> Widget for Canvas<'a, F>
 where
     F: Fn(&mut Context),
 {
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = CanvasState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl<'a, F> StatefulWidget for Canvas<'a, F>
+where
+    F: Fn(&mut Context),
+{
+    type State = CanvasState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         let canvas_area = match self.block.take() {
             Some(b) => {
                 let inner_area = b.inner(area);
@@ -444,6 +880,10 @@ where
 
         let width = canvas_area.width as usize;
 
+        if let Some((x_bounds, y_bounds)) = state.viewport {
+            self.x_bounds = x_bounds;
+            self.y_bounds = y_bounds;
+        }
         let painter = match self.painter {
             Some(ref p) => p,
             None => return,
@@ -457,12 +897,38 @@ where
             self.y_bounds,
             self.marker,
         );
+        let key = (
+            canvas_area.width,
+            canvas_area.height,
+            self.x_bounds,
+            self.y_bounds,
+            self.marker,
+        );
+        if state.key != Some(key) {
+            state.layers.clear();
+            state.key = Some(key);
+        }
+        ctx.layers = std::mem::take(&mut state.layers);
         // Paint to this context
         painter(&mut ctx);
         ctx.finish();
-
-        // Retreive painted points for each layer
-        for layer in ctx.layers {
+        state.layers = ctx
+            .layers
+            .iter()
+            .filter(|layer| layer.name.is_some())
+            .cloned()
+            .collect();
+        ctx.layers.sort_by_key(|layer| layer.z_index);
+
+        // Retreive painted cells and points for each layer
+        for layer in ctx.layers.into_iter().filter(|layer| layer.visible) {
+            for cell in layer.cells {
+                if cell.x < canvas_area.width && cell.y < canvas_area.height {
+                    buf.get_mut(cell.x + canvas_area.left(), cell.y + canvas_area.top())
+                        .set_symbol(cell.symbol)
+                        .set_style(cell.style);
+                }
+            }
             for (i, (ch, color)) in layer
                 .string
                 .chars()
@@ -479,6 +945,9 @@ where
         }
 
         // Finally draw the labels
+        if canvas_area.area() == 0 {
+            return;
+        }
         let left = self.x_bounds[0];
         let right = self.x_bounds[1];
         let top = self.y_bounds[1];
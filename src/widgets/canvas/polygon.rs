@@ -0,0 +1,80 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{Line, Painter, Shape},
+};
+
+/// Shape to draw a closed polygon going through the given points, filled or as an outline
+///
+/// Filled polygons are drawn with a scanline: on each row of the grid, the points between
+/// crossings of the edges are painted according to the even-odd rule, so self-intersecting
+/// polygons have holes where their parts overlap.
+#[derive(Debug, Clone)]
+pub struct Polygon<'a> {
+    pub points: &'a [(f64, f64)],
+    pub filled: bool,
+    pub color: Color,
+}
+
+impl<'a> Polygon<'a> {
+    /// Returns the x coordinates, in increasing order, where the edges cross the horizontal line
+    /// at `y`.
+    fn crossings(&self, y: f64) -> Vec<f64> {
+        let mut crossings: Vec<f64> = self
+            .edges()
+            .filter(|(a, b)| (a.1 <= y) != (b.1 <= y))
+            .map(|(a, b)| a.0 + (y - a.1) * (b.0 - a.0) / (b.1 - a.1))
+            .filter(|x| !x.is_nan())
+            .collect();
+        crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        crossings
+    }
+
+    fn edges(&self) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+        let closing = self.points.last().zip(self.points.first());
+        self.points
+            .windows(2)
+            .map(|w| (w[0], w[1]))
+            .chain(closing.map(|(a, b)| (*a, *b)))
+    }
+}
+
+impl<'a> Shape for Polygon<'a> {
+    fn draw(&self, painter: &mut Painter) {
+        if self.filled {
+            let rows = painter.resolution.1 as usize + 1;
+            for y in 0..rows {
+                let crossings = self.crossings(painter.row_center(y));
+                for span in crossings.chunks_exact(2) {
+                    painter.paint_span(y, [span[0], span[1]], self.color);
+                }
+            }
+        }
+        for (a, b) in self.edges() {
+            Line {
+                x1: a.0,
+                y1: a.1,
+                x2: b.0,
+                y2: b.1,
+                color: self.color,
+            }
+            .draw(painter);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polygon_crossings_are_sorted() {
+        let polygon = Polygon {
+            points: &[(0.0, 0.0), (4.0, 4.0), (8.0, 0.0), (8.0, 8.0), (0.0, 8.0)],
+            filled: true,
+            color: Color::Reset,
+        };
+        assert_eq!(polygon.crossings(2.0), vec![0.0, 2.0, 6.0, 8.0]);
+        assert_eq!(polygon.crossings(6.0), vec![0.0, 8.0]);
+        assert!(polygon.crossings(9.0).is_empty());
+    }
+}
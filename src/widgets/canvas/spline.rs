@@ -0,0 +1,75 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{BezierCurve, Painter, Shape},
+};
+
+/// Shape to draw a smooth curve going through all the given points
+///
+/// The curve is a Catmull-Rom spline: between two consecutive points, it is tangent to the line
+/// joining the points around them.
+#[derive(Debug, Clone)]
+pub struct Spline<'a> {
+    pub points: &'a [(f64, f64)],
+    pub color: Color,
+}
+
+impl<'a> Spline<'a> {
+    /// Returns the cubic Bézier curves joining the consecutive points.
+    pub fn segments(&self) -> Vec<BezierCurve> {
+        let points = self.points;
+        let point = |i: usize| points[i.min(points.len() - 1)];
+        (0..points.len().saturating_sub(1))
+            .map(|i| {
+                let (before, start, end, after) = (
+                    point(i.saturating_sub(1)),
+                    point(i),
+                    point(i + 1),
+                    point(i + 2),
+                );
+                BezierCurve {
+                    p0: start,
+                    p1: (
+                        start.0 + (end.0 - before.0) / 6.0,
+                        start.1 + (end.1 - before.1) / 6.0,
+                    ),
+                    p2: (
+                        end.0 - (after.0 - start.0) / 6.0,
+                        end.1 - (after.1 - start.1) / 6.0,
+                    ),
+                    p3: end,
+                    color: self.color,
+                }
+            })
+            .collect()
+    }
+}
+
+impl<'a> Shape for Spline<'a> {
+    fn draw(&self, painter: &mut Painter) {
+        for segment in self.segments() {
+            segment.draw(painter);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spline_goes_through_the_points() {
+        let spline = Spline {
+            points: &[(0.0, 0.0), (1.0, 2.0), (3.0, -1.0), (4.0, 0.0)],
+            color: Color::Reset,
+        };
+        let segments = spline.segments();
+        assert_eq!(segments.len(), 3);
+        for (segment, points) in segments.iter().zip(spline.points.windows(2)) {
+            assert_eq!(segment.p0, points[0]);
+            assert_eq!(segment.p3, points[1]);
+        }
+        // The tangent at the second point is parallel to the line joining its neighbours
+        assert_eq!(segments[0].p2, (0.5, 2.0 + 1.0 / 6.0));
+        assert_eq!(segments[1].p1, (1.5, 2.0 - 1.0 / 6.0));
+    }
+}
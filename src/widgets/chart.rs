@@ -1,4 +1,4 @@
-use std::{borrow::Cow, cmp::max};
+use std::{borrow::Cow, cmp::max, fmt, rc::Rc};
 
 use unicode_width::UnicodeWidthStr;
 
@@ -10,24 +10,122 @@ use crate::{
     symbols,
     text::{Span, Spans},
     widgets::{
-        canvas::{Canvas, Line, Points},
+        canvas::{Canvas, Context, Line, Painter, Points, Shape},
         Block, Borders, Widget,
     },
 };
 
+/// How values are mapped to positions along an [`Axis`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AxisScale {
+    Linear,
+    /// Positions are proportional to the base 10 logarithm of the values. Data points with
+    /// non-positive coordinates on such an axis are not drawn.
+    Log10,
+}
+
+/// Format of the labels generated by an [`Axis`], see [`Axis::auto_labels`].
+#[derive(Clone)]
+pub enum LabelFormat<'a> {
+    /// The values with the given number of decimals
+    Decimal(usize),
+    /// The values with an SI prefix and at most one decimal, e.g. `1.5k` or `20M`
+    Si,
+    /// The values formatted by the given function, e.g. to display timestamps as dates, see
+    /// [`LabelFormat::custom`]
+    Custom(Rc<dyn Fn(f64) -> String + 'a>),
+    /// The values are seconds since the Unix epoch, formatted in UTC as `HH:MM:SS`, `HH:MM` or
+    /// `MM-DD` depending on the interval between labels. Instead of being evenly spaced between
+    /// the bounds, the labels are placed on round times, e.g. every 5 minutes.
+    Time,
+}
+
+impl<'a> LabelFormat<'a> {
+    /// Returns the format of the values formatted by the given function.
+    pub fn custom<F>(formatter: F) -> LabelFormat<'a>
+    where
+        F: Fn(f64) -> String + 'a,
+    {
+        LabelFormat::Custom(Rc::new(formatter))
+    }
+
+    pub(super) fn format(&self, value: f64) -> String {
+        match self {
+            LabelFormat::Decimal(decimals) => format!("{:.*}", decimals, value),
+            LabelFormat::Si => format_si_label(value),
+            LabelFormat::Custom(formatter) => formatter(value),
+            LabelFormat::Time => format_time_label(value, 0.0),
+        }
+    }
+}
+
+impl<'a> fmt::Debug for LabelFormat<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LabelFormat::Decimal(decimals) => f.debug_tuple("Decimal").field(decimals).finish(),
+            LabelFormat::Si => f.write_str("Si"),
+            LabelFormat::Custom(_) => f.debug_tuple("Custom").field(&"..").finish(),
+            LabelFormat::Time => f.write_str("Time"),
+        }
+    }
+}
+
+/// Intervals between time labels, in seconds
+const TIME_STEPS: [f64; 20] = [
+    1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0, 1800.0, 3600.0, 7200.0,
+    10800.0, 21600.0, 43200.0, 86400.0, 172_800.0, 604_800.0,
+];
+
+/// Formats a time in seconds since the Unix epoch with the precision needed for labels `step`
+/// seconds apart.
+fn format_time_label(seconds: f64, step: f64) -> String {
+    let seconds = seconds.floor() as i64;
+    let (days, time) = (seconds.div_euclid(86400), seconds.rem_euclid(86400));
+    if step >= 86400.0 {
+        // Civil date from the number of days since the epoch, see
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = days + 719_468;
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        format!("{:02}-{:02}", month, day)
+    } else if step >= 60.0 {
+        format!("{:02}:{:02}", time / 3600, time % 3600 / 60)
+    } else {
+        format!(
+            "{:02}:{:02}:{:02}",
+            time / 3600,
+            time % 3600 / 60,
+            time % 60
+        )
+    }
+}
+
+/// Maximum number of intervals between generated labels
+const MAX_LABEL_INTERVALS: u16 = 10;
+
 /// An X or Y axis for the chart widget
 #[derive(Debug, Clone)]
 pub struct Axis<'a> {
     /// Title displayed next to axis end
-    title: Option<Spans<'a>>,
+    pub(super) title: Option<Spans<'a>>,
     /// Bounds for the axis (all data points outside these limits will not be represented)
     bounds: [f64; 2],
     /// A list of labels to put to the left or below the axis
-    labels: Option<Vec<Span<'a>>>,
+    pub(super) labels: Option<Vec<Span<'a>>>,
     /// The style used to draw the axis itself
-    style: Style,
+    pub(super) style: Style,
     /// The alignment of the labels of the Axis
     labels_alignment: Alignment,
+    scale: AxisScale,
+    /// Format of the labels generated when none are given
+    label_format: Option<LabelFormat<'a>>,
+    /// Values at which the labels are placed, if they are not evenly spaced
+    label_positions: Option<Vec<f64>>,
 }
 
 impl<'a> Default for Axis<'a> {
@@ -38,6 +136,9 @@ impl<'a> Default for Axis<'a> {
             labels: None,
             style: Style::DEFAULT,
             labels_alignment: Alignment::Left,
+            scale: AxisScale::Linear,
+            label_format: None,
+            label_positions: None,
         }
     }
 }
@@ -80,12 +181,170 @@ impl<'a> Axis<'a> {
 
     /// Defines the alignment of the labels of the axis.
     /// The alignment behaves differently based on the axis:
-    /// - Y-Axis: The labels are aligned within the area on the left of the axis
+    /// - Y-Axis: The labels are aligned within the area on the left of the axis, or on its right
+    ///   for the secondary axis (see [`Chart::y_axis2`])
     /// - X-Axis: The first X-axis label is aligned relative to the Y-axis
     pub fn labels_alignment(mut self, alignment: Alignment) -> Axis<'a> {
         self.labels_alignment = alignment;
         self
     }
+
+    /// Set the scale of the axis. Labels are still evenly spaced along the axis, so with
+    /// [`AxisScale::Log10`] they should follow a geometric progression. When no labels are given
+    /// to a logarithmic axis, labels are generated from its bounds, at most one per decade.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::{Axis, AxisScale};
+    /// Axis::default()
+    ///     .title("Latency (ms)")
+    ///     .bounds([1.0, 10_000.0])
+    ///     .scale(AxisScale::Log10);
+    /// ```
+    pub fn scale(mut self, scale: AxisScale) -> Axis<'a> {
+        self.scale = scale;
+        self
+    }
+
+    /// Generate the labels from the bounds of the axis instead of giving them with
+    /// [`Axis::labels`], which takes precedence. The labels are evenly spaced between the bounds,
+    /// as many as fit in the size of the chart, so that they stay in sync with the bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::{Axis, LabelFormat};
+    /// Axis::default()
+    ///     .title("Throughput (B/s)")
+    ///     .bounds([0.0, 2_500_000.0])
+    ///     .auto_labels(LabelFormat::Si);
+    /// ```
+    pub fn auto_labels(mut self, format: LabelFormat<'a>) -> Axis<'a> {
+        self.label_format = Some(format);
+        self
+    }
+
+    /// Maps a value to its position according to the scale of the axis.
+    fn transform(&self, value: f64) -> f64 {
+        match self.scale {
+            AxisScale::Linear => value,
+            AxisScale::Log10 => value.log10(),
+        }
+    }
+
+    fn transformed_bounds(&self) -> [f64; 2] {
+        [
+            self.transform(self.bounds[0]),
+            self.transform(self.bounds[1]),
+        ]
+    }
+
+    /// Generates the labels if none were given, for an axis of the given length. Labels of a
+    /// vertical axis are at least two rows apart, and labels of an horizontal one are separated by
+    /// at least one column.
+    pub(super) fn generate_labels(&mut self, length: u16, horizontal: bool) {
+        if self.labels.is_some() {
+            return;
+        }
+        if let Some(LabelFormat::Time) = self.label_format {
+            self.generate_time_labels(length, horizontal);
+            return;
+        }
+        let format = match (&self.label_format, self.scale) {
+            (Some(format), _) => format.clone(),
+            (None, AxisScale::Log10) => LabelFormat::custom(format_log_label),
+            (None, AxisScale::Linear) => return,
+        };
+        let [min, max] = self.transformed_bounds();
+        if !min.is_finite() || !max.is_finite() || max <= min {
+            return;
+        }
+        let max_intervals = if horizontal {
+            let label_width = self
+                .bounds
+                .iter()
+                .map(|&bound| format.format(bound).width() as u16)
+                .max()
+                .unwrap_or_default();
+            length / (label_width + 1)
+        } else {
+            length.saturating_sub(1) / 2
+        }
+        .clamp(1, MAX_LABEL_INTERVALS);
+        let intervals = match self.scale {
+            AxisScale::Linear => max_intervals,
+            AxisScale::Log10 => ((max - min).ceil() as u16).clamp(1, max_intervals),
+        };
+        let labels = (0..=intervals)
+            .map(|i| {
+                let position = min + (max - min) * f64::from(i) / f64::from(intervals);
+                let value = match self.scale {
+                    AxisScale::Linear => position,
+                    AxisScale::Log10 => 10f64.powf(position),
+                };
+                Span::from(format.format(value))
+            })
+            .collect();
+        self.labels = Some(labels);
+    }
+
+    /// Generates labels on round times, with the smallest interval for which they fit.
+    fn generate_time_labels(&mut self, length: u16, horizontal: bool) {
+        let [min, max] = self.bounds;
+        if !min.is_finite() || !max.is_finite() || max <= min {
+            return;
+        }
+        for &step in &TIME_STEPS {
+            let first = (min / step).ceil();
+            let count = ((max / step).floor() - first + 1.0).max(0.0) as u16;
+            let max_count = if horizontal {
+                let label_width = format_time_label(min, step).width() as u16;
+                length / (label_width + 1)
+            } else {
+                length.saturating_sub(1) / 2 + 1
+            };
+            if count <= max_count {
+                let positions: Vec<f64> =
+                    (0..count).map(|i| (first + f64::from(i)) * step).collect();
+                let labels = positions
+                    .iter()
+                    .map(|&value| Span::from(format_time_label(value, step)))
+                    .collect();
+                self.labels = Some(labels);
+                self.label_positions = Some(positions);
+                return;
+            }
+        }
+    }
+
+    /// Returns the offset of the value from the start of an axis drawn on `length` cells.
+    pub(super) fn offset(&self, value: f64, length: u16) -> u16 {
+        let [min, max] = self.transformed_bounds();
+        let ratio = (self.transform(value) - min) / (max - min);
+        (ratio * f64::from(length.saturating_sub(1))).round() as u16
+    }
+}
+
+/// Formats a value with an SI prefix and at most one decimal.
+fn format_si_label(value: f64) -> String {
+    const PREFIXES: [(f64, &str); 4] = [(1e12, "T"), (1e9, "G"), (1e6, "M"), (1e3, "k")];
+    let (value, prefix) = PREFIXES
+        .iter()
+        .find(|(factor, _)| value.abs() >= *factor)
+        .map_or((value, ""), |&(factor, prefix)| (value / factor, prefix));
+    let value = format!("{:.1}", value);
+    format!("{}{}", value.trim_end_matches(".0"), prefix)
+}
+
+/// Formats a value with as many decimals as needed to show its first significant digit.
+fn format_log_label(value: f64) -> String {
+    if value >= 1.0 {
+        format!("{:.0}", value)
+    } else {
+        let decimals = (-value.log10()).ceil() as usize;
+        format!("{:.*}", decimals, value)
+    }
 }
 
 /// Used to determine which style of graphing to use
@@ -95,6 +354,25 @@ pub enum GraphType {
     Scatter,
     /// Draw each point and lines between each point using the same marker
     Line,
+    /// Draw a line and fill the area between it and zero with a dimmed version of the dataset
+    /// color
+    Area,
+    /// Like [`GraphType::Area`], but the values are added to the ones of the previous stacked
+    /// datasets projected against the same vertical axis, and only the area between the two is
+    /// filled. Stacked datasets are expected to share the same x coordinates.
+    StackedArea,
+    /// Draw each point and join them with horizontal then vertical lines, i.e. each value holds
+    /// until the next point
+    Step,
+}
+
+/// Used to determine which vertical axis a dataset is projected against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YAxis {
+    /// The axis on the left of the graph, set with [`Chart::y_axis`]
+    Primary,
+    /// The axis on the right of the graph, set with [`Chart::y_axis2`]
+    Secondary,
 }
 
 /// A group of data points
@@ -110,6 +388,14 @@ pub struct Dataset<'a> {
     graph_type: GraphType,
     /// Style used to plot this dataset
     style: Style,
+    /// Vertical axis used to place the data points
+    y_axis: YAxis,
+    /// Join the points with monotone cubic curves instead of straight lines
+    smooth: bool,
+    /// Symbols drawn instead of the marker for the first points
+    point_symbols: &'a [&'a str],
+    /// Sizes of the first points, between 0 and 1
+    point_sizes: &'a [f64],
 }
 
 impl<'a> Default for Dataset<'a> {
@@ -120,6 +406,10 @@ impl<'a> Default for Dataset<'a> {
             marker: symbols::Marker::Dot,
             graph_type: GraphType::Scatter,
             style: Style::DEFAULT,
+            y_axis: YAxis::Primary,
+            smooth: false,
+            point_symbols: &[],
+            point_sizes: &[],
         }
     }
 }
@@ -152,6 +442,46 @@ impl<'a> Dataset<'a> {
         self.style = style;
         self
     }
+
+    /// Selects the vertical axis the data points are projected against. Datasets bound to
+    /// [`YAxis::Secondary`] fall back to the primary axis if the chart has no secondary axis.
+    pub fn y_axis(mut self, y_axis: YAxis) -> Dataset<'a> {
+        self.y_axis = y_axis;
+        self
+    }
+
+    /// Join the points of line and area graphs with smooth curves. The curves are monotone
+    /// between consecutive points, so they never overshoot the data. The x coordinates of the
+    /// points must be strictly increasing, otherwise straight lines are drawn.
+    pub fn smooth(mut self, smooth: bool) -> Dataset<'a> {
+        self.smooth = smooth;
+        self
+    }
+
+    /// Draws the n-th point with the n-th symbol instead of the marker, e.g. to tell categories
+    /// of points apart. Points without a symbol are drawn with the marker.
+    pub fn point_symbols(mut self, symbols: &'a [&'a str]) -> Dataset<'a> {
+        self.point_symbols = symbols;
+        self
+    }
+
+    /// Sets the size of the n-th point to the n-th value, from 0 (a single point) to 1. With the
+    /// braille marker, points are drawn as discs of braille dots. With the other markers, they
+    /// are drawn with denser symbols as their size grows. Point symbols take precedence over
+    /// sizes, and points without a size are drawn with the marker.
+    pub fn point_sizes(mut self, sizes: &'a [f64]) -> Dataset<'a> {
+        self.point_sizes = sizes;
+        self
+    }
+}
+
+/// Corner of the graph where the legend of a [`Chart`] is displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegendPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
 }
 
 /// A container that holds all the infos about where to display each elements of the chart (axis,
@@ -162,16 +492,24 @@ struct ChartLayout {
     title_x: Option<(u16, u16)>,
     /// Location of the title of the y axis
     title_y: Option<(u16, u16)>,
+    /// Location of the title of the secondary y axis
+    title_y2: Option<(u16, u16)>,
     /// Location of the first label of the x axis
     label_x: Option<u16>,
     /// Location of the first label of the y axis
     label_y: Option<u16>,
+    /// Location of the first label of the secondary y axis
+    label_y2: Option<u16>,
     /// Y coordinate of the horizontal axis
     axis_x: Option<u16>,
     /// X coordinate of the vertical axis
     axis_y: Option<u16>,
+    /// X coordinate of the secondary vertical axis
+    axis_y2: Option<u16>,
     /// Area of the legend
     legend_area: Option<Rect>,
+    /// Number of rows of each column of the legend
+    legend_rows: u16,
     /// Area of the graph
     graph_area: Rect,
 }
@@ -220,12 +558,16 @@ pub struct Chart<'a> {
     x_axis: Axis<'a>,
     /// The vertical axis
     y_axis: Axis<'a>,
+    /// The optional secondary vertical axis, displayed on the right of the graph
+    y_axis2: Option<Axis<'a>>,
     /// A reference to the datasets
     datasets: Vec<Dataset<'a>>,
     /// The widget base style
     style: Style,
     /// Constraints used to determine whether the legend should be shown or not
     hidden_legend_constraints: (Constraint, Constraint),
+    /// Corner of the legend, if displayed
+    legend_position: Option<LegendPosition>,
 }
 
 impl<'a> Chart<'a> {
@@ -234,9 +576,11 @@ impl<'a> Chart<'a> {
             block: None,
             x_axis: Axis::default(),
             y_axis: Axis::default(),
+            y_axis2: None,
             style: Style::DEFAULT,
             datasets,
             hidden_legend_constraints: (Constraint::Ratio(1, 4), Constraint::Ratio(1, 4)),
+            legend_position: Some(LegendPosition::TopRight),
         }
     }
 
@@ -260,6 +604,30 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Adds a secondary vertical axis on the right of the graph. Datasets are bound to it with
+    /// [`Dataset::y_axis`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::{Axis, Chart, Dataset, YAxis};
+    /// let datasets = vec![
+    ///     Dataset::default().name("cpu").data(&[(0.0, 20.0), (1.0, 80.0)]),
+    ///     Dataset::default()
+    ///         .name("memory")
+    ///         .y_axis(YAxis::Secondary)
+    ///         .data(&[(0.0, 1024.0), (1.0, 4096.0)]),
+    /// ];
+    /// Chart::new(datasets)
+    ///     .x_axis(Axis::default().bounds([0.0, 1.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 100.0]))
+    ///     .y_axis2(Axis::default().bounds([0.0, 8192.0]));
+    /// ```
+    pub fn y_axis2(mut self, axis: Axis<'a>) -> Chart<'a> {
+        self.y_axis2 = Some(axis);
+        self
+    }
+
     /// Set the constraints used to determine whether the legend should be shown or not.
     ///
     /// # Examples
@@ -281,6 +649,24 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Set the corner of the graph where the legend is displayed, or hide it with `None`. The
+    /// legend is in the top right corner by default.
+    ///
+    /// When the datasets don't fit in a single column within the height allowed by the
+    /// [hidden legend constraints](Chart::hidden_legend_constraints), they are laid out in
+    /// several columns, as long as the legend still fits in the allowed width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::{Chart, LegendPosition};
+    /// let _chart: Chart = Chart::new(vec![]).legend_position(Some(LegendPosition::BottomLeft));
+    /// ```
+    pub fn legend_position(mut self, position: Option<LegendPosition>) -> Chart<'a> {
+        self.legend_position = position;
+        self
+    }
+
     /// Compute the internal layout of the chart given the area. If the area is too small some
     /// elements may be automatically hidden
     fn layout(&self, area: Rect) -> ChartLayout {
@@ -309,8 +695,20 @@ impl<'a> Chart<'a> {
             x += 1;
         }
 
-        if x < area.right() && y > 1 {
-            layout.graph_area = Rect::new(x, area.top(), area.right() - x, y - area.top() + 1);
+        let mut right = area.right();
+        if let Some(labels) = self.y_axis2.as_ref().and_then(|a| a.labels.as_ref()) {
+            let labels_width = labels.iter().map(Span::width).max().unwrap_or_default() as u16;
+            let labels_width = labels_width.min(area.width / 3);
+            if x + labels_width + 1 < right {
+                right -= labels_width;
+                layout.label_y2 = Some(right);
+                right -= 1;
+                layout.axis_y2 = Some(right);
+            }
+        }
+
+        if x < right && y > 1 {
+            layout.graph_area = Rect::new(x, area.top(), right - x, y - area.top() + 1);
         }
 
         if let Some(ref title) = self.x_axis.title {
@@ -327,9 +725,15 @@ impl<'a> Chart<'a> {
             }
         }
 
-        if let Some(inner_width) = self.datasets.iter().map(|d| d.name.width() as u16).max() {
-            let legend_width = inner_width + 2;
-            let legend_height = self.datasets.len() as u16 + 2;
+        if let Some(title) = self.y_axis2.as_ref().and_then(|a| a.title.as_ref()) {
+            let w = title.width() as u16;
+            if w + 1 < layout.graph_area.width && layout.graph_area.height > 2 {
+                layout.title_y2 = Some((layout.graph_area.right() - w, area.top()));
+            }
+        }
+
+        let inner_width = self.datasets.iter().map(|d| d.name.width() as u16).max();
+        if let (Some(inner_width), Some(position)) = (inner_width, self.legend_position) {
             let max_legend_width = self
                 .hidden_legend_constraints
                 .0
@@ -338,16 +742,39 @@ impl<'a> Chart<'a> {
                 .hidden_legend_constraints
                 .1
                 .apply(layout.graph_area.height);
+            // Datasets are spread over as many columns as needed to fit in the allowed height
+            let count = self.datasets.len() as u16;
+            let max_rows = max_legend_height.saturating_sub(3).max(1);
+            let columns = (count + max_rows - 1) / max_rows;
+            let rows = (count + columns - 1) / columns;
+            let legend_width = columns * (inner_width + 1) + 1;
+            let legend_height = rows + 2;
             if inner_width > 0
                 && legend_width < max_legend_width
                 && legend_height < max_legend_height
             {
-                layout.legend_area = Some(Rect::new(
-                    layout.graph_area.right() - legend_width,
-                    layout.graph_area.top(),
-                    legend_width,
-                    legend_height,
-                ));
+                let graph_area = layout.graph_area;
+                // Leave room for the titles of the axes which share the same corners
+                let (x, y) = match position {
+                    LegendPosition::TopLeft => (
+                        graph_area.left(),
+                        graph_area.top() + u16::from(layout.title_y.is_some()),
+                    ),
+                    LegendPosition::TopRight => (
+                        graph_area.right() - legend_width,
+                        graph_area.top() + u16::from(layout.title_y2.is_some()),
+                    ),
+                    LegendPosition::BottomLeft => (
+                        graph_area.left(),
+                        graph_area.bottom() - legend_height - u16::from(layout.title_x.is_some()),
+                    ),
+                    LegendPosition::BottomRight => (
+                        graph_area.right() - legend_width,
+                        graph_area.bottom() - legend_height - u16::from(layout.title_x.is_some()),
+                    ),
+                };
+                layout.legend_area = Some(Rect::new(x, y, legend_width, legend_height));
+                layout.legend_rows = rows;
             }
         }
         layout
@@ -362,7 +789,7 @@ impl<'a> Chart<'a> {
             .unwrap_or_default();
 
         if let Some(first_x_label) = self.x_axis.labels.as_ref().and_then(|labels| labels.get(0)) {
-            let first_label_width = first_x_label.content.width() as u16;
+            let first_label_width = first_x_label.width() as u16;
             let width_left_of_y_axis = match self.x_axis.labels_alignment {
                 Alignment::Left => {
                     // The last character of the label should be below the Y-Axis when it exists, not on its left
@@ -390,6 +817,17 @@ impl<'a> Chart<'a> {
             None => return,
         };
         let labels = self.x_axis.labels.as_ref().unwrap();
+        if let Some(positions) = &self.x_axis.label_positions {
+            for (label, &value) in labels.iter().zip(positions) {
+                let width = label.width() as u16;
+                let x = (graph_area.left() + self.x_axis.offset(value, graph_area.width))
+                    .saturating_sub(width / 2)
+                    .max(chart_area.left())
+                    .min(chart_area.right().saturating_sub(width));
+                buf.set_span(x, y, label, width);
+            }
+            return;
+        }
         let labels_len = labels.len() as u16;
         if labels_len < 2 {
             return;
@@ -474,23 +912,318 @@ impl<'a> Chart<'a> {
             Some(x) => x,
             None => return,
         };
-        let labels = self.y_axis.labels.as_ref().unwrap();
-        let labels_len = labels.len() as u16;
+        let width = (graph_area.left() - chart_area.left()).saturating_sub(1);
+        Self::render_y_axis_labels(buf, &self.y_axis, x, width, graph_area);
+    }
+
+    fn render_y2_labels(
+        &mut self,
+        buf: &mut Buffer,
+        layout: &ChartLayout,
+        chart_area: Rect,
+        graph_area: Rect,
+    ) {
+        let x = match layout.label_y2 {
+            Some(x) => x,
+            None => return,
+        };
+        let axis = self.y_axis2.as_ref().unwrap();
+        let width = chart_area.right().saturating_sub(x);
+        Self::render_y_axis_labels(buf, axis, x, width, graph_area);
+    }
+
+    pub(super) fn render_y_axis_labels(
+        buf: &mut Buffer,
+        axis: &Axis,
+        x: u16,
+        width: u16,
+        graph_area: Rect,
+    ) {
+        let labels = axis.labels.as_ref().unwrap();
+        if let Some(positions) = &axis.label_positions {
+            for (label, &value) in labels.iter().zip(positions) {
+                let y = graph_area.bottom() - 1 - axis.offset(value, graph_area.height);
+                let label_area = Rect::new(x, y, width, 1);
+                Self::render_label(buf, label, label_area, axis.labels_alignment);
+            }
+            return;
+        }
+        // A single label is placed at the bottom
+        let intervals = (labels.len() as u16).saturating_sub(1).max(1);
         for (i, label) in labels.iter().enumerate() {
-            let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
+            let dy = i as u16 * (graph_area.height - 1) / intervals;
             if dy < graph_area.bottom() {
-                let label_area = Rect::new(
+                let label_area = Rect::new(x, graph_area.bottom().saturating_sub(1) - dy, width, 1);
+                Self::render_label(buf, label, label_area, axis.labels_alignment);
+            }
+        }
+    }
+
+    /// Returns the vertical axis the given dataset is projected against.
+    fn dataset_y_axis(&self, dataset: &Dataset) -> &Axis<'a> {
+        match (dataset.y_axis, &self.y_axis2) {
+            (YAxis::Secondary, Some(axis)) => axis,
+            _ => &self.y_axis,
+        }
+    }
+
+    /// Returns the data points mapped according to the scales of the x axis and the given
+    /// vertical axis, without the ones that can't be placed.
+    fn transformed_data<'b>(
+        &self,
+        y_axis: &Axis,
+        data: Cow<'b, [(f64, f64)]>,
+    ) -> Cow<'b, [(f64, f64)]> {
+        if self.x_axis.scale == AxisScale::Linear && y_axis.scale == AxisScale::Linear {
+            return data;
+        }
+        data.iter()
+            .map(|&(x, y)| (self.x_axis.transform(x), y_axis.transform(y)))
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect()
+    }
+
+    /// Draws the points of a dataset with their own symbols and sizes.
+    fn draw_points(&self, ctx: &mut Context, dataset: &Dataset, y_axis: &Axis, area: Rect) {
+        let color = dataset.style.fg.unwrap_or(Color::Reset);
+        let resolution = grid_resolution(dataset.marker, area);
+        for (i, &(x, y)) in dataset.data.iter().enumerate() {
+            let (x, y) = (self.x_axis.transform(x), y_axis.transform(y));
+            if !x.is_finite() || !y.is_finite() {
+                continue;
+            }
+            let size = dataset.point_sizes.get(i).map(|s| s.clamp(0.0, 1.0));
+            let symbol = match (dataset.point_symbols.get(i), size) {
+                (Some(symbol), _) => Some(*symbol),
+                (None, Some(size)) if !matches!(dataset.marker, symbols::Marker::Braille) => {
+                    let last = POINT_SIZE_SYMBOLS.len() - 1;
+                    Some(POINT_SIZE_SYMBOLS[(size * last as f64).round() as usize])
+                }
+                _ => None,
+            };
+            match symbol {
+                Some(symbol) => ctx.print(
                     x,
-                    graph_area.bottom().saturating_sub(1) - dy,
-                    (graph_area.left() - chart_area.left()).saturating_sub(1),
-                    1,
-                );
-                Self::render_label(buf, label, label_area, self.y_axis.labels_alignment);
+                    y,
+                    Span::styled(symbol.to_string(), Style::DEFAULT.fg(color)),
+                ),
+                None => ctx.draw(&Disc {
+                    x,
+                    y,
+                    radius: (size.unwrap_or_default() * MAX_POINT_RADIUS).round() as usize,
+                    resolution,
+                    color,
+                }),
             }
         }
     }
 }
 
+/// Symbols of the sized points drawn with one point per cell, from the smallest to the largest
+const POINT_SIZE_SYMBOLS: [&str; 4] = ["·", "•", "●", "█"];
+
+/// Radius, in braille dots, of the largest points
+const MAX_POINT_RADIUS: f64 = 2.0;
+
+/// A disc of points of the canvas grid, reduced to a single point if its radius is zero
+struct Disc {
+    x: f64,
+    y: f64,
+    /// Radius in points of the grid
+    radius: usize,
+    /// Index of the last point of the grid along each axis
+    resolution: (f64, f64),
+    color: Color,
+}
+
+impl Shape for Disc {
+    fn draw(&self, painter: &mut Painter) {
+        let (x, y) = match painter.get_point(self.x, self.y) {
+            Some(point) => point,
+            None => return,
+        };
+        let radius = self.radius as isize;
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let (x, y) = (x as isize + dx, y as isize + dy);
+                if dx * dx + dy * dy > radius * radius
+                    || x < 0
+                    || y < 0
+                    || x as f64 > self.resolution.0
+                    || y as f64 > self.resolution.1
+                {
+                    continue;
+                }
+                painter.paint(x as usize, y as usize, self.color);
+            }
+        }
+    }
+}
+
+/// Returns the corners of the steps joining the points, each value holding until the next point.
+fn step_points(data: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut points = Vec::with_capacity(data.len() * 2);
+    for segment in data.windows(2) {
+        points.push(segment[0]);
+        points.push((segment[1].0, segment[0].1));
+    }
+    points.extend(data.last());
+    points
+}
+
+/// Number of straight lines drawn between two points of a smoothed dataset
+const SMOOTH_STEPS: usize = 16;
+
+/// Samples the monotone cubic interpolation (Fritsch-Carlson) of the points. The points are
+/// returned as is if their x coordinates are not strictly increasing.
+fn smooth_points(data: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    if data.len() < 3 || data.windows(2).any(|s| s[1].0 <= s[0].0) {
+        return data.to_vec();
+    }
+    let slopes: Vec<f64> = data
+        .windows(2)
+        .map(|s| (s[1].1 - s[0].1) / (s[1].0 - s[0].0))
+        .collect();
+    let mut tangents = Vec::with_capacity(data.len());
+    tangents.push(slopes[0]);
+    for s in slopes.windows(2) {
+        tangents.push(if s[0] * s[1] <= 0.0 {
+            0.0
+        } else {
+            (s[0] + s[1]) / 2.0
+        });
+    }
+    tangents.push(slopes[slopes.len() - 1]);
+    for (k, &slope) in slopes.iter().enumerate() {
+        if slope == 0.0 {
+            tangents[k] = 0.0;
+            tangents[k + 1] = 0.0;
+            continue;
+        }
+        let (a, b) = (tangents[k] / slope, tangents[k + 1] / slope);
+        let norm = (a * a + b * b).sqrt();
+        if norm > 3.0 {
+            tangents[k] = 3.0 / norm * a * slope;
+            tangents[k + 1] = 3.0 / norm * b * slope;
+        }
+    }
+
+    let mut points = Vec::with_capacity(slopes.len() * SMOOTH_STEPS + 1);
+    for (k, s) in data.windows(2).enumerate() {
+        let ((x1, y1), (x2, y2)) = (s[0], s[1]);
+        let h = x2 - x1;
+        for i in 0..SMOOTH_STEPS {
+            let t = i as f64 / SMOOTH_STEPS as f64;
+            let (t2, t3) = (t * t, t * t * t);
+            let y = (2.0 * t3 - 3.0 * t2 + 1.0) * y1
+                + (t3 - 2.0 * t2 + t) * h * tangents[k]
+                + (-2.0 * t3 + 3.0 * t2) * y2
+                + (t3 - t2) * h * tangents[k + 1];
+            points.push((x1 + t * h, y));
+        }
+    }
+    points.extend(data.last());
+    points
+}
+
+/// Returns the value of the line joining the points at the given x coordinate, or zero outside
+/// of the line.
+fn interpolate(data: &[(f64, f64)], x: f64) -> f64 {
+    for segment in data.windows(2) {
+        let ((x1, y1), (x2, y2)) = (segment[0], segment[1]);
+        if x1 <= x && x <= x2 {
+            return if x1 == x2 {
+                y2
+            } else {
+                y1 + (y2 - y1) * (x - x1) / (x2 - x1)
+            };
+        }
+    }
+    match data {
+        [(x1, y1)] if *x1 == x => *y1,
+        _ => 0.0,
+    }
+}
+
+/// Returns the number of intervals between the points of the canvas grid drawn with the given
+/// marker, horizontally and vertically.
+fn grid_resolution(marker: symbols::Marker, area: Rect) -> (f64, f64) {
+    let (width, height) = (f64::from(area.width), f64::from(area.height));
+    match marker {
+        symbols::Marker::Braille => (width * 2.0 - 1.0, height * 4.0 - 1.0),
+        symbols::Marker::Dot | symbols::Marker::Block => (width - 1.0, height - 1.0),
+    }
+}
+
+/// Fills the area between the line joining the points and the base line (zero if empty) with a
+/// vertical line on each column of the canvas grid. The points of a non-empty base line are left
+/// untouched, so that the line of the dataset below remains visible.
+fn draw_area(
+    ctx: &mut Context,
+    data: &[(f64, f64)],
+    base: &[(f64, f64)],
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+    resolution: (f64, f64),
+    color: Color,
+) {
+    let step = (x_bounds[1] - x_bounds[0]) / resolution.0;
+    if !step.is_finite() || step <= 0.0 {
+        return;
+    }
+    let y_step = (y_bounds[1] - y_bounds[0]) / resolution.1;
+    let clamp = |y: f64| y.max(y_bounds[0]).min(y_bounds[1]);
+    for segment in data.windows(2) {
+        let ((x1, y1), (x2, y2)) = (segment[0], segment[1]);
+        let (x1, x2) = (x1.max(x_bounds[0]), x2.min(x_bounds[1]));
+        if x2 < x1 {
+            continue;
+        }
+        let first_column = ((x1 - x_bounds[0]) / step).ceil() as usize;
+        let last_column = ((x2 - x_bounds[0]) / step).floor() as usize;
+        for column in first_column..=last_column {
+            let x = x_bounds[0] + column as f64 * step;
+            let y = if segment[1].0 == segment[0].0 {
+                y2
+            } else {
+                y1 + (y2 - y1) * (x - segment[0].0) / (segment[1].0 - segment[0].0)
+            };
+            let base = if base.is_empty() {
+                0.0
+            } else {
+                let base = interpolate(base, x);
+                if (y - base).abs() < y_step {
+                    continue;
+                }
+                base + y_step.copysign(y - base)
+            };
+            ctx.draw(&Line {
+                x1: x,
+                y1: clamp(base),
+                x2: x,
+                y2: clamp(y),
+                color,
+            });
+        }
+    }
+}
+
+/// Returns a darker variant of the given color.
+fn dim(color: Color) -> Color {
+    match color {
+        Color::Rgb(r, g, b) => Color::Rgb(r / 2, g / 2, b / 2),
+        Color::LightRed => Color::Red,
+        Color::LightGreen => Color::Green,
+        Color::LightYellow => Color::Yellow,
+        Color::LightBlue => Color::Blue,
+        Color::LightMagenta => Color::Magenta,
+        Color::LightCyan => Color::Cyan,
+        Color::White => Color::Gray,
+        Color::Gray => Color::DarkGray,
+        color => color,
+    }
+}
+
 impl<'a> Widget for Chart<'a> {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
         if area.area() == 0 {
@@ -511,6 +1244,12 @@ impl<'a> Widget for Chart<'a> {
             None => area,
         };
 
+        self.x_axis.generate_labels(chart_area.width, true);
+        self.y_axis.generate_labels(chart_area.height, false);
+        if let Some(axis) = self.y_axis2.as_mut() {
+            axis.generate_labels(chart_area.height, false);
+        }
+
         let layout = self.layout(chart_area);
         let graph_area = layout.graph_area;
         if graph_area.width < 1 || graph_area.height < 1 {
@@ -519,6 +1258,7 @@ impl<'a> Widget for Chart<'a> {
 
         self.render_x_labels(buf, &layout, chart_area, graph_area);
         self.render_y_labels(buf, &layout, chart_area, graph_area);
+        self.render_y2_labels(buf, &layout, chart_area, graph_area);
 
         if let Some(y) = layout.axis_x {
             for x in graph_area.left()..graph_area.right() {
@@ -536,35 +1276,87 @@ impl<'a> Widget for Chart<'a> {
             }
         }
 
+        if let (Some(x), Some(axis)) = (layout.axis_y2, &self.y_axis2) {
+            for y in graph_area.top()..graph_area.bottom() {
+                buf.get_mut(x, y)
+                    .set_symbol(symbols::line::VERTICAL)
+                    .set_style(axis.style);
+            }
+        }
+
         if let Some(y) = layout.axis_x {
             if let Some(x) = layout.axis_y {
                 buf.get_mut(x, y)
                     .set_symbol(symbols::line::BOTTOM_LEFT)
                     .set_style(self.x_axis.style);
             }
+            if let Some(x) = layout.axis_y2 {
+                buf.get_mut(x, y)
+                    .set_symbol(symbols::line::BOTTOM_RIGHT)
+                    .set_style(self.x_axis.style);
+            }
         }
 
+        // Top lines of the stacked areas of the primary and secondary vertical axes
+        let mut stacks: [Vec<(f64, f64)>; 2] = Default::default();
         for dataset in &self.datasets {
+            let y_axis = self.dataset_y_axis(dataset);
+            let (data, base) = if let GraphType::StackedArea = dataset.graph_type {
+                let secondary = dataset.y_axis == YAxis::Secondary && self.y_axis2.is_some();
+                let stack = &mut stacks[usize::from(secondary)];
+                let top: Vec<(f64, f64)> = dataset
+                    .data
+                    .iter()
+                    .map(|&(x, y)| (x, y + interpolate(stack, x)))
+                    .collect();
+                let base = std::mem::replace(stack, top.clone());
+                (Cow::Owned(top), Cow::Owned(base))
+            } else {
+                (Cow::Borrowed(dataset.data), Cow::Borrowed(&[][..]))
+            };
+            let data = self.transformed_data(y_axis, data);
+            let base = self.transformed_data(y_axis, base);
+            let x_bounds = self.x_axis.transformed_bounds();
+            let y_bounds = y_axis.transformed_bounds();
             Canvas::default()
                 .background_color(self.style.bg.unwrap_or(Color::Reset))
-                .x_bounds(self.x_axis.bounds)
-                .y_bounds(self.y_axis.bounds)
+                .x_bounds(x_bounds)
+                .y_bounds(y_bounds)
                 .marker(dataset.marker)
                 .paint(|ctx| {
-                    ctx.draw(&Points {
-                        coords: dataset.data,
-                        color: dataset.style.fg.unwrap_or(Color::Reset),
-                    });
-                    if let GraphType::Line = dataset.graph_type {
-                        for data in dataset.data.windows(2) {
-                            ctx.draw(&Line {
-                                x1: data[0].0,
-                                y1: data[0].1,
-                                x2: data[1].0,
-                                y2: data[1].1,
-                                color: dataset.style.fg.unwrap_or(Color::Reset),
-                            })
-                        }
+                    if dataset.point_symbols.is_empty() && dataset.point_sizes.is_empty() {
+                        ctx.draw(&Points {
+                            coords: &data,
+                            color: dataset.style.fg.unwrap_or(Color::Reset),
+                        });
+                    } else {
+                        self.draw_points(ctx, dataset, y_axis, graph_area);
+                    }
+                    let line = match dataset.graph_type {
+                        GraphType::Scatter => return,
+                        GraphType::Step => Cow::Owned(step_points(&data)),
+                        _ if dataset.smooth => Cow::Owned(smooth_points(&data)),
+                        _ => Cow::Borrowed(&data[..]),
+                    };
+                    if let GraphType::Area | GraphType::StackedArea = dataset.graph_type {
+                        draw_area(
+                            ctx,
+                            &line,
+                            &base,
+                            x_bounds,
+                            y_bounds,
+                            grid_resolution(dataset.marker, graph_area),
+                            dim(dataset.style.fg.unwrap_or(Color::Reset)),
+                        );
+                    }
+                    for data in line.windows(2) {
+                        ctx.draw(&Line {
+                            x1: data[0].0,
+                            y1: data[0].1,
+                            x2: data[1].0,
+                            y2: data[1].1,
+                            color: dataset.style.fg.unwrap_or(Color::Reset),
+                        })
                     }
                 })
                 .render(graph_area, buf);
@@ -575,10 +1367,18 @@ impl<'a> Widget for Chart<'a> {
             Block::default()
                 .borders(Borders::ALL)
                 .render(legend_area, buf);
+            let column_width = self
+                .datasets
+                .iter()
+                .map(|d| d.name.width() as u16)
+                .max()
+                .unwrap_or_default()
+                + 1;
             for (i, dataset) in self.datasets.iter().enumerate() {
+                let (column, row) = (i as u16 / layout.legend_rows, i as u16 % layout.legend_rows);
                 buf.set_string(
-                    legend_area.x + 1,
-                    legend_area.y + 1 + i as u16,
+                    legend_area.x + 1 + column * column_width,
+                    legend_area.y + 1 + row,
                     &dataset.name,
                     dataset.style,
                 );
@@ -600,6 +1400,25 @@ impl<'a> Widget for Chart<'a> {
             buf.set_spans(x, y, &title, width);
         }
 
+        if let Some((x, y)) = layout.title_y2 {
+            let title = self
+                .y_axis2
+                .as_ref()
+                .and_then(|a| a.title.as_ref())
+                .unwrap();
+            let width = graph_area.right().saturating_sub(x);
+            buf.set_style(
+                Rect {
+                    x,
+                    y,
+                    width,
+                    height: 1,
+                },
+                original_style,
+            );
+            buf.set_spans(x, y, title, width);
+        }
+
         if let Some((x, y)) = layout.title_y {
             let title = self.y_axis.title.unwrap();
             let width = graph_area.right().saturating_sub(x);
@@ -657,4 +1476,104 @@ mod tests {
             assert_eq!(layout.legend_area, case.legend_area);
         }
     }
+
+    #[test]
+    fn it_should_generate_labels_for_logarithmic_axes() {
+        let mut axis = Axis::default()
+            .bounds([0.01, 1000.0])
+            .scale(AxisScale::Log10);
+        axis.generate_labels(20, false);
+        let labels = axis.labels.unwrap();
+        let labels: Vec<&str> = labels.iter().map(|l| l.content.as_ref()).collect();
+        assert_eq!(labels, ["0.01", "0.1", "1", "10", "100", "1000"]);
+    }
+
+    #[test]
+    fn it_should_smooth_lines_without_overshooting() {
+        let data = [(0.0, 0.0), (1.0, 1.0), (2.0, 1.0), (3.0, 4.0)];
+        let points = smooth_points(&data);
+        assert_eq!(points.len(), 3 * SMOOTH_STEPS + 1);
+        assert_eq!(points[SMOOTH_STEPS], (1.0, 1.0));
+        assert_eq!(points.last(), Some(&(3.0, 4.0)));
+        for (segment, s) in data.windows(2).zip(points.chunks(SMOOTH_STEPS)) {
+            let (min, max) = (
+                segment[0].1.min(segment[1].1),
+                segment[0].1.max(segment[1].1),
+            );
+            assert!(s.iter().all(|&(_, y)| min <= y && y <= max));
+        }
+    }
+
+    #[test]
+    fn it_should_lay_out_the_legend_in_columns() {
+        let data = [(0.0, 5.0)];
+        let datasets = (0..10)
+            .map(|i| {
+                Dataset::default()
+                    .name(format!("Dataset #{}", i))
+                    .data(&data)
+            })
+            .collect::<Vec<_>>();
+        let chart = Chart::new(datasets)
+            .hidden_legend_constraints((Constraint::Ratio(1, 2), Constraint::Ratio(1, 4)))
+            .legend_position(Some(LegendPosition::BottomLeft));
+        let layout = chart.layout(Rect::new(0, 0, 100, 30));
+        assert_eq!(layout.legend_area, Some(Rect::new(0, 24, 34, 6)));
+        assert_eq!(layout.legend_rows, 4);
+
+        let layout = chart.legend_position(None).layout(Rect::new(0, 0, 100, 30));
+        assert_eq!(layout.legend_area, None);
+    }
+
+    #[test]
+    fn it_should_generate_labels_fitting_the_axis() {
+        let mut axis = Axis::default()
+            .bounds([0.0, 2_500_000.0])
+            .auto_labels(LabelFormat::Si);
+        axis.generate_labels(25, true);
+        let labels = axis.labels.unwrap();
+        let labels: Vec<&str> = labels.iter().map(|l| l.content.as_ref()).collect();
+        assert_eq!(labels, ["0", "500k", "1M", "1.5M", "2M", "2.5M"]);
+
+        let mut axis = Axis::default()
+            .bounds([0.0, 1.0])
+            .auto_labels(LabelFormat::Decimal(2));
+        axis.generate_labels(5, false);
+        let labels = axis.labels.unwrap();
+        let labels: Vec<&str> = labels.iter().map(|l| l.content.as_ref()).collect();
+        assert_eq!(labels, ["0.00", "0.50", "1.00"]);
+
+        let unit = String::from("ms");
+        let mut axis = Axis::default()
+            .bounds([0.0, 10.0])
+            .auto_labels(LabelFormat::custom(|value| format!("{}{}", value, unit)));
+        axis.generate_labels(5, false);
+        let labels = axis.labels.unwrap();
+        let labels: Vec<&str> = labels.iter().map(|l| l.content.as_ref()).collect();
+        assert_eq!(labels, ["0ms", "5ms", "10ms"]);
+    }
+
+    #[test]
+    fn it_should_generate_labels_on_round_times() {
+        // 12:03:20 to 12:31:40 on the 2nd of March 2021
+        let start = 1_614_686_600.0;
+        let mut axis = Axis::default()
+            .bounds([start, start + 1700.0])
+            .auto_labels(LabelFormat::Time);
+        axis.generate_labels(40, true);
+        let labels = axis.labels.unwrap();
+        let labels: Vec<&str> = labels.iter().map(|l| l.content.as_ref()).collect();
+        assert_eq!(
+            labels,
+            ["12:05", "12:10", "12:15", "12:20", "12:25", "12:30"]
+        );
+
+        let mut axis = Axis::default()
+            .bounds([start, start + 5.0 * 86400.0])
+            .auto_labels(LabelFormat::Time);
+        axis.generate_labels(5, false);
+        let labels = axis.labels.unwrap();
+        let labels: Vec<&str> = labels.iter().map(|l| l.content.as_ref()).collect();
+        assert_eq!(labels, ["03-04", "03-06"]);
+    }
 }
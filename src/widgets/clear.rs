@@ -21,7 +21,7 @@ use crate::{buffer::Buffer, layout::Rect, widgets::Widget};
 ///
 /// # Popup Example
 ///
-/// For a more complete example how to utilize `Clear` to realize popups see
+/// [`Popup`](crate::widgets::Popup) clears its area with `Clear` before drawing its content, see
 /// the example `examples/popup.rs`
 #[derive(Debug, Clone)]
 pub struct Clear;
@@ -0,0 +1,506 @@
+use crate::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Style, Styled},
+    text::{Span, Spans},
+    widgets::{navigation, Block, StatefulWidget, Widget},
+};
+
+/// Keeps track of the focused control among a fixed number of controls, e.g. the fields of a form.
+///
+/// The controls are told whether they have the focus with their `focused` method.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Button, Checkbox, FocusState};
+/// let mut focus = FocusState::new(2);
+/// focus.focus_next();
+/// assert_eq!(focus.focused(), Some(0));
+/// focus.focus_previous();
+/// assert_eq!(focus.focused(), Some(1));
+/// let checkbox = Checkbox::new("Remember me").focused(focus.is_focused(0));
+/// let button = Button::new("Log in").focused(focus.is_focused(1));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FocusState {
+    focused: Option<usize>,
+    len: usize,
+}
+
+impl FocusState {
+    pub fn new(len: usize) -> FocusState {
+        FocusState { focused: None, len }
+    }
+
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    pub fn is_focused(&self, index: usize) -> bool {
+        self.focused == Some(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Focus the control at the given index, if any. Indices past the last control remove the
+    /// focus.
+    pub fn focus(&mut self, index: Option<usize>) {
+        self.focused = index.filter(|&i| i < self.len);
+    }
+
+    /// Focus the control after the focused one, going back to the first one after the last.
+    pub fn focus_next(&mut self) {
+        self.focused = navigation::next(self.focused, self.len, true);
+    }
+
+    /// Focus the control before the focused one, going to the last one before the first.
+    pub fn focus_previous(&mut self) {
+        self.focused = navigation::previous(self.focused, self.len, true);
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ButtonState {
+    pressed: bool,
+}
+
+impl ButtonState {
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    pub fn press(&mut self) {
+        self.pressed = true;
+    }
+
+    pub fn release(&mut self) {
+        self.pressed = false;
+    }
+}
+
+/// A widget displaying a label centered in its area, styled after whether it is focused and
+/// pressed.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, Button};
+/// # use tui::style::{Style, Color, Modifier};
+/// Button::new("OK")
+///     .block(Block::default().borders(Borders::ALL))
+///     .focused_style(Style::DEFAULT.fg(Color::Yellow))
+///     .pressed_style(Style::DEFAULT.add_modifier(Modifier::REVERSED))
+///     .focused(true);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Button<'a> {
+    block: Option<Block<'a>>,
+    label: Spans<'a>,
+    style: Style,
+    /// Style patched over the style of the button when it is focused
+    focused_style: Style,
+    /// Style patched over the style of the button when it is pressed
+    pressed_style: Style,
+    focused: bool,
+}
+
+impl<'a> Button<'a> {
+    pub fn new<T>(label: T) -> Button<'a>
+    where
+        T: Into<Spans<'a>>,
+    {
+        Button {
+            block: None,
+            label: label.into(),
+            style: Style::DEFAULT,
+            focused_style: Style::DEFAULT,
+            pressed_style: Style::DEFAULT,
+            focused: false,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Button<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Button<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn focused_style(mut self, style: Style) -> Button<'a> {
+        self.focused_style = style;
+        self
+    }
+
+    pub fn pressed_style(mut self, style: Style) -> Button<'a> {
+        self.pressed_style = style;
+        self
+    }
+
+    pub fn focused(mut self, focused: bool) -> Button<'a> {
+        self.focused = focused;
+        self
+    }
+}
+
+impl<'a> Styled for Button<'a> {
+    fn patch_style(mut self, style: Style) -> Button<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> StatefulWidget for Button<'a> {
+    type State = ButtonState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut ButtonState) {
+        let mut style = self.style;
+        if self.focused {
+            style = style.patch(self.focused_style);
+        }
+        if state.pressed {
+            style = style.patch(self.pressed_style);
+        }
+        buf.set_style(area, style);
+        let label_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+        if label_area.area() == 0 {
+            return;
+        }
+
+        let label_width = self.label.width() as u16;
+        let x = label_area.left() + label_area.width.saturating_sub(label_width) / 2;
+        let y = label_area.top() + (label_area.height - 1) / 2;
+        buf.set_spans(x, y, &self.label, label_area.right() - x);
+    }
+}
+
+impl<'a> Widget for Button<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ButtonState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CheckboxState {
+    checked: bool,
+}
+
+impl CheckboxState {
+    pub fn new(checked: bool) -> CheckboxState {
+        CheckboxState { checked }
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+
+    pub fn toggle(&mut self) {
+        self.checked = !self.checked;
+    }
+}
+
+/// A widget displaying a box, checked or not, followed by a label.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::Checkbox;
+/// # use tui::style::{Style, Modifier};
+/// Checkbox::new("Word wrap")
+///     .symbols("☑", "☐")
+///     .focused_style(Style::DEFAULT.add_modifier(Modifier::BOLD));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Checkbox<'a> {
+    label: Spans<'a>,
+    style: Style,
+    /// Style patched over the style of the checkbox when it is focused
+    focused_style: Style,
+    checked_symbol: &'a str,
+    unchecked_symbol: &'a str,
+    focused: bool,
+}
+
+impl<'a> Checkbox<'a> {
+    pub fn new<T>(label: T) -> Checkbox<'a>
+    where
+        T: Into<Spans<'a>>,
+    {
+        Checkbox {
+            label: label.into(),
+            style: Style::DEFAULT,
+            focused_style: Style::DEFAULT,
+            checked_symbol: "[x]",
+            unchecked_symbol: "[ ]",
+            focused: false,
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Checkbox<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn focused_style(mut self, style: Style) -> Checkbox<'a> {
+        self.focused_style = style;
+        self
+    }
+
+    /// Set the symbols of the box when checked and unchecked.
+    pub fn symbols(mut self, checked: &'a str, unchecked: &'a str) -> Checkbox<'a> {
+        self.checked_symbol = checked;
+        self.unchecked_symbol = unchecked;
+        self
+    }
+
+    pub fn focused(mut self, focused: bool) -> Checkbox<'a> {
+        self.focused = focused;
+        self
+    }
+}
+
+impl<'a> Styled for Checkbox<'a> {
+    fn patch_style(mut self, style: Style) -> Checkbox<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> StatefulWidget for Checkbox<'a> {
+    type State = CheckboxState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut CheckboxState) {
+        buf.set_style(area, self.style);
+        if area.area() == 0 {
+            return;
+        }
+        let symbol = if state.checked {
+            self.checked_symbol
+        } else {
+            self.unchecked_symbol
+        };
+        let area = Rect { height: 1, ..area };
+        let width = render_option(symbol, &self.label, area, buf);
+        if self.focused {
+            buf.set_style(Rect { width, ..area }, self.focused_style);
+        }
+    }
+}
+
+/// Draws a symbol followed by a label on the first row of the area and returns the width they
+/// take.
+fn render_option(symbol: &str, label: &Spans, area: Rect, buf: &mut Buffer) -> u16 {
+    let (x, _) = buf.set_span(area.left(), area.top(), &Span::raw(symbol), area.width);
+    let x = x.saturating_add(1);
+    let remaining_width = area.right().saturating_sub(x);
+    if remaining_width == 0 {
+        return area.width;
+    }
+    let (x, _) = buf.set_spans(x, area.top(), label, remaining_width);
+    x - area.left()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RadioGroupState {
+    selected: Option<usize>,
+    /// Option on which the cursor is, selected by [`RadioGroupState::select_highlighted`]
+    highlighted: Option<usize>,
+}
+
+impl RadioGroupState {
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    pub fn highlight(&mut self, index: Option<usize>) {
+        self.highlighted = index;
+    }
+
+    /// Highlights the option after the highlighted one, or the first option if none is. At the
+    /// end of the `len` options, goes back to the first one if `wrap` is set and stays on the last
+    /// one otherwise.
+    pub fn highlight_next(&mut self, len: usize, wrap: bool) {
+        self.highlighted = navigation::next(self.highlighted, len, wrap);
+    }
+
+    /// Highlights the option before the highlighted one, or the last option if none is. At the
+    /// start of the `len` options, goes to the last one if `wrap` is set and stays on the first
+    /// one otherwise.
+    pub fn highlight_previous(&mut self, len: usize, wrap: bool) {
+        self.highlighted = navigation::previous(self.highlighted, len, wrap);
+    }
+
+    /// Selects the highlighted option, if any.
+    pub fn select_highlighted(&mut self) {
+        if self.highlighted.is_some() {
+            self.selected = self.highlighted;
+        }
+    }
+}
+
+/// A widget displaying options of which at most one is selected.
+///
+/// When the group is focused, the highlighted option of the [`RadioGroupState`] is styled with
+/// the focused style.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{RadioGroup, RadioGroupState};
+/// # use tui::layout::Direction;
+/// let mut state = RadioGroupState::default();
+/// state.highlight_next(3, false);
+/// state.select_highlighted();
+/// assert_eq!(state.selected(), Some(0));
+/// RadioGroup::new(vec!["Small".into(), "Medium".into(), "Large".into()])
+///     .direction(Direction::Horizontal)
+///     .focused(true);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RadioGroup<'a> {
+    block: Option<Block<'a>>,
+    options: Vec<Spans<'a>>,
+    style: Style,
+    /// Style patched over the highlighted option when the group is focused
+    focused_style: Style,
+    selected_symbol: &'a str,
+    unselected_symbol: &'a str,
+    /// Whether the options are laid out in a column or in a row
+    direction: Direction,
+    focused: bool,
+}
+
+impl<'a> RadioGroup<'a> {
+    pub fn new(options: Vec<Spans<'a>>) -> RadioGroup<'a> {
+        RadioGroup {
+            block: None,
+            options,
+            style: Style::DEFAULT,
+            focused_style: Style::DEFAULT,
+            selected_symbol: "(•)",
+            unselected_symbol: "( )",
+            direction: Direction::Vertical,
+            focused: false,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> RadioGroup<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> RadioGroup<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn focused_style(mut self, style: Style) -> RadioGroup<'a> {
+        self.focused_style = style;
+        self
+    }
+
+    /// Set the symbols in front of the selected option and of the other ones.
+    pub fn symbols(mut self, selected: &'a str, unselected: &'a str) -> RadioGroup<'a> {
+        self.selected_symbol = selected;
+        self.unselected_symbol = unselected;
+        self
+    }
+
+    pub fn direction(mut self, direction: Direction) -> RadioGroup<'a> {
+        self.direction = direction;
+        self
+    }
+
+    pub fn focused(mut self, focused: bool) -> RadioGroup<'a> {
+        self.focused = focused;
+        self
+    }
+}
+
+impl<'a> Styled for RadioGroup<'a> {
+    fn patch_style(mut self, style: Style) -> RadioGroup<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> StatefulWidget for RadioGroup<'a> {
+    type State = RadioGroupState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut RadioGroupState) {
+        buf.set_style(area, self.style);
+        let options_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+
+        let mut option_area = Rect {
+            height: options_area.height.min(1),
+            ..options_area
+        };
+        for (i, option) in self.options.iter().enumerate() {
+            if option_area.height == 0 || option_area.width == 0 {
+                break;
+            }
+            let symbol = if state.selected == Some(i) {
+                self.selected_symbol
+            } else {
+                self.unselected_symbol
+            };
+            let width = render_option(symbol, option, option_area, buf);
+            if self.focused && state.highlighted == Some(i) {
+                buf.set_style(
+                    Rect {
+                        width,
+                        ..option_area
+                    },
+                    self.focused_style,
+                );
+            }
+            match self.direction {
+                Direction::Vertical => {
+                    option_area.y += 1;
+                    option_area.height = u16::from(option_area.y < options_area.bottom());
+                }
+                Direction::Horizontal => {
+                    let advance = (width + 2).min(option_area.width);
+                    option_area.x += advance;
+                    option_area.width -= advance;
+                }
+            }
+        }
+    }
+}
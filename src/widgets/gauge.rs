@@ -1,12 +1,41 @@
 use crate::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Gradient, Style},
     symbols,
     text::{Span, Spans},
     widgets::{Block, Widget},
 };
 
+/// Determines how finely the filled part of a gauge is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GaugePrecision {
+    /// Only whole cells are filled
+    Cell,
+    /// The last partially filled cell is drawn with a fractional symbol. This gives 8 steps per
+    /// cell for [`Gauge`] (using block elements) and 2 steps per cell for [`LineGauge`] (using half
+    /// lines).
+    SubCell,
+}
+
+/// The direction in which a [`Gauge`] fills up as its ratio grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GaugeDirection {
+    LeftToRight,
+    RightToLeft,
+    BottomToTop,
+    TopToBottom,
+}
+
+impl GaugeDirection {
+    fn is_vertical(self) -> bool {
+        matches!(
+            self,
+            GaugeDirection::BottomToTop | GaugeDirection::TopToBottom
+        )
+    }
+}
+
 /// A widget to display a task progress.
 ///
 /// # Examples:
@@ -24,9 +53,11 @@ pub struct Gauge<'a> {
     block: Option<Block<'a>>,
     ratio: f64,
     label: Option<Span<'a>>,
-    use_unicode: bool,
+    precision: GaugePrecision,
     style: Style,
     gauge_style: Style,
+    gauge_gradient: Option<Gradient>,
+    direction: GaugeDirection,
 }
 
 impl<'a> Default for Gauge<'a> {
@@ -35,9 +66,11 @@ impl<'a> Default for Gauge<'a> {
             block: None,
             ratio: 0.0,
             label: None,
-            use_unicode: false,
+            precision: GaugePrecision::Cell,
             style: Style::DEFAULT,
             gauge_style: Style::DEFAULT,
+            gauge_gradient: None,
+            direction: GaugeDirection::LeftToRight,
         }
     }
 }
@@ -85,8 +118,35 @@ impl<'a> Gauge<'a> {
         self
     }
 
+    /// Colors the filled part of the gauge with a gradient spanning the whole gauge, instead of
+    /// the foreground color of [`Gauge::gauge_style`]. The gradient follows the
+    /// [direction](Gauge::direction) of the gauge, starting where it starts to fill up, whatever
+    /// the direction of the gradient.
+    pub fn gauge_gradient(mut self, gradient: Gradient) -> Gauge<'a> {
+        self.gauge_gradient = Some(gradient);
+        self
+    }
+
+    /// Shorthand for [`Gauge::precision`] with [`GaugePrecision::SubCell`] when `true` and
+    /// [`GaugePrecision::Cell`] otherwise.
     pub fn use_unicode(mut self, unicode: bool) -> Gauge<'a> {
-        self.use_unicode = unicode;
+        self.precision = if unicode {
+            GaugePrecision::SubCell
+        } else {
+            GaugePrecision::Cell
+        };
+        self
+    }
+
+    pub fn precision(mut self, precision: GaugePrecision) -> Gauge<'a> {
+        self.precision = precision;
+        self
+    }
+
+    /// Set the direction in which the gauge fills up, e.g. [`GaugeDirection::BottomToTop`] for
+    /// a volume meter. The label stays centered in the gauge.
+    pub fn direction(mut self, direction: GaugeDirection) -> Gauge<'a> {
+        self.direction = direction;
         self
     }
 }
@@ -103,7 +163,7 @@ impl<'a> Widget for Gauge<'a> {
             None => area,
         };
         buf.set_style(gauge_area, self.gauge_style);
-        if gauge_area.height < 1 {
+        if gauge_area.area() == 0 {
             return;
         }
 
@@ -118,25 +178,76 @@ impl<'a> Widget for Gauge<'a> {
         let label_col = gauge_area.left() + (gauge_area.width - clamped_label_width) / 2;
         let label_row = gauge_area.top() + gauge_area.height / 2;
 
-        // the gauge will be filled proportionally to the ratio
-        let filled_width = f64::from(gauge_area.width) * self.ratio;
-        let end = if self.use_unicode {
-            gauge_area.left() + filled_width.floor() as u16
+        // the gauge will be filled proportionally to the ratio, starting from the edge given by
+        // its direction
+        let direction = self.direction;
+        let (length, breadth) = if direction.is_vertical() {
+            (gauge_area.height, gauge_area.width)
+        } else {
+            (gauge_area.width, gauge_area.height)
+        };
+        let position = |i: u16, j: u16| match direction {
+            GaugeDirection::LeftToRight => (gauge_area.left() + i, gauge_area.top() + j),
+            GaugeDirection::RightToLeft => (gauge_area.right() - 1 - i, gauge_area.top() + j),
+            GaugeDirection::TopToBottom => (gauge_area.left() + j, gauge_area.top() + i),
+            GaugeDirection::BottomToTop => (gauge_area.left() + j, gauge_area.bottom() - 1 - i),
+        };
+        let filled_length = f64::from(length) * self.ratio;
+        let sub_cell = self.precision == GaugePrecision::SubCell;
+        let end = if sub_cell {
+            filled_length.floor() as u16
         } else {
-            gauge_area.left() + filled_width.round() as u16
+            filled_length.round() as u16
         };
-        for y in gauge_area.top()..gauge_area.bottom() {
-            // render the filled area (left to end)
-            for x in gauge_area.left()..end {
+        // The gradient goes along the gauge from where it starts to fill up
+        let filled_color = |i: u16| match self.gauge_gradient {
+            Some(ref gradient) if length > 1 => {
+                gradient.color_at(f64::from(i) / f64::from(length - 1))
+            }
+            Some(ref gradient) => gradient.color_at(0.0),
+            None => self.gauge_style.fg.unwrap_or(Color::Reset),
+        };
+        let empty_color = self.gauge_style.bg.unwrap_or(Color::Reset);
+        for j in 0..breadth {
+            // render the filled area (start to end)
+            for i in 0..end {
+                let (x, y) = position(i, j);
                 // spaces are needed to apply the background styling
                 buf.get_mut(x, y)
                     .set_symbol(" ")
-                    .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
-                    .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
+                    .set_fg(empty_color)
+                    .set_bg(filled_color(i));
             }
-            if self.use_unicode && self.ratio < 1.0 {
-                buf.get_mut(end, y)
-                    .set_symbol(get_unicode_block(filled_width % 1.0));
+            if sub_cell && self.ratio < 1.0 {
+                let (x, y) = position(end, j);
+                let fraction = filled_length % 1.0;
+                let cell = buf.get_mut(x, y);
+                match direction {
+                    GaugeDirection::LeftToRight => {
+                        cell.set_symbol(get_unicode_block(fraction));
+                        if self.gauge_gradient.is_some() {
+                            cell.set_fg(filled_color(end));
+                        }
+                    }
+                    GaugeDirection::BottomToTop => {
+                        cell.set_symbol(get_unicode_bar(fraction));
+                        if self.gauge_gradient.is_some() {
+                            cell.set_fg(filled_color(end));
+                        }
+                    }
+                    // There are no right or upper fractional blocks: the empty part of the cell is
+                    // drawn over the filled color instead
+                    GaugeDirection::RightToLeft => {
+                        cell.set_symbol(get_unicode_block(1.0 - fraction))
+                            .set_fg(empty_color)
+                            .set_bg(filled_color(end));
+                    }
+                    GaugeDirection::TopToBottom => {
+                        cell.set_symbol(get_unicode_bar(1.0 - fraction))
+                            .set_fg(empty_color)
+                            .set_bg(filled_color(end));
+                    }
+                }
             }
         }
         // set the span
@@ -158,6 +269,172 @@ fn get_unicode_block<'a>(frac: f64) -> &'a str {
     }
 }
 
+fn get_unicode_bar<'a>(frac: f64) -> &'a str {
+    match (frac * 8.0).round() as u16 {
+        1 => symbols::bar::ONE_EIGHTH,
+        2 => symbols::bar::ONE_QUARTER,
+        3 => symbols::bar::THREE_EIGHTHS,
+        4 => symbols::bar::HALF,
+        5 => symbols::bar::FIVE_EIGHTHS,
+        6 => symbols::bar::THREE_QUARTERS,
+        7 => symbols::bar::SEVEN_EIGHTHS,
+        8 => symbols::bar::FULL,
+        _ => " ",
+    }
+}
+
+/// A part of a [`StackedGauge`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaugeSegment<'a> {
+    label: Span<'a>,
+    ratio: f64,
+    color: Color,
+}
+
+impl<'a> GaugeSegment<'a> {
+    /// Creates a segment filling the given ratio ([0.0, 1.0]) of the gauge with `color`.
+    pub fn new<T>(label: T, ratio: f64, color: Color) -> GaugeSegment<'a>
+    where
+        T: Into<Span<'a>>,
+    {
+        assert!(
+            (0.0..=1.0).contains(&ratio),
+            "Ratio should be between 0 and 1 inclusively."
+        );
+        GaugeSegment {
+            label: label.into(),
+            ratio,
+            color,
+        }
+    }
+}
+
+/// A widget to display several quantities stacked in a single bar, e.g. the used, cached and
+/// free memory.
+///
+/// The segments are laid out from left to right. The label of each segment is displayed at its
+/// center when it fits, and a legend listing all of them can be displayed on the last line.
+///
+/// # Examples:
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, GaugeSegment, StackedGauge};
+/// # use tui::style::Color;
+/// StackedGauge::new(vec![
+///     GaugeSegment::new("used", 0.4, Color::Red),
+///     GaugeSegment::new("cached", 0.25, Color::Yellow),
+///     GaugeSegment::new("free", 0.35, Color::Green),
+/// ])
+/// .block(Block::default().borders(Borders::ALL).title("Memory"))
+/// .legend(true);
+/// ```
+#[derive(Debug, Clone)]
+pub struct StackedGauge<'a> {
+    block: Option<Block<'a>>,
+    segments: Vec<GaugeSegment<'a>>,
+    style: Style,
+    /// Style of the labels drawn over the segments
+    label_style: Style,
+    legend: bool,
+}
+
+impl<'a> StackedGauge<'a> {
+    pub fn new<T>(segments: T) -> StackedGauge<'a>
+    where
+        T: Into<Vec<GaugeSegment<'a>>>,
+    {
+        StackedGauge {
+            block: None,
+            segments: segments.into(),
+            style: Style::DEFAULT,
+            label_style: Style::DEFAULT,
+            legend: false,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> StackedGauge<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> StackedGauge<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn label_style(mut self, style: Style) -> StackedGauge<'a> {
+        self.label_style = style;
+        self
+    }
+
+    /// Display a legend listing the segments on the last line of the gauge.
+    pub fn legend(mut self, legend: bool) -> StackedGauge<'a> {
+        self.legend = legend;
+        self
+    }
+}
+
+impl<'a> Widget for StackedGauge<'a> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let mut gauge_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+        if gauge_area.area() == 0 {
+            return;
+        }
+
+        if self.legend {
+            gauge_area.height -= 1;
+            let mut x = gauge_area.left();
+            let y = gauge_area.bottom();
+            for (i, segment) in self.segments.iter().enumerate() {
+                let separator = if i > 0 { "  " } else { "" };
+                let marker = Span::styled("■ ", Style::DEFAULT.fg(segment.color));
+                for span in [Span::raw(separator), marker, segment.label.clone()] {
+                    if x >= gauge_area.right() {
+                        break;
+                    }
+                    x = buf.set_span(x, y, &span, gauge_area.right() - x).0;
+                }
+            }
+        }
+
+        // Segments are placed by rounding their cumulative ratios, so that they are contiguous
+        let width = f64::from(gauge_area.width);
+        let label_row = gauge_area.top() + gauge_area.height / 2;
+        let mut cumulated = 0.0;
+        for segment in &self.segments {
+            let start = (cumulated * width).round() as u16;
+            cumulated = (cumulated + segment.ratio).min(1.0);
+            let end = (cumulated * width).round() as u16;
+            let segment_area = Rect {
+                x: gauge_area.left() + start,
+                width: end - start,
+                ..gauge_area
+            };
+            if segment_area.area() == 0 {
+                continue;
+            }
+            buf.set_style(segment_area, Style::DEFAULT.bg(segment.color));
+            let label_width = segment.label.width() as u16;
+            if label_width <= segment_area.width {
+                let label_col = segment_area.left() + (segment_area.width - label_width) / 2;
+                buf.set_span(label_col, label_row, &segment.label, label_width);
+                buf.set_style(
+                    Rect::new(label_col, label_row, label_width, 1),
+                    self.label_style,
+                );
+            }
+        }
+    }
+}
+
 /// A compact widget to display a task progress over a single line.
 ///
 /// # Examples:
@@ -177,8 +454,10 @@ pub struct LineGauge<'a> {
     ratio: f64,
     label: Option<Spans<'a>>,
     line_set: symbols::line::Set,
+    precision: GaugePrecision,
     style: Style,
     gauge_style: Style,
+    gauge_gradient: Option<Gradient>,
 }
 
 impl<'a> Default for LineGauge<'a> {
@@ -189,7 +468,9 @@ impl<'a> Default for LineGauge<'a> {
             label: None,
             style: Style::DEFAULT,
             line_set: symbols::line::NORMAL,
+            precision: GaugePrecision::Cell,
             gauge_style: Style::DEFAULT,
+            gauge_gradient: None,
         }
     }
 }
@@ -214,6 +495,11 @@ impl<'a> LineGauge<'a> {
         self
     }
 
+    pub fn precision(mut self, precision: GaugePrecision) -> Self {
+        self.precision = precision;
+        self
+    }
+
     pub fn label<T>(mut self, label: T) -> Self
     where
         T: Into<Spans<'a>>,
@@ -231,6 +517,15 @@ impl<'a> LineGauge<'a> {
         self.gauge_style = style;
         self
     }
+
+    /// Colors the filled part of the line with a gradient spanning the whole line from left to
+    /// right, whatever the direction of the gradient, instead of the foreground color of
+    /// [`LineGauge::gauge_style`]. The color of each cell thus depends on its position, e.g.
+    /// turning from green to red as the ratio grows.
+    pub fn gauge_gradient(mut self, gradient: Gradient) -> Self {
+        self.gauge_gradient = Some(gradient);
+        self
+    }
 }
 
 impl<'a> Widget for LineGauge<'a> {
@@ -264,17 +559,31 @@ impl<'a> Widget for LineGauge<'a> {
             return;
         }
 
-        let end = start
-            + (f64::from(gauge_area.right().saturating_sub(start)) * self.ratio).floor() as u16;
+        let filled_width = f64::from(gauge_area.right().saturating_sub(start)) * self.ratio;
+        let end = start + filled_width.floor() as u16;
+        let filled_style = Style {
+            fg: self.gauge_style.fg,
+            bg: None,
+            underline_color: self.gauge_style.underline_color,
+            hyperlink: self.gauge_style.hyperlink,
+            fg_alpha: self.gauge_style.fg_alpha,
+            bg_alpha: None,
+            underline_alpha: self.gauge_style.underline_alpha,
+            add_modifier: self.gauge_style.add_modifier,
+            sub_modifier: self.gauge_style.sub_modifier,
+        };
+        let length = gauge_area.right() - start;
+        let filled_style = |col: u16| match self.gauge_gradient {
+            Some(ref gradient) if length > 1 => {
+                filled_style.fg(gradient.color_at(f64::from(col - start) / f64::from(length - 1)))
+            }
+            Some(ref gradient) => filled_style.fg(gradient.color_at(0.0)),
+            None => filled_style,
+        };
         for col in start..end {
             buf.get_mut(col, row)
                 .set_symbol(self.line_set.horizontal)
-                .set_style(Style {
-                    fg: self.gauge_style.fg,
-                    bg: None,
-                    add_modifier: self.gauge_style.add_modifier,
-                    sub_modifier: self.gauge_style.sub_modifier,
-                });
+                .set_style(filled_style(col));
         }
         for col in end..gauge_area.right() {
             buf.get_mut(col, row)
@@ -282,10 +591,28 @@ impl<'a> Widget for LineGauge<'a> {
                 .set_style(Style {
                     fg: self.gauge_style.bg,
                     bg: None,
+                    underline_color: self.gauge_style.underline_color,
+                    hyperlink: self.gauge_style.hyperlink,
+                    fg_alpha: self.gauge_style.bg_alpha,
+                    bg_alpha: None,
+                    underline_alpha: self.gauge_style.underline_alpha,
                     add_modifier: self.gauge_style.add_modifier,
                     sub_modifier: self.gauge_style.sub_modifier,
                 });
         }
+        if self.precision == GaugePrecision::SubCell
+            && end < gauge_area.right()
+            && filled_width.fract() >= 0.5
+        {
+            let symbol = if self.line_set.horizontal == symbols::line::THICK_HORIZONTAL {
+                symbols::line::THICK_HORIZONTAL_LEFT_HALF
+            } else {
+                symbols::line::HORIZONTAL_LEFT_HALF
+            };
+            buf.get_mut(end, row)
+                .set_symbol(symbol)
+                .set_style(filled_style(end));
+        }
     }
 }
 
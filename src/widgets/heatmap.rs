@@ -0,0 +1,213 @@
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Gradient, Style, Styled},
+    text::Span,
+    widgets::{Block, LabelFormat, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+/// A widget mapping a matrix of values to colored cells.
+///
+/// Each value is drawn as a cell whose background color is taken from a [`Gradient`], the
+/// minimum value of the scale giving the first color of the gradient and the maximum value the
+/// last one. `NaN` values are left blank. Rows can be labeled on the left of the cells and columns
+/// under them.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, Heatmap, LabelFormat};
+/// # use tui::style::{Color, Gradient};
+/// # use tui::text::Span;
+/// let data = vec![vec![1.0, 0.2, -0.5], vec![0.2, 1.0, 0.1], vec![-0.5, 0.1, 1.0]];
+/// Heatmap::new(&data)
+///     .block(Block::default().title("Correlations").borders(Borders::ALL))
+///     .bounds([-1.0, 1.0])
+///     .gradient(Gradient::new([Color::Blue, Color::White, Color::Red]))
+///     .cell_width(5)
+///     .cell_labels(LabelFormat::Decimal(1))
+///     .row_labels(vec![Span::raw("cpu"), Span::raw("mem"), Span::raw("io")])
+///     .column_labels(vec![Span::raw("cpu"), Span::raw("mem"), Span::raw("io")]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Heatmap<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    /// Rows of values
+    data: &'a [Vec<f64>],
+    /// Values mapped to the first and last colors of the gradient (if none are specified, the
+    /// minimum and maximum values of the data are taken as reference)
+    bounds: Option<[f64; 2]>,
+    gradient: Gradient,
+    cell_width: u16,
+    cell_height: u16,
+    /// Format of the values printed in the cells, if any
+    cell_labels: Option<LabelFormat<'a>>,
+    row_labels: Vec<Span<'a>>,
+    column_labels: Vec<Span<'a>>,
+}
+
+impl<'a> Heatmap<'a> {
+    pub fn new(data: &'a [Vec<f64>]) -> Heatmap<'a> {
+        Heatmap {
+            block: None,
+            style: Style::DEFAULT,
+            data,
+            bounds: None,
+            gradient: Gradient::new([Color::Blue, Color::Red]),
+            cell_width: 2,
+            cell_height: 1,
+            cell_labels: None,
+            row_labels: Vec::new(),
+            column_labels: Vec::new(),
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Heatmap<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Heatmap<'a> {
+        self.style = style;
+        self
+    }
+
+    /// Set the values mapped to the first and last colors of the gradient. Values out of the
+    /// bounds get the color of the closest bound.
+    pub fn bounds(mut self, bounds: [f64; 2]) -> Heatmap<'a> {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    pub fn gradient(mut self, gradient: Gradient) -> Heatmap<'a> {
+        self.gradient = gradient;
+        self
+    }
+
+    pub fn cell_width(mut self, width: u16) -> Heatmap<'a> {
+        self.cell_width = width.max(1);
+        self
+    }
+
+    pub fn cell_height(mut self, height: u16) -> Heatmap<'a> {
+        self.cell_height = height.max(1);
+        self
+    }
+
+    /// Print the values in the middle row of their cells, in black or white depending on the
+    /// color of the cell.
+    pub fn cell_labels(mut self, format: LabelFormat<'a>) -> Heatmap<'a> {
+        self.cell_labels = Some(format);
+        self
+    }
+
+    /// Set the labels displayed on the left of the rows, the n-th label being displayed next to
+    /// the n-th row.
+    pub fn row_labels(mut self, labels: Vec<Span<'a>>) -> Heatmap<'a> {
+        self.row_labels = labels;
+        self
+    }
+
+    /// Set the labels displayed under the columns, truncated to the width of the cells.
+    pub fn column_labels(mut self, labels: Vec<Span<'a>>) -> Heatmap<'a> {
+        self.column_labels = labels;
+        self
+    }
+
+    fn bounds_or_data_bounds(&self) -> [f64; 2] {
+        self.bounds.unwrap_or_else(|| {
+            let values = self.data.iter().flatten().copied().filter(|v| !v.is_nan());
+            values.fold([f64::INFINITY, f64::NEG_INFINITY], |[min, max], v| {
+                [min.min(v), max.max(v)]
+            })
+        })
+    }
+}
+
+impl<'a> Styled for Heatmap<'a> {
+    fn patch_style(mut self, style: Style) -> Heatmap<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> Widget for Heatmap<'a> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let mut cells_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+
+        let labels_width = (self.row_labels.iter().map(Span::width).max().unwrap_or(0) as u16)
+            .min(cells_area.width / 3);
+        if labels_width > 0 {
+            for (i, label) in self.row_labels.iter().enumerate() {
+                let y = cells_area.top() + i as u16 * self.cell_height;
+                if y >= cells_area.bottom() {
+                    break;
+                }
+                buf.set_span(cells_area.left(), y, label, labels_width);
+            }
+            cells_area.x += labels_width + 1;
+            cells_area.width = cells_area.width.saturating_sub(labels_width + 1);
+        }
+        if !self.column_labels.is_empty() && cells_area.height > 0 {
+            cells_area.height -= 1;
+            for (i, label) in self.column_labels.iter().enumerate() {
+                let x = cells_area.left() + i as u16 * self.cell_width;
+                if x >= cells_area.right() {
+                    break;
+                }
+                let width = self.cell_width.min(cells_area.right() - x);
+                buf.set_span(x, cells_area.bottom(), label, width);
+            }
+        }
+        if cells_area.area() == 0 {
+            return;
+        }
+
+        let [min, max] = self.bounds_or_data_bounds();
+        for (i, row) in self.data.iter().enumerate() {
+            let y = cells_area.top() + i as u16 * self.cell_height;
+            if y >= cells_area.bottom() {
+                break;
+            }
+            for (j, &value) in row.iter().enumerate() {
+                let x = cells_area.left() + j as u16 * self.cell_width;
+                let cell = Rect::new(x, y, self.cell_width, self.cell_height);
+                let cell = match cell.intersection(cells_area) {
+                    Some(cell) => cell,
+                    None => break,
+                };
+                if value.is_nan() {
+                    continue;
+                }
+                let ratio = if max > min {
+                    (value - min) / (max - min)
+                } else {
+                    0.0
+                };
+                let color = self.gradient.color_at(ratio);
+                buf.set_style(cell, Style::DEFAULT.bg(color));
+                if let Some(format) = &self.cell_labels {
+                    let label = format.format(value);
+                    let width = (label.width() as u16).min(cell.width);
+                    buf.set_stringn(
+                        cell.left() + (cell.width - width) / 2,
+                        cell.top() + (cell.height - 1) / 2,
+                        label,
+                        usize::from(width),
+                        Style::DEFAULT.fg(color.contrast_fg()),
+                    );
+                }
+            }
+        }
+    }
+}
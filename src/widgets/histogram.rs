@@ -0,0 +1,236 @@
+use crate::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Style, Styled},
+    widgets::{BarChart, Block, LabelFormat, Widget},
+};
+
+/// A widget counting samples in bins of equal width and displaying the counts as bars.
+///
+/// The samples are binned over the given range, or over the range of the samples if none is
+/// given, samples out of the range being ignored. Each bar is labeled with the lower bound of its
+/// bin. The bars are drawn by a [`BarChart`], so the same options are available to lay them out.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, Histogram, LabelFormat};
+/// # use tui::layout::Direction;
+/// let latencies = [12.0, 15.5, 14.2, 40.1, 13.3, 18.0, 22.7];
+/// Histogram::new(&latencies)
+///     .block(Block::default().title("Latency (ms)").borders(Borders::ALL))
+///     .range([0.0, 50.0])
+///     .bin_width(5.0)
+///     .label_format(LabelFormat::Decimal(0))
+///     .direction(Direction::Horizontal);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Histogram<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    samples: &'a [f64],
+    /// Range of the bins (if none is specified, the minimum and maximum samples are taken as
+    /// reference)
+    range: Option<[f64; 2]>,
+    bins: usize,
+    /// Width of the bins, taking precedence over their number
+    bin_width: Option<f64>,
+    label_format: LabelFormat<'a>,
+    bar_style: Style,
+    bar_width: u16,
+    bar_gap: u16,
+    direction: Direction,
+}
+
+impl<'a> Histogram<'a> {
+    pub fn new(samples: &'a [f64]) -> Histogram<'a> {
+        Histogram {
+            block: None,
+            style: Style::DEFAULT,
+            samples,
+            range: None,
+            bins: 10,
+            bin_width: None,
+            label_format: LabelFormat::Decimal(1),
+            bar_style: Style::DEFAULT,
+            bar_width: 3,
+            bar_gap: 1,
+            direction: Direction::Vertical,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Histogram<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Histogram<'a> {
+        self.style = style;
+        self
+    }
+
+    /// Set the range of the samples counted, the range of the finite samples by default. Nothing
+    /// is drawn if the range is not finite.
+    pub fn range(mut self, range: [f64; 2]) -> Histogram<'a> {
+        self.range = Some(range);
+        self
+    }
+
+    /// Set the number of bins the range is divided into. The bins are widened if there are more of
+    /// them than bars fitting in the area.
+    pub fn bins(mut self, bins: usize) -> Histogram<'a> {
+        self.bins = bins.max(1);
+        self
+    }
+
+    /// Set the width of the bins. The last bin may extend past the end of the range. As with
+    /// [`Histogram::bins`], the bins are widened if there are more of them than bars fitting in
+    /// the area.
+    pub fn bin_width(mut self, width: f64) -> Histogram<'a> {
+        self.bin_width = Some(width);
+        self
+    }
+
+    /// Set the format of the lower bounds of the bins labeling the bars.
+    pub fn label_format(mut self, format: LabelFormat<'a>) -> Histogram<'a> {
+        self.label_format = format;
+        self
+    }
+
+    pub fn bar_style(mut self, style: Style) -> Histogram<'a> {
+        self.bar_style = style;
+        self
+    }
+
+    pub fn bar_width(mut self, width: u16) -> Histogram<'a> {
+        self.bar_width = width;
+        self
+    }
+
+    pub fn bar_gap(mut self, gap: u16) -> Histogram<'a> {
+        self.bar_gap = gap;
+        self
+    }
+
+    /// Set the direction in which the bars grow, see [`BarChart::direction`].
+    pub fn direction(mut self, direction: Direction) -> Histogram<'a> {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns the lower bound and the number of samples of each bin, the bins being widened so
+    /// that there are at most `max_bins` of them.
+    fn count(&self, max_bins: usize) -> Vec<(f64, u64)> {
+        let [min, max] = self.range.unwrap_or_else(|| {
+            let samples = self.samples.iter().copied().filter(|s| s.is_finite());
+            samples.fold([f64::INFINITY, f64::NEG_INFINITY], |[min, max], s| {
+                [min.min(s), max.max(s)]
+            })
+        });
+        if !min.is_finite() || !max.is_finite() || min > max {
+            return Vec::new();
+        }
+        let max_bins = max_bins.max(1);
+        let (bins, width) = match self.bin_width {
+            Some(width) if width > 0.0 => (((max - min) / width).ceil().max(1.0) as usize, width),
+            _ => (self.bins, (max - min) / self.bins as f64),
+        };
+        let (bins, width) = if bins > max_bins {
+            (max_bins, (max - min) / max_bins as f64)
+        } else {
+            (bins, width)
+        };
+        let mut counts = vec![0; bins];
+        for &sample in self.samples {
+            if !(min..=max).contains(&sample) {
+                continue;
+            }
+            let index = if width > 0.0 {
+                ((sample - min) / width) as usize
+            } else {
+                0
+            };
+            counts[index.min(bins - 1)] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| (min + i as f64 * width, count))
+            .collect()
+    }
+}
+
+impl<'a> Styled for Histogram<'a> {
+    fn patch_style(mut self, style: Style) -> Histogram<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> Widget for Histogram<'a> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+
+        // Each bar takes at least a cell
+        let max_bins = match self.direction {
+            Direction::Vertical => area.width,
+            Direction::Horizontal => area.height,
+        };
+        let bins = self.count(usize::from(max_bins));
+        let labels: Vec<String> = bins
+            .iter()
+            .map(|(bound, _)| self.label_format.format(*bound))
+            .collect();
+        let data: Vec<(&str, u64)> = labels
+            .iter()
+            .zip(&bins)
+            .map(|(label, (_, count))| (label.as_str(), *count))
+            .collect();
+        BarChart::default()
+            .data(&data)
+            .bar_style(self.bar_style)
+            .bar_width(self.bar_width)
+            .bar_gap(self.bar_gap)
+            .direction(self.direction)
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_counts_samples_in_bins() {
+        let samples = [0.0, 0.5, 1.0, 2.5, 4.0, 7.0, f64::NAN];
+        let histogram = Histogram::new(&samples).bins(4);
+        assert_eq!(
+            histogram.count(10),
+            vec![(0.0, 3), (1.75, 1), (3.5, 1), (5.25, 1)]
+        );
+
+        let histogram = Histogram::new(&samples).range([0.0, 3.0]).bin_width(2.0);
+        assert_eq!(histogram.count(10), vec![(0.0, 3), (2.0, 1)]);
+    }
+
+    #[test]
+    fn it_limits_the_number_of_bins() {
+        let samples = [0.0, 1.0, 2.0, 3.0];
+        let histogram = Histogram::new(&samples).bin_width(1e-300);
+        assert_eq!(histogram.count(2), vec![(0.0, 2), (1.5, 2)]);
+
+        let histogram = Histogram::new(&samples).bins(usize::MAX);
+        assert_eq!(histogram.count(2), vec![(0.0, 2), (1.5, 2)]);
+
+        let histogram = Histogram::new(&samples).range([0.0, f64::INFINITY]);
+        assert_eq!(histogram.count(2), vec![]);
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    text::Span,
+    widgets::{
+        textarea::{byte_index, char_count, display_width},
+        Block, StatefulWidget, Widget,
+    },
+};
+use unicode_width::UnicodeWidthChar;
+
+/// The value and editing state of an [`Input`].
+///
+/// The cursor counts chars, not bytes. Insertions beyond the maximum length, if any, are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::InputState;
+/// let mut state = InputState::default().max_length(5);
+/// state.insert_str("Hello, World");
+/// assert_eq!(state.value(), "Hello");
+/// state.move_left();
+/// state.delete_backward();
+/// assert_eq!(state.value(), "Helo");
+/// assert_eq!(state.cursor(), 3);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputState {
+    value: String,
+    cursor: usize,
+    max_length: Option<usize>,
+    /// First visible display column, as of the last render
+    offset: usize,
+    /// Position of the cursor on screen, as of the last render
+    screen_cursor: Option<(u16, u16)>,
+}
+
+impl InputState {
+    /// Creates a state holding the given value, with the cursor at its end.
+    pub fn new(value: &str) -> InputState {
+        let mut state = InputState::default();
+        state.set_value(value);
+        state
+    }
+
+    /// Limits the value to `max_length` chars, truncating the current one if needed.
+    pub fn max_length(mut self, max_length: usize) -> InputState {
+        self.max_length = Some(max_length);
+        let value = std::mem::take(&mut self.value);
+        self.set_value(&value);
+        self
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the value and moves the cursor to its end.
+    pub fn set_value(&mut self, value: &str) {
+        let value = match self.max_length {
+            Some(max_length) => &value[..byte_index(value, max_length)],
+            None => value,
+        };
+        self.value = value.to_string();
+        self.cursor = char_count(value);
+    }
+
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the cursor to the given char index, clamped to the value.
+    pub fn set_cursor(&mut self, cursor: usize) {
+        self.cursor = cursor.min(char_count(&self.value));
+    }
+
+    /// Position of the cursor on screen as of the last render, to be given to
+    /// [`Frame::set_cursor`](crate::terminal::Frame::set_cursor). `None` if the area was empty.
+    pub fn screen_cursor(&self) -> Option<(u16, u16)> {
+        self.screen_cursor
+    }
+
+    /// Inserts a char at the cursor. Returns `false` if the value is already at its maximum
+    /// length.
+    pub fn insert_char(&mut self, c: char) -> bool {
+        if let Some(max_length) = self.max_length {
+            if char_count(&self.value) >= max_length {
+                return false;
+            }
+        }
+        self.value.insert(byte_index(&self.value, self.cursor), c);
+        self.cursor += 1;
+        true
+    }
+
+    /// Inserts as much of `text` as the maximum length allows.
+    pub fn insert_str(&mut self, text: &str) {
+        for c in text.chars() {
+            if !self.insert_char(c) {
+                break;
+            }
+        }
+    }
+
+    /// Deletes the char before the cursor (backspace).
+    pub fn delete_backward(&mut self) {
+        if self.cursor > 0 {
+            self.value.remove(byte_index(&self.value, self.cursor - 1));
+            self.cursor -= 1;
+        }
+    }
+
+    /// Deletes the char under the cursor (delete).
+    pub fn delete_forward(&mut self) {
+        if self.cursor < char_count(&self.value) {
+            self.value.remove(byte_index(&self.value, self.cursor));
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.set_cursor(self.cursor + 1);
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = char_count(&self.value);
+    }
+}
+
+/// A widget to edit a single line of text.
+///
+/// The value and cursor live in an [`InputState`], which the application updates from its input
+/// events. When a validator is set, the value is drawn with the invalid style whenever the
+/// validator rejects it.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, Input};
+/// # use tui::style::{Style, Color};
+/// Input::default()
+///     .block(Block::default().title("Password").borders(Borders::ALL))
+///     .placeholder("At least 8 chars")
+///     .mask('*')
+///     .validator(|value| value.chars().count() >= 8)
+///     .invalid_style(Style::DEFAULT.fg(Color::Red));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Input<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    /// Text displayed while the value is empty
+    placeholder: Option<Span<'a>>,
+    /// Char displayed in place of each char of the value
+    mask: Option<char>,
+    validator: Option<fn(&str) -> bool>,
+    /// Style applied to the value when the validator rejects it
+    invalid_style: Style,
+}
+
+impl<'a> Default for Input<'a> {
+    fn default() -> Input<'a> {
+        Input {
+            block: None,
+            style: Style::DEFAULT,
+            placeholder: None,
+            mask: None,
+            validator: None,
+            invalid_style: Style::DEFAULT,
+        }
+    }
+}
+
+impl<'a> Input<'a> {
+    pub fn block(mut self, block: Block<'a>) -> Input<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Input<'a> {
+        self.style = style;
+        self
+    }
+
+    /// Set the text displayed while the value is empty. Unstyled placeholders are dimmed.
+    pub fn placeholder<T>(mut self, placeholder: T) -> Input<'a>
+    where
+        T: Into<Span<'a>>,
+    {
+        let mut placeholder = placeholder.into();
+        if placeholder.style == Style::DEFAULT {
+            placeholder.style = Style::DEFAULT.add_modifier(Modifier::DIM);
+        }
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    pub fn mask(mut self, mask: char) -> Input<'a> {
+        self.mask = Some(mask);
+        self
+    }
+
+    pub fn validator(mut self, validator: fn(&str) -> bool) -> Input<'a> {
+        self.validator = Some(validator);
+        self
+    }
+
+    pub fn invalid_style(mut self, style: Style) -> Input<'a> {
+        self.invalid_style = style;
+        self
+    }
+}
+
+impl<'a> Styled for Input<'a> {
+    fn patch_style(mut self, style: Style) -> Input<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> StatefulWidget for Input<'a> {
+    type State = InputState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+        state.screen_cursor = None;
+        if area.area() == 0 {
+            return;
+        }
+        if !self
+            .validator
+            .map_or(true, |validator| validator(&state.value))
+        {
+            buf.set_style(area, self.invalid_style);
+        }
+
+        let width = usize::from(area.width);
+        let y = area.top();
+        if state.value.is_empty() {
+            state.offset = 0;
+            if let Some(placeholder) = self.placeholder {
+                buf.set_span(area.left(), y, &placeholder, area.width);
+            }
+            state.screen_cursor = Some((area.left(), y));
+            return;
+        }
+
+        let displayed = match self.mask {
+            Some(mask) => std::iter::repeat(mask)
+                .take(char_count(&state.value))
+                .collect(),
+            None => state.value.clone(),
+        };
+        let cursor_x = display_width(&displayed, state.cursor);
+        if cursor_x < state.offset {
+            state.offset = cursor_x;
+        } else if cursor_x >= state.offset + width {
+            state.offset = cursor_x + 1 - width;
+        }
+
+        let mut x = 0;
+        for c in displayed.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if char_width == 0 {
+                continue;
+            }
+            if x >= state.offset + width {
+                break;
+            }
+            // Wide chars crossing one of the edges are not drawn
+            if x >= state.offset && x + char_width <= state.offset + width {
+                let cell_x = area.left() + (x - state.offset) as u16;
+                buf.get_mut(cell_x, y).set_char(c);
+                for i in 1..char_width {
+                    buf.get_mut(cell_x + i as u16, y).reset();
+                }
+            }
+            x += char_width;
+        }
+        state.screen_cursor = Some((area.left() + (cursor_x - state.offset) as u16, y));
+    }
+}
@@ -1,16 +1,37 @@
 use crate::{
     buffer::Buffer,
-    layout::{Corner, Rect},
-    style::Style,
-    text::Text,
-    widgets::{Block, StatefulWidget, Widget},
+    layout::{Corner, Direction, Rect},
+    style::{Style, Styled},
+    text::{Span, Text},
+    widgets::{navigation, Block, StatefulWidget, Widget},
 };
+use std::{collections::BTreeSet, fmt, ops::Range};
 use unicode_width::UnicodeWidthStr;
 
+/// State of a [`List`]: the highlighted item, the scroll offset, and the set of selected items.
+///
+/// The highlighted item is the one given by [`ListState::selected`], while several items can be
+/// part of the selection, for instance to apply an action to all of them.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::ListState;
+/// let mut state = ListState::default();
+/// state.toggle_selection(1);
+/// state.extend_selection_to(3);
+/// assert_eq!(state.selection().iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+/// assert_eq!(state.selected(), Some(3));
+/// state.extend_selection_to(0);
+/// assert_eq!(state.selection().iter().copied().collect::<Vec<_>>(), [0, 1]);
+/// ```
 #[derive(Debug, Clone, Default)]
 pub struct ListState {
     offset: usize,
     selected: Option<usize>,
+    selection: BTreeSet<usize>,
+    /// Item from which range selections start
+    anchor: Option<usize>,
 }
 
 impl ListState {
@@ -18,12 +39,91 @@ impl ListState {
         self.selected
     }
 
+    /// Index of the first item displayed, as of the last render.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
     pub fn select(&mut self, index: Option<usize>) {
         self.selected = index;
         if index.is_none() {
             self.offset = 0;
         }
     }
+
+    /// Indices of the selected items, in order.
+    pub fn selection(&self) -> &BTreeSet<usize> {
+        &self.selection
+    }
+
+    pub fn is_in_selection(&self, index: usize) -> bool {
+        self.selection.contains(&index)
+    }
+
+    /// Adds the item to the selection or removes it, and makes it the anchor of range selections.
+    pub fn toggle_selection(&mut self, index: usize) {
+        if !self.selection.remove(&index) {
+            self.selection.insert(index);
+        }
+        self.anchor = Some(index);
+    }
+
+    /// Selects the items between the anchor and `index`, both included, like a shift-click would,
+    /// and highlights `index`. The anchor defaults to the highlighted item. Items selected from
+    /// the same anchor before are unselected first.
+    pub fn extend_selection_to(&mut self, index: usize) {
+        let anchor = *self
+            .anchor
+            .get_or_insert_with(|| self.selected.unwrap_or(index));
+        if let Some(previous) = self.selected {
+            let (start, end) = (anchor.min(previous), anchor.max(previous));
+            for i in start..=end {
+                self.selection.remove(&i);
+            }
+        }
+        let (start, end) = (anchor.min(index), anchor.max(index));
+        self.selection.extend(start..=end);
+        self.selected = Some(index);
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selection.clear();
+        self.anchor = None;
+    }
+
+    /// Highlights the item after the highlighted one, or the first item if none is. At the end of
+    /// the `len` items, goes back to the first one if `wrap` is set and stays on the last one
+    /// otherwise.
+    pub fn select_next(&mut self, len: usize, wrap: bool) {
+        self.select(navigation::next(self.selected, len, wrap));
+    }
+
+    /// Highlights the item before the highlighted one, or the last item if none is. At the start
+    /// of the `len` items, goes to the last one if `wrap` is set and stays on the first one
+    /// otherwise.
+    pub fn select_previous(&mut self, len: usize, wrap: bool) {
+        self.select(navigation::previous(self.selected, len, wrap));
+    }
+
+    /// Highlights the first of the `len` items, if any.
+    pub fn select_first(&mut self, len: usize) {
+        self.select(navigation::first(len));
+    }
+
+    /// Highlights the last of the `len` items, if any.
+    pub fn select_last(&mut self, len: usize) {
+        self.select(navigation::last(len));
+    }
+
+    /// Moves the highlight `page` items down, stopping at the last of the `len` items.
+    pub fn scroll_page_down(&mut self, len: usize, page: usize) {
+        self.select(navigation::page_down(self.selected, len, page));
+    }
+
+    /// Moves the highlight `page` items up, stopping at the first item.
+    pub fn scroll_page_up(&mut self, len: usize, page: usize) {
+        self.select(navigation::page_up(self.selected, len, page));
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -51,10 +151,25 @@ impl<'a> ListItem<'a> {
     pub fn height(&self) -> usize {
         self.content.height()
     }
+
+    pub fn width(&self) -> usize {
+        self.content.width()
+    }
+}
+
+impl<'a> Styled for ListItem<'a> {
+    fn patch_style(mut self, style: Style) -> ListItem<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
 }
 
 /// A widget to display several items among which one can be selected (optional)
 ///
+/// Items are stacked vertically by default. With [`Direction::Horizontal`], they are laid out
+/// side by side, separated by [`List::separator`], and the list scrolls horizontally to keep the
+/// selected item visible, which suits toolbars and rows of chips.
+///
 /// # Examples
 ///
 /// ```
@@ -80,6 +195,16 @@ pub struct List<'a> {
     highlight_symbol: Option<&'a str>,
     /// Whether to repeat the highlight symbol for each line of the selected item
     repeat_highlight_symbol: bool,
+    /// Style used to render the items of the selection
+    selection_style: Style,
+    /// Symbol in front of the items of the selection
+    selection_symbol: Option<&'a str>,
+    /// Direction in which the items are laid out
+    direction: Direction,
+    /// Separator drawn between items laid out horizontally
+    separator: Span<'a>,
+    /// Number of items to keep visible around the selected one when scrolling
+    scroll_padding: usize,
 }
 
 impl<'a> List<'a> {
@@ -95,6 +220,11 @@ impl<'a> List<'a> {
             highlight_style: Style::DEFAULT,
             highlight_symbol: None,
             repeat_highlight_symbol: false,
+            selection_style: Style::DEFAULT,
+            selection_symbol: None,
+            direction: Direction::Vertical,
+            separator: Span::raw(" "),
+            scroll_padding: 0,
         }
     }
 
@@ -123,47 +253,153 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Set the style of the items in the selection of the [`ListState`]. The highlight style is
+    /// applied on top of it.
+    pub fn selection_style(mut self, style: Style) -> List<'a> {
+        self.selection_style = style;
+        self
+    }
+
+    /// Set the symbol displayed in front of the items in the selection of the [`ListState`],
+    /// after the highlight symbol.
+    pub fn selection_symbol(mut self, selection_symbol: &'a str) -> List<'a> {
+        self.selection_symbol = Some(selection_symbol);
+        self
+    }
+
+    /// Only used when the items are laid out vertically.
     pub fn start_corner(mut self, corner: Corner) -> List<'a> {
         self.start_corner = corner;
         self
     }
 
-    fn get_items_bounds(
-        &self,
-        selected: Option<usize>,
-        offset: usize,
-        max_height: usize,
-    ) -> (usize, usize) {
-        let offset = offset.min(self.items.len().saturating_sub(1));
-        let mut start = offset;
-        let mut end = offset;
-        let mut height = 0;
-        for item in self.items.iter().skip(offset) {
-            if height + item.height() > max_height {
-                break;
+    /// Set the direction in which the items are laid out. Defaults to [`Direction::Vertical`].
+    pub fn direction(mut self, direction: Direction) -> List<'a> {
+        self.direction = direction;
+        self
+    }
+
+    /// Set the separator drawn between items laid out horizontally. Defaults to a single space.
+    pub fn separator<T>(mut self, separator: T) -> List<'a>
+    where
+        T: Into<Span<'a>>,
+    {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Keep up to `items` items visible before and after the selected item when scrolling, as
+    /// long as the list is long enough.
+    pub fn scroll_padding(mut self, items: usize) -> List<'a> {
+        self.scroll_padding = items;
+        self
+    }
+
+    fn render_horizontal(self, list_area: Rect, buf: &mut Buffer, state: &mut ListState) {
+        let has_selection = state.selected.is_some();
+        let highlight_symbol = self.highlight_symbol.unwrap_or("");
+        let selection_symbol = self.selection_symbol.unwrap_or("");
+        let prefix_width = if has_selection {
+            highlight_symbol.width()
+        } else {
+            0
+        } + selection_symbol.width();
+        let separator_width = self.separator.width();
+
+        // Every item but the last is followed by a separator
+        let (start, end) = navigation::bounds(
+            self.items.len(),
+            state.selected,
+            state.offset,
+            usize::from(list_area.width),
+            self.scroll_padding,
+            |i| (prefix_width + self.items[i].width(), separator_width),
+        );
+        state.offset = start;
+
+        let blank_symbol = " ".repeat(highlight_symbol.width());
+        let blank_selection_symbol = " ".repeat(selection_symbol.width());
+        let mut x = list_area.left();
+        for (i, item) in self.items.iter().enumerate().skip(start).take(end - start) {
+            if i > start {
+                let (separator_x, _) = buf.set_span(
+                    x,
+                    list_area.top(),
+                    &self.separator,
+                    list_area.right().saturating_sub(x),
+                );
+                x = separator_x;
             }
-            height += item.height();
-            end += 1;
-        }
+            let width = ((prefix_width + item.width()) as u16).min(list_area.right() - x);
+            let area = Rect {
+                x,
+                y: list_area.top(),
+                width,
+                height: list_area.height.min(item.height() as u16),
+            };
+            let item_style = self.style.patch(item.style);
+            buf.set_style(area, item_style);
 
-        let selected = selected.unwrap_or(0).min(self.items.len() - 1);
-        while selected >= end {
-            height = height.saturating_add(self.items[end].height());
-            end += 1;
-            while height > max_height {
-                height = height.saturating_sub(self.items[start].height());
-                start += 1;
+            let is_selected = state.selected.map(|s| s == i).unwrap_or(false);
+            let is_in_selection = state.selection.contains(&i);
+            for (j, line) in item
+                .content
+                .lines
+                .iter()
+                .enumerate()
+                .take(area.height as usize)
+            {
+                let y = area.top() + j as u16;
+                let mut elem_x = x;
+                if has_selection {
+                    let symbol = if is_selected && (j == 0 || self.repeat_highlight_symbol) {
+                        highlight_symbol
+                    } else {
+                        &blank_symbol
+                    };
+                    elem_x = buf
+                        .set_stringn(
+                            elem_x,
+                            y,
+                            symbol,
+                            (area.right() - elem_x) as usize,
+                            item_style,
+                        )
+                        .0;
+                }
+                if self.selection_symbol.is_some() {
+                    let symbol = if is_in_selection && j == 0 {
+                        selection_symbol
+                    } else {
+                        &blank_selection_symbol
+                    };
+                    elem_x = buf
+                        .set_stringn(
+                            elem_x,
+                            y,
+                            symbol,
+                            (area.right() - elem_x) as usize,
+                            item_style,
+                        )
+                        .0;
+                }
+                buf.set_spans(elem_x, y, line, area.right() - elem_x);
             }
-        }
-        while selected < start {
-            start -= 1;
-            height = height.saturating_add(self.items[start].height());
-            while height > max_height {
-                end -= 1;
-                height = height.saturating_sub(self.items[end].height());
+            if is_in_selection {
+                buf.set_style(area, self.selection_style);
             }
+            if is_selected {
+                buf.set_style(area, self.highlight_style);
+            }
+            x = area.right();
         }
-        (start, end)
+    }
+}
+
+impl<'a> Styled for List<'a> {
+    fn patch_style(mut self, style: Style) -> List<'a> {
+        self.style = self.style.patch(style);
+        self
     }
 }
 
@@ -188,13 +424,25 @@ impl<'a> StatefulWidget for List<'a> {
         if self.items.is_empty() {
             return;
         }
+        if self.direction == Direction::Horizontal {
+            self.render_horizontal(list_area, buf, state);
+            return;
+        }
         let list_height = list_area.height as usize;
 
-        let (start, end) = self.get_items_bounds(state.selected, state.offset, list_height);
+        let (start, end) = navigation::bounds(
+            self.items.len(),
+            state.selected,
+            state.offset,
+            list_height,
+            self.scroll_padding,
+            |i| (self.items[i].height(), 0),
+        );
         state.offset = start;
 
         let highlight_symbol = self.highlight_symbol.unwrap_or("");
         let blank_symbol = " ".repeat(highlight_symbol.width());
+        let blank_selection_symbol = " ".repeat(self.selection_symbol.unwrap_or("").width());
 
         let mut current_height = 0;
         let has_selection = state.selected.is_some();
@@ -226,6 +474,7 @@ impl<'a> StatefulWidget for List<'a> {
             buf.set_style(area, item_style);
 
             let is_selected = state.selected.map(|s| s == i).unwrap_or(false);
+            let is_in_selection = state.selection.contains(&i);
             for (j, line) in item.content.lines.iter().enumerate() {
                 // if the item is selected, we need to display the hightlight symbol:
                 // - either for the first line of the item only,
@@ -247,8 +496,29 @@ impl<'a> StatefulWidget for List<'a> {
                 } else {
                     (x, list_area.width)
                 };
+                let (elem_x, max_element_width) = match self.selection_symbol {
+                    Some(selection_symbol) => {
+                        let symbol = if is_in_selection && j == 0 {
+                            selection_symbol
+                        } else {
+                            &blank_selection_symbol
+                        };
+                        let (symbol_x, _) = buf.set_stringn(
+                            elem_x,
+                            y + j as u16,
+                            symbol,
+                            max_element_width as usize,
+                            item_style,
+                        );
+                        (symbol_x, max_element_width - (symbol_x - elem_x))
+                    }
+                    None => (elem_x, max_element_width),
+                };
                 buf.set_spans(elem_x, y + j as u16, line, max_element_width as u16);
             }
+            if is_in_selection {
+                buf.set_style(area, self.selection_style);
+            }
             if is_selected {
                 buf.set_style(area, self.highlight_style);
             }
@@ -262,3 +532,226 @@ impl<'a> Widget for List<'a> {
         StatefulWidget::render(self, area, buf, &mut state);
     }
 }
+
+/// A [`List`] whose items are produced on demand, for lists too long to be built in full on
+/// every frame (e.g. a log with millions of lines).
+///
+/// Only the items within the visible window are requested from the provider, which is given the
+/// range of their indices. The list is scrolled with a regular [`ListState`].
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, LazyList, ListItem};
+/// let list = LazyList::new(1_000_000, |range| {
+///     range.map(|i| ListItem::new(format!("Line {}", i))).collect()
+/// })
+/// .block(Block::default().title("Log").borders(Borders::ALL))
+/// .highlight_symbol(">>");
+///
+/// let lines = vec!["Line 1", "Line 2", "Line 3"];
+/// let list = LazyList::from_iter(lines.into_iter().map(ListItem::new));
+/// ```
+pub struct LazyList<'a> {
+    block: Option<Block<'a>>,
+    item_count: usize,
+    provider: Box<dyn Fn(Range<usize>) -> Vec<ListItem<'a>> + 'a>,
+    style: Style,
+    highlight_style: Style,
+    highlight_symbol: Option<&'a str>,
+    repeat_highlight_symbol: bool,
+    selection_style: Style,
+    selection_symbol: Option<&'a str>,
+}
+
+impl<'a> fmt::Debug for LazyList<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyList")
+            .field("block", &self.block)
+            .field("item_count", &self.item_count)
+            .field("provider", &"..")
+            .field("style", &self.style)
+            .field("highlight_style", &self.highlight_style)
+            .field("highlight_symbol", &self.highlight_symbol)
+            .field("repeat_highlight_symbol", &self.repeat_highlight_symbol)
+            .field("selection_style", &self.selection_style)
+            .field("selection_symbol", &self.selection_symbol)
+            .finish()
+    }
+}
+
+impl<'a> LazyList<'a> {
+    /// Creates a list of `item_count` items, the ones in a given range of indices being returned
+    /// by `provider`.
+    pub fn new<F>(item_count: usize, provider: F) -> LazyList<'a>
+    where
+        F: Fn(Range<usize>) -> Vec<ListItem<'a>> + 'a,
+    {
+        LazyList {
+            block: None,
+            item_count,
+            provider: Box::new(provider),
+            style: Style::DEFAULT,
+            highlight_style: Style::DEFAULT,
+            highlight_symbol: None,
+            repeat_highlight_symbol: false,
+            selection_style: Style::DEFAULT,
+            selection_symbol: None,
+        }
+    }
+
+    /// Creates a list from an iterator, which is cloned and advanced to the visible window on
+    /// each render instead of being collected.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_iter<I>(items: I) -> LazyList<'a>
+    where
+        I: ExactSizeIterator<Item = ListItem<'a>> + Clone + 'a,
+    {
+        let item_count = items.len();
+        LazyList::new(item_count, move |range: Range<usize>| {
+            items.clone().skip(range.start).take(range.len()).collect()
+        })
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> LazyList<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> LazyList<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn highlight_symbol(mut self, highlight_symbol: &'a str) -> LazyList<'a> {
+        self.highlight_symbol = Some(highlight_symbol);
+        self
+    }
+
+    pub fn highlight_style(mut self, style: Style) -> LazyList<'a> {
+        self.highlight_style = style;
+        self
+    }
+
+    pub fn repeat_highlight_symbol(mut self, repeat: bool) -> LazyList<'a> {
+        self.repeat_highlight_symbol = repeat;
+        self
+    }
+
+    pub fn selection_style(mut self, style: Style) -> LazyList<'a> {
+        self.selection_style = style;
+        self
+    }
+
+    pub fn selection_symbol(mut self, selection_symbol: &'a str) -> LazyList<'a> {
+        self.selection_symbol = Some(selection_symbol);
+        self
+    }
+
+    /// Returns the first visible item and the items of the visible window, making sure the
+    /// selected item is part of it. Items are assumed to be at least one line high, so at most
+    /// `max_height` of them are requested at once.
+    fn window(
+        &self,
+        selected: Option<usize>,
+        offset: usize,
+        max_height: usize,
+    ) -> (usize, Vec<ListItem<'a>>) {
+        let last = self.item_count - 1;
+        let selected = selected.map(|s| s.min(last));
+        let mut start = offset.min(last);
+        if let Some(selected) = selected {
+            start = start.min(selected);
+        }
+        let end = (start + max_height).min(self.item_count);
+        let items = (self.provider)(start..end);
+        let selected = match selected {
+            Some(selected) => selected,
+            None => return (start, items),
+        };
+
+        let mut height = 0;
+        let fitting = items
+            .iter()
+            .take_while(|item| {
+                height += item.height();
+                height <= max_height
+            })
+            .count();
+        if selected < start + fitting {
+            return (start, items);
+        }
+
+        // Scroll down until the selected item is the last visible one
+        let window_start = (selected + 1).saturating_sub(max_height);
+        let mut items = (self.provider)(window_start..selected + 1);
+        let mut height = 0;
+        let visible = items
+            .iter()
+            .rev()
+            .take_while(|item| {
+                height += item.height();
+                height <= max_height
+            })
+            .count()
+            .max(1);
+        let skipped = items.len().saturating_sub(visible);
+        items.drain(..skipped);
+        (window_start + skipped, items)
+    }
+}
+
+impl<'a> Styled for LazyList<'a> {
+    fn patch_style(mut self, style: Style) -> LazyList<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> StatefulWidget for LazyList<'a> {
+    type State = ListState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let list_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+        if list_area.width < 1 || list_area.height < 1 || self.item_count == 0 {
+            return;
+        }
+
+        let (start, items) = self.window(state.selected, state.offset, list_area.height as usize);
+        state.offset = start;
+        let end = start + items.len();
+        let mut window_state = ListState {
+            offset: 0,
+            selected: state.selected.map(|s| s.min(self.item_count - 1) - start),
+            selection: state
+                .selection
+                .range(start..end)
+                .map(|i| i - start)
+                .collect(),
+            anchor: None,
+        };
+        let mut list = List::new(items)
+            .style(self.style)
+            .highlight_style(self.highlight_style)
+            .repeat_highlight_symbol(self.repeat_highlight_symbol)
+            .selection_style(self.selection_style);
+        list.highlight_symbol = self.highlight_symbol;
+        list.selection_symbol = self.selection_symbol;
+        StatefulWidget::render(list, list_area, buf, &mut window_state);
+    }
+}
+
+impl<'a> Widget for LazyList<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ListState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
@@ -5,39 +5,92 @@
 //!
 //! The available widgets are:
 //! - [`Block`]
+//! - [`BigText`]
 //! - [`Tabs`]
 //! - [`List`]
+//! - [`LazyList`]
 //! - [`Table`]
 //! - [`Paragraph`]
 //! - [`Chart`]
 //! - [`BarChart`]
+//! - [`Candlestick`]
 //! - [`Gauge`]
+//! - [`StackedGauge`]
 //! - [`Sparkline`]
+//! - [`Heatmap`]
+//! - [`Histogram`]
+//! - [`Scrollbar`]
+//! - [`TextArea`]
+//! - [`Input`]
+//! - [`Button`], [`Checkbox`] and [`RadioGroup`]
+//! - [`Slider`]
+//! - [`Select`]
+//! - [`Tree`]
+//! - [`Throbber`]
+//! - [`calendar::Monthly`]
 //! - [`Clear`]
+//! - [`Popup`]
+//! - [`Toasts`]
 
 mod barchart;
+mod big_text;
 mod block;
+pub mod calendar;
+mod candlestick;
 pub mod canvas;
 mod chart;
 mod clear;
+mod form;
 mod gauge;
+mod heatmap;
+mod histogram;
+mod input;
 mod list;
+mod navigation;
 mod paragraph;
+mod popup;
 mod reflow;
+mod scrollbar;
+mod select;
+mod slider;
 mod sparkline;
 mod table;
 mod tabs;
+mod textarea;
+mod throbber;
+mod toast;
+mod tree;
 
-pub use self::barchart::BarChart;
-pub use self::block::{Block, BorderType};
-pub use self::chart::{Axis, Chart, Dataset, GraphType};
+pub use self::barchart::{BarChart, BarMode, BarScale, BarSeries};
+pub use self::big_text::{BigText, PixelSize};
+pub use self::block::{Block, BorderPart, BorderType, Padding, Title, TitlePosition};
+pub use self::candlestick::{Candle, Candlestick};
+pub use self::chart::{
+    Axis, AxisScale, Chart, Dataset, GraphType, LabelFormat, LegendPosition, YAxis,
+};
 pub use self::clear::Clear;
-pub use self::gauge::{Gauge, LineGauge};
-pub use self::list::{List, ListItem, ListState};
-pub use self::paragraph::{Paragraph, Wrap};
-pub use self::sparkline::Sparkline;
-pub use self::table::{Cell, Row, Table, TableState};
-pub use self::tabs::Tabs;
+pub use self::form::{
+    Button, ButtonState, Checkbox, CheckboxState, FocusState, RadioGroup, RadioGroupState,
+};
+pub use self::gauge::{
+    Gauge, GaugeDirection, GaugePrecision, GaugeSegment, LineGauge, StackedGauge,
+};
+pub use self::heatmap::Heatmap;
+pub use self::histogram::Histogram;
+pub use self::input::{Input, InputState};
+pub use self::list::{LazyList, List, ListItem, ListState};
+pub use self::paragraph::{LineNumbers, Paragraph, Truncate, TruncatePosition, Wrap};
+pub use self::popup::Popup;
+pub use self::scrollbar::{Scrollbar, ScrollbarOrientation, ScrollbarState};
+pub use self::select::{Select, SelectState};
+pub use self::slider::{Slider, SliderState};
+pub use self::sparkline::{RenderDirection, Sparkline};
+pub use self::table::{Cell, Row, SortDirection, Table, TableState};
+pub use self::tabs::{Tab, Tabs, TabsState};
+pub use self::textarea::{TextArea, TextAreaState};
+pub use self::throbber::{Throbber, ThrobberState};
+pub use self::toast::{Toast, ToastLevel, ToastState, Toasts};
+pub use self::tree::{Tree, TreeItem, TreeState};
 
 use crate::{buffer::Buffer, layout::Rect};
 use bitflags::bitflags;
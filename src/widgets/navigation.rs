@@ -0,0 +1,182 @@
+//! Index arithmetic shared by the states of the widgets holding a list of selectable items.
+
+pub fn next(selected: Option<usize>, len: usize, wrap: bool) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    Some(match selected {
+        None => 0,
+        Some(i) if i >= last => {
+            if wrap {
+                0
+            } else {
+                last
+            }
+        }
+        Some(i) => i + 1,
+    })
+}
+
+pub fn previous(selected: Option<usize>, len: usize, wrap: bool) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    Some(match selected {
+        None => last,
+        Some(0) => {
+            if wrap {
+                last
+            } else {
+                0
+            }
+        }
+        Some(i) => (i - 1).min(last),
+    })
+}
+
+pub fn first(len: usize) -> Option<usize> {
+    if len == 0 {
+        None
+    } else {
+        Some(0)
+    }
+}
+
+pub fn last(len: usize) -> Option<usize> {
+    len.checked_sub(1)
+}
+
+pub fn page_down(selected: Option<usize>, len: usize, page: usize) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    Some(selected.unwrap_or(0).saturating_add(page).min(last))
+}
+
+pub fn page_up(selected: Option<usize>, len: usize, page: usize) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    Some(selected.unwrap_or(0).min(last).saturating_sub(page))
+}
+
+/// Returns the range of the `len` items to display, from `offset` if possible, so that the
+/// selected item and, if they fit, the `padding` items around it are visible.
+///
+/// `length` returns the length an item takes along the direction of the list and the length of
+/// the gap following it, the gap after the last displayed item being allowed not to fit.
+pub fn bounds<F>(
+    len: usize,
+    selected: Option<usize>,
+    offset: usize,
+    max_length: usize,
+    padding: usize,
+    length: F,
+) -> (usize, usize)
+where
+    F: Fn(usize) -> (usize, usize),
+{
+    if len == 0 {
+        return (0, 0);
+    }
+    let (start, end) = padded_bounds(len, selected, offset, max_length, padding, &length);
+    match selected {
+        // The padding does not fit, only keep the selected item visible
+        Some(selected) if selected.min(len - 1) >= end => {
+            padded_bounds(len, Some(selected), offset, max_length, 0, &length)
+        }
+        _ => (start, end),
+    }
+}
+
+fn padded_bounds<F>(
+    len: usize,
+    selected: Option<usize>,
+    offset: usize,
+    max_length: usize,
+    padding: usize,
+    length: F,
+) -> (usize, usize)
+where
+    F: Fn(usize) -> (usize, usize),
+{
+    let total_length = |i| {
+        let (item_length, gap) = length(i);
+        item_length.saturating_add(gap)
+    };
+    // Whether items of the given total length, the last one being before `end`, overflow
+    let overflows = |total: usize, end: usize| {
+        let last_gap = end.checked_sub(1).map_or(0, |last| length(last).1);
+        total.saturating_sub(last_gap) > max_length
+    };
+    let offset = offset.min(len - 1);
+    let mut start = offset;
+    let mut end = offset;
+    let mut height = 0;
+    for i in offset..len {
+        let (item_length, gap) = length(i);
+        if height + item_length > max_length {
+            break;
+        }
+        height += item_length + gap;
+        end += 1;
+    }
+
+    let selected = selected.unwrap_or(0).min(len - 1);
+    let last = (selected + padding).min(len - 1);
+    while last >= end {
+        height = height.saturating_add(total_length(end));
+        end += 1;
+        while overflows(height, end) {
+            height = height.saturating_sub(total_length(start));
+            start += 1;
+        }
+    }
+    let first = selected.saturating_sub(padding);
+    while first < start {
+        start -= 1;
+        height = height.saturating_add(total_length(start));
+        while overflows(height, end) {
+            end -= 1;
+            height = height.saturating_sub(total_length(end));
+        }
+    }
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_and_previous_stop_or_wrap_at_the_ends() {
+        assert_eq!(next(None, 3, false), Some(0));
+        assert_eq!(next(Some(1), 3, false), Some(2));
+        assert_eq!(next(Some(2), 3, false), Some(2));
+        assert_eq!(next(Some(2), 3, true), Some(0));
+        assert_eq!(next(Some(7), 3, false), Some(2));
+        assert_eq!(next(Some(0), 0, true), None);
+
+        assert_eq!(previous(None, 3, false), Some(2));
+        assert_eq!(previous(Some(1), 3, false), Some(0));
+        assert_eq!(previous(Some(0), 3, false), Some(0));
+        assert_eq!(previous(Some(0), 3, true), Some(2));
+        assert_eq!(previous(Some(7), 3, false), Some(2));
+        assert_eq!(previous(None, 0, true), None);
+    }
+
+    #[test]
+    fn pages_are_clamped_to_the_items() {
+        assert_eq!(page_down(None, 10, 4), Some(4));
+        assert_eq!(page_down(Some(8), 10, 4), Some(9));
+        assert_eq!(page_up(Some(5), 10, 4), Some(1));
+        assert_eq!(page_up(Some(2), 10, 4), Some(0));
+        assert_eq!(page_up(Some(20), 10, 4), Some(5));
+        assert_eq!(page_down(Some(2), 0, 4), None);
+        assert_eq!(first(0), None);
+        assert_eq!(last(3), Some(2));
+    }
+
+    #[test]
+    fn bounds_keep_the_padding_around_the_selected_item_if_it_fits() {
+        // Items of length 1 separated by gaps of 1, the last gap being cut
+        let length = |_| (1, 1);
+        assert_eq!(bounds(10, None, 0, 5, 1, length), (0, 3));
+        assert_eq!(bounds(10, Some(5), 0, 5, 1, length), (4, 7));
+        assert_eq!(bounds(10, Some(5), 0, 5, 3, length), (3, 6));
+        assert_eq!(bounds(10, Some(1), 4, 5, 1, length), (0, 3));
+        assert_eq!(bounds(0, Some(1), 4, 5, 1, length), (0, 0));
+    }
+}
@@ -1,14 +1,15 @@
 use crate::{
     buffer::Buffer,
-    layout::{Alignment, Rect},
-    style::Style,
-    text::{StyledGrapheme, Text},
+    layout::{Alignment, Rect, VerticalAlignment},
+    style::{Style, Styled},
+    text::{Span, Spans, StyledGrapheme, Text},
     widgets::{
         reflow::{LineComposer, LineTruncator, WordWrapper},
         Block, Widget,
     },
 };
-use std::iter;
+use std::{cell::Cell, fmt, iter, ops::Range, rc::Rc};
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment) -> u16 {
@@ -50,12 +51,242 @@ pub struct Paragraph<'a> {
     style: Style,
     /// How to wrap the text
     wrap: Option<Wrap>,
+    /// Number of columns the first line of each wrapped line is indented by
+    first_line_indent: u16,
+    /// Number of columns the continuation lines of each wrapped line are indented by
+    hanging_indent: u16,
+    /// How to shorten the lines too long to fit, instead of wrapping them
+    truncate: Option<Truncate<'a>>,
     /// The text to display
     text: Text<'a>,
     /// Scroll
     scroll: (u16, u16),
     /// Alignment of the text
     alignment: Alignment,
+    /// Vertical alignment of the text, when shorter than the area
+    vertical_alignment: VerticalAlignment,
+    /// How to number the lines in the gutter
+    line_numbers: LineNumbers,
+    /// Style of the line number gutter
+    line_number_style: Style,
+    /// Minimum number of digits of the line numbers
+    line_number_width: u16,
+    /// Line that relative line numbers are computed from
+    selected_line: usize,
+    /// Styles applied over the matches of some patterns
+    highlights: Vec<(Matcher<'a>, Style)>,
+}
+
+type Finder<'a> = Rc<dyn Fn(&str) -> Vec<Range<usize>> + 'a>;
+
+/// Finds the byte ranges of a line to highlight.
+#[derive(Clone)]
+enum Matcher<'a> {
+    Literal(&'a str),
+    Custom(Finder<'a>),
+}
+
+impl<'a> fmt::Debug for Matcher<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Matcher::Literal(pattern) => f.debug_tuple("Literal").field(pattern).finish(),
+            Matcher::Custom(_) => f.debug_tuple("Custom").field(&"..").finish(),
+        }
+    }
+}
+
+impl<'a> Matcher<'a> {
+    fn find(&self, line: &str) -> Vec<Range<usize>> {
+        match self {
+            Matcher::Literal("") => Vec::new(),
+            Matcher::Literal(pattern) => line
+                .match_indices(pattern)
+                .map(|(start, matched)| start..start + matched.len())
+                .collect(),
+            Matcher::Custom(matcher) => matcher(line),
+        }
+    }
+}
+
+/// Patches `style` over the given byte ranges of the line, splitting its spans as needed.
+fn highlight_line<'a>(line: &Spans<'a>, ranges: &[Range<usize>], style: Style) -> Spans<'a> {
+    let ranges = merge_ranges(ranges);
+    let mut spans = Vec::with_capacity(line.0.len());
+    let mut offset = 0;
+    for span in &line.0 {
+        let content = span.content.as_ref();
+        let span_range = offset..offset + content.len();
+        offset = span_range.end;
+        let mut cuts: Vec<(usize, bool)> = ranges
+            .iter()
+            .filter(|range| range.start < span_range.end && range.end > span_range.start)
+            .flat_map(|range| {
+                [
+                    (range.start.max(span_range.start) - span_range.start, true),
+                    (range.end.min(span_range.end) - span_range.start, false),
+                ]
+            })
+            .filter(|(cut, _)| content.is_char_boundary(*cut))
+            .collect();
+        if cuts.is_empty() {
+            spans.push(span.clone());
+            continue;
+        }
+        cuts.sort_unstable();
+        cuts.push((content.len(), false));
+        let mut start = 0;
+        let mut highlighted = false;
+        for (cut, starts_match) in cuts {
+            if cut > start {
+                let span_style = if highlighted {
+                    span.style.patch(style)
+                } else {
+                    span.style
+                };
+                spans.push(Span::styled(content[start..cut].to_string(), span_style));
+                start = cut;
+            }
+            highlighted = starts_match;
+        }
+    }
+    Spans::from(spans)
+}
+
+/// Sorts the ranges and merges the overlapping ones, so that nested matches do not end the
+/// highlight of the matches containing them.
+fn merge_ranges(ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut ranges = ranges.to_vec();
+    ranges.sort_unstable_by_key(|range| range.start);
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Describes how the lines of a [`Paragraph`] are numbered.
+///
+/// When enabled, the numbers are right-aligned in a gutter on the left of the widget. Only the
+/// first row of a wrapped line is numbered.
+///
+/// ## Examples
+///
+/// ```
+/// # use tui::widgets::{LineNumbers, Paragraph};
+/// # use tui::style::{Color, Style};
+/// Paragraph::new("First line\nSecond line")
+///     .line_numbers(LineNumbers::Relative)
+///     .line_number_style(Style::DEFAULT.fg(Color::DarkGray))
+///     .selected_line(1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineNumbers {
+    /// No gutter is displayed
+    None,
+    /// Lines are numbered starting from 1
+    Absolute,
+    /// Lines are numbered by their distance to the selected line
+    Relative,
+}
+
+/// Where the ellipsis of a truncated line is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TruncatePosition {
+    /// The start of the line is replaced, keeping its end visible (e.g. `…/src/main.rs`)
+    Start,
+    /// The middle of the line is replaced (e.g. `/home/…/main.rs`)
+    Middle,
+    /// The end of the line is replaced (e.g. `/home/user/…`)
+    End,
+}
+
+/// Describes how to shorten the lines of a [`Paragraph`] which are too long to fit, as an
+/// alternative to [`Wrap`].
+///
+/// ## Examples
+///
+/// ```
+/// # use tui::widgets::{Paragraph, Truncate, TruncatePosition};
+/// Paragraph::new("/home/user/projects/tui-rs/src/widgets/paragraph.rs").truncate(Truncate {
+///     ellipsis: "…",
+///     position: TruncatePosition::Middle,
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Truncate<'a> {
+    /// Symbol replacing the part of the line which does not fit
+    pub ellipsis: &'a str,
+    pub position: TruncatePosition,
+}
+
+impl<'a> Truncate<'a> {
+    /// Shortens `line` to at most `width` cells. Lines which fit are returned as is.
+    fn apply(&self, line: &Spans<'a>, width: usize) -> Spans<'a> {
+        if line.width() <= width {
+            return line.clone();
+        }
+        let graphemes: Vec<StyledGrapheme> = line
+            .0
+            .iter()
+            .flat_map(|span| span.styled_graphemes(Style::DEFAULT))
+            .collect();
+        let ellipsis_width = self.ellipsis.width();
+        if ellipsis_width > width {
+            return Spans::from(Span::raw(take_width(self.ellipsis, width)));
+        }
+        let available = width - ellipsis_width;
+        let (head, tail) = match self.position {
+            TruncatePosition::Start => (0, available),
+            TruncatePosition::Middle => (available - available / 2, available / 2),
+            TruncatePosition::End => (available, 0),
+        };
+        let mut spans: Vec<Span<'a>> = Vec::new();
+        let mut push = |grapheme: &StyledGrapheme| match spans.last_mut() {
+            Some(span) if span.style == grapheme.style => {
+                span.content.to_mut().push_str(grapheme.symbol)
+            }
+            _ => spans.push(Span::styled(grapheme.symbol.to_string(), grapheme.style)),
+        };
+        let mut head_width = 0;
+        for grapheme in &graphemes {
+            head_width += grapheme.symbol.width();
+            if head_width > head {
+                break;
+            }
+            push(grapheme);
+        }
+        push(&StyledGrapheme {
+            symbol: self.ellipsis,
+            style: Style::DEFAULT,
+        });
+        let mut tail_width = 0;
+        let tail_start = graphemes
+            .iter()
+            .rev()
+            .take_while(|grapheme| {
+                tail_width += grapheme.symbol.width();
+                tail_width <= tail
+            })
+            .count();
+        for grapheme in &graphemes[graphemes.len() - tail_start..] {
+            push(grapheme);
+        }
+        Spans::from(spans)
+    }
+}
+
+/// Returns the longest prefix of `s` that is at most `width` cells wide.
+fn take_width(s: &str, width: usize) -> String {
+    let mut taken = 0;
+    s.graphemes(true)
+        .take_while(|grapheme| {
+            taken += grapheme.width();
+            taken <= width
+        })
+        .collect()
 }
 
 /// Describes how to wrap text across lines.
@@ -84,8 +315,15 @@ pub struct Paragraph<'a> {
 /// // and is long so that it wraps
 /// //     - Here is another point
 /// // that is long enough to wrap
+///
+/// // With a hanging indent, wrapped lines stay aligned under the bullets:
+/// Paragraph::new(Text::from("- First thing goes here and is long so that it wraps"))
+///     .wrap(Wrap { trim: true })
+///     .hanging_indent(2);
+/// // - First thing goes here and is
+/// //   long so that it wraps
 /// ```
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Wrap {
     /// Should leading whitespace be trimmed
     pub trim: bool,
@@ -100,9 +338,18 @@ impl<'a> Paragraph<'a> {
             block: None,
             style: Style::DEFAULT,
             wrap: None,
+            first_line_indent: 0,
+            hanging_indent: 0,
+            truncate: None,
             text: text.into(),
             scroll: (0, 0),
             alignment: Alignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+            line_numbers: LineNumbers::None,
+            line_number_style: Style::DEFAULT,
+            line_number_width: 0,
+            highlights: Vec::new(),
+            selected_line: 0,
         }
     }
 
@@ -118,6 +365,30 @@ impl<'a> Paragraph<'a> {
 
     pub fn wrap(mut self, wrap: Wrap) -> Paragraph<'a> {
         self.wrap = Some(wrap);
+        self.truncate = None;
+        self
+    }
+
+    /// Set the number of columns the lines of the text are indented by when wrapped, e.g. to
+    /// start paragraphs with an indent. Lines are not indented if the indent leaves no room for
+    /// their content.
+    pub fn first_line_indent(mut self, indent: u16) -> Paragraph<'a> {
+        self.first_line_indent = indent;
+        self
+    }
+
+    /// Set the number of columns the continuation lines of a wrapped line are indented by, e.g.
+    /// to keep them aligned under a bullet. See [`Paragraph::first_line_indent`].
+    pub fn hanging_indent(mut self, indent: u16) -> Paragraph<'a> {
+        self.hanging_indent = indent;
+        self
+    }
+
+    /// Shorten the lines too long to fit by replacing part of them with an ellipsis. This
+    /// replaces wrapping.
+    pub fn truncate(mut self, truncate: Truncate<'a>) -> Paragraph<'a> {
+        self.truncate = Some(truncate);
+        self.wrap = None;
         self
     }
 
@@ -130,6 +401,169 @@ impl<'a> Paragraph<'a> {
         self.alignment = alignment;
         self
     }
+
+    /// Set where the text is placed when it is shorter than the area, e.g. to center a message in
+    /// its block.
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Paragraph<'a> {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    pub fn line_numbers(mut self, line_numbers: LineNumbers) -> Paragraph<'a> {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    pub fn line_number_style(mut self, style: Style) -> Paragraph<'a> {
+        self.line_number_style = style;
+        self
+    }
+
+    /// Sets the minimum number of digits the gutter has room for, so that its width does not
+    /// change as lines are added. The gutter still grows for higher line numbers.
+    pub fn line_number_width(mut self, width: u16) -> Paragraph<'a> {
+        self.line_number_width = width;
+        self
+    }
+
+    /// Patches `style` over all the occurrences of `pattern` in the text, e.g. to show the
+    /// results of a search. Occurrences spanning several spans are highlighted too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::Paragraph;
+    /// # use tui::style::{Color, Style};
+    /// Paragraph::new("To be, or not to be")
+    ///     .highlight("be", Style::DEFAULT.bg(Color::Yellow))
+    ///     .highlight_matches(
+    ///         |line| line.find(',').map(|i| i..i + 1).into_iter().collect(),
+    ///         Style::DEFAULT.fg(Color::Red),
+    ///     );
+    /// ```
+    pub fn highlight(mut self, pattern: &'a str, style: Style) -> Paragraph<'a> {
+        self.highlights.push((Matcher::Literal(pattern), style));
+        self
+    }
+
+    /// Patches `style` over the byte ranges returned by `matcher` for each line of the text, for
+    /// searches that are not a literal string (e.g. case insensitive or regular expressions).
+    pub fn highlight_matches<F>(mut self, matcher: F, style: Style) -> Paragraph<'a>
+    where
+        F: Fn(&str) -> Vec<Range<usize>> + 'a,
+    {
+        self.highlights
+            .push((Matcher::Custom(Rc::new(matcher)), style));
+        self
+    }
+
+    /// Sets the line (zero-based) from which [`LineNumbers::Relative`] distances are computed.
+    pub fn selected_line(mut self, line: usize) -> Paragraph<'a> {
+        self.selected_line = line;
+        self
+    }
+
+    /// Returns the height needed to render the whole text in an area `width` cells wide, block
+    /// and wrapping included. The maximum vertical scroll in an area is this height minus the
+    /// height of the area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::{Block, Borders, Paragraph, Wrap};
+    /// let paragraph = Paragraph::new("Hello, world!\nGoodbye")
+    ///     .block(Block::default().borders(Borders::ALL))
+    ///     .wrap(Wrap { trim: true });
+    /// assert_eq!(paragraph.line_count(10), 5);
+    /// assert_eq!(paragraph.line_width(), 15);
+    /// ```
+    pub fn line_count(&self, width: u16) -> usize {
+        let (block_width, block_height) = self.block_size();
+        let text_width = width
+            .saturating_sub(block_width)
+            .saturating_sub(self.gutter_width());
+        self.text_height(text_width) + usize::from(block_height)
+    }
+
+    /// Number of lines of the text once wrapped to `text_width`, if wrapping is enabled.
+    fn text_height(&self, text_width: u16) -> usize {
+        match self.wrap {
+            Some(Wrap { trim }) => {
+                let mut styled = self.styled_graphemes();
+                let mut line_composer = WordWrapper::new(&mut styled, text_width, trim);
+                line_composer.set_first_line_indent(self.first_line_indent);
+                line_composer.set_hanging_indent(self.hanging_indent);
+                let mut lines = 0;
+                while line_composer.next_line().is_some() {
+                    lines += 1;
+                }
+                lines
+            }
+            None => self.text.lines.len(),
+        }
+    }
+
+    /// Returns the width needed to render the text without wrapping nor truncating it, block and
+    /// line numbers included.
+    pub fn line_width(&self) -> usize {
+        let (block_width, _) = self.block_size();
+        self.text.width() + usize::from(block_width) + usize::from(self.gutter_width())
+    }
+
+    /// Width and height taken by the borders, titles and padding of the block.
+    fn block_size(&self) -> (u16, u16) {
+        match &self.block {
+            Some(b) => {
+                let area = Rect {
+                    x: 0,
+                    y: 0,
+                    width: u16::MAX,
+                    height: u16::MAX,
+                };
+                let inner_area = b.inner(area);
+                (
+                    area.width - inner_area.width,
+                    area.height - inner_area.height,
+                )
+            }
+            None => (0, 0),
+        }
+    }
+
+    /// The graphemes of the text, each line being terminated by a newline.
+    fn styled_graphemes(&self) -> impl Iterator<Item = StyledGrapheme<'_>> {
+        let style = self.style;
+        self.text.lines.iter().flat_map(move |spans| {
+            spans
+                .0
+                .iter()
+                .flat_map(move |span| span.styled_graphemes(style))
+                // Required given the way composers work but might be refactored out if we change
+                // composers to operate on lines instead of a stream of graphemes.
+                .chain(iter::once(StyledGrapheme {
+                    symbol: "\n",
+                    style,
+                }))
+        })
+    }
+
+    fn gutter_width(&self) -> u16 {
+        match self.line_numbers {
+            LineNumbers::None => 0,
+            LineNumbers::Absolute | LineNumbers::Relative => {
+                // Number of digits of the highest line number plus a separating space
+                let digits = self.text.lines.len().max(1).to_string().len() as u16;
+                digits.max(self.line_number_width) + 1
+            }
+        }
+    }
+}
+
+impl<'a> Styled for Paragraph<'a> {
+    fn patch_style(mut self, style: Style) -> Paragraph<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
 }
 
 impl<'a> Widget for Paragraph<'a> {
@@ -148,22 +582,75 @@ impl<'a> Widget for Paragraph<'a> {
             return;
         }
 
-        let style = self.style;
-        let mut styled = self.text.lines.iter().flat_map(|spans| {
-            spans
-                .0
+        let gutter_width = self.gutter_width().min(text_area.width);
+        for (matcher, style) in &self.highlights {
+            for line in &mut self.text.lines {
+                let content: String = line.0.iter().map(|span| span.content.as_ref()).collect();
+                let ranges = matcher.find(&content);
+                if !ranges.is_empty() {
+                    *line = highlight_line(line, &ranges, *style);
+                }
+            }
+        }
+        if let Some(truncate) = self.truncate {
+            let text_width = usize::from(text_area.width - gutter_width);
+            let lines = self
+                .text
+                .lines
                 .iter()
-                .flat_map(|span| span.styled_graphemes(style))
-                // Required given the way composers work but might be refactored out if we change
-                // composers to operate on lines instead of a stream of graphemes.
-                .chain(iter::once(StyledGrapheme {
-                    symbol: "\n",
-                    style: self.style,
-                }))
-        });
+                .map(|line| truncate.apply(line, text_width))
+                .collect();
+            self.text.lines = lines;
+        }
+        let text_area = match self.vertical_alignment {
+            VerticalAlignment::Top => text_area,
+            alignment => {
+                let height = self
+                    .text_height(text_area.width - gutter_width)
+                    .saturating_sub(usize::from(self.scroll.0))
+                    .min(usize::from(text_area.height)) as u16;
+                let free = text_area.height - height;
+                let top = if alignment == VerticalAlignment::Center {
+                    free / 2
+                } else {
+                    free
+                };
+                Rect {
+                    y: text_area.y + top,
+                    height,
+                    ..text_area
+                }
+            }
+        };
+        if text_area.height < 1 {
+            return;
+        }
+        let gutter_area = Rect {
+            width: gutter_width,
+            ..text_area
+        };
+        buf.set_style(gutter_area, self.line_number_style);
+        let text_area = Rect {
+            x: text_area.x + gutter_width,
+            width: text_area.width - gutter_width,
+            ..text_area
+        };
+
+        // Counts the consumed newlines to find out which source line a composed line belongs to.
+        let newlines = Cell::new(0);
+        let mut styled = self
+            .styled_graphemes()
+            .inspect(|StyledGrapheme { symbol, .. }| {
+                if *symbol == "\n" {
+                    newlines.set(newlines.get() + 1);
+                }
+            });
 
         let mut line_composer: Box<dyn LineComposer> = if let Some(Wrap { trim }) = self.wrap {
-            Box::new(WordWrapper::new(&mut styled, text_area.width, trim))
+            let mut line_composer = WordWrapper::new(&mut styled, text_area.width, trim);
+            line_composer.set_first_line_indent(self.first_line_indent);
+            line_composer.set_hanging_indent(self.hanging_indent);
+            Box::new(line_composer)
         } else {
             let mut line_composer = Box::new(LineTruncator::new(&mut styled, text_area.width));
             if let Alignment::Left = self.alignment {
@@ -172,7 +659,27 @@ impl<'a> Widget for Paragraph<'a> {
             line_composer
         };
         let mut y = 0;
+        let mut line_index = 0;
+        let mut previous_line_index = None;
         while let Some((current_line, current_line_width)) = line_composer.next_line() {
+            let is_first_row = previous_line_index != Some(line_index);
+            previous_line_index = Some(line_index);
+            if y >= self.scroll.0 && is_first_row && gutter_width > 0 {
+                let number = match self.line_numbers {
+                    LineNumbers::Relative => {
+                        (line_index as isize - self.selected_line as isize).unsigned_abs()
+                    }
+                    _ => line_index + 1,
+                };
+                buf.set_stringn(
+                    gutter_area.left(),
+                    gutter_area.top() + y - self.scroll.0,
+                    format!("{:>1$} ", number, gutter_width as usize - 1),
+                    gutter_width as usize,
+                    self.line_number_style,
+                );
+            }
+            line_index = newlines.get();
             if y >= self.scroll.0 {
                 let mut x = get_line_offset(current_line_width, text_area.width, self.alignment);
                 for StyledGrapheme { symbol, style } in current_line {
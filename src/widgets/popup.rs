@@ -0,0 +1,167 @@
+use crate::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Style, Styled},
+    widgets::{Block, Clear, Widget},
+};
+
+/// A widget drawing its content in a rectangle centered in the render area, over what was drawn
+/// before.
+///
+/// The popup area is cleared before the content is drawn. The rest of the render area, the
+/// backdrop, can be restyled (e.g. dimmed), and a shadow can be cast to the bottom right of the
+/// popup. Render the popup last, over the whole frame.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, Paragraph, Popup};
+/// # use tui::layout::Constraint;
+/// # use tui::style::{Color, Modifier, Style};
+/// Popup::new(Paragraph::new("Are you sure?"))
+///     .block(Block::default().title("Quit").borders(Borders::ALL))
+///     .width(Constraint::Length(30))
+///     .height(Constraint::Percentage(20))
+///     .backdrop_style(Style::DEFAULT.add_modifier(Modifier::DIM))
+///     .shadow_style(Style::DEFAULT.bg(Color::Black));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Popup<'a, W> {
+    content: W,
+    block: Option<Block<'a>>,
+    /// Style of the popup area
+    style: Style,
+    width: Constraint,
+    height: Constraint,
+    /// Style patched over the render area outside of the popup
+    backdrop_style: Option<Style>,
+    /// Style patched over the cells under the shadow of the popup
+    shadow_style: Option<Style>,
+}
+
+impl<'a, W> Popup<'a, W>
+where
+    W: Widget,
+{
+    pub fn new(content: W) -> Popup<'a, W> {
+        Popup {
+            content,
+            block: None,
+            style: Style::DEFAULT,
+            width: Constraint::Percentage(50),
+            height: Constraint::Percentage(50),
+            backdrop_style: None,
+            shadow_style: None,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Popup<'a, W> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Popup<'a, W> {
+        self.style = style;
+        self
+    }
+
+    /// Set the width of the popup, applied to the width of the render area.
+    pub fn width<C>(mut self, width: C) -> Popup<'a, W>
+    where
+        C: Into<Constraint>,
+    {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the height of the popup, applied to the height of the render area.
+    pub fn height<C>(mut self, height: C) -> Popup<'a, W>
+    where
+        C: Into<Constraint>,
+    {
+        self.height = height.into();
+        self
+    }
+
+    pub fn backdrop_style(mut self, style: Style) -> Popup<'a, W> {
+        self.backdrop_style = Some(style);
+        self
+    }
+
+    pub fn shadow_style(mut self, style: Style) -> Popup<'a, W> {
+        self.shadow_style = Some(style);
+        self
+    }
+
+    /// Returns the area of the popup when rendered in the given area, e.g. to position the cursor
+    /// in its content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::{Clear, Popup};
+    /// # use tui::layout::{Constraint, Rect};
+    /// let popup = Popup::new(Clear).width(Constraint::Length(10)).height(Constraint::Length(4));
+    /// assert_eq!(popup.area(Rect::new(0, 0, 40, 10)), Rect::new(15, 3, 10, 4));
+    /// ```
+    pub fn area(&self, area: Rect) -> Rect {
+        let width = self.width.apply(area.width).min(area.width);
+        let height = self.height.apply(area.height).min(area.height);
+        Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        }
+    }
+}
+
+impl<'a, W> Styled for Popup<'a, W> {
+    fn patch_style(mut self, style: Style) -> Popup<'a, W> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a, W> Widget for Popup<'a, W>
+where
+    W: Widget,
+{
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let popup_area = self.area(area);
+        if let Some(style) = self.backdrop_style {
+            buf.set_style(area, style);
+        }
+        if let Some(style) = self.shadow_style {
+            let right = Rect {
+                x: popup_area.right(),
+                y: popup_area.y.saturating_add(1),
+                width: 1,
+                height: popup_area.height,
+            };
+            let bottom = Rect {
+                x: popup_area.x.saturating_add(1),
+                y: popup_area.bottom(),
+                width: popup_area.width,
+                height: 1,
+            };
+            for shadow in [right, bottom] {
+                if let Some(shadow) = shadow.intersection(area) {
+                    buf.set_style(shadow, style);
+                }
+            }
+        }
+
+        Clear.render(popup_area, buf);
+        buf.set_style(popup_area, self.style);
+        let content_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(popup_area);
+                b.render(popup_area, buf);
+                inner_area
+            }
+            None => popup_area,
+        };
+        self.content.render(content_area, buf);
+    }
+}
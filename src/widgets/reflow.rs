@@ -1,5 +1,4 @@
-use crate::text::StyledGrapheme;
-use unicode_segmentation::UnicodeSegmentation;
+use crate::{style::Style, text::StyledGrapheme};
 use unicode_width::UnicodeWidthStr;
 
 const NBSP: &str = "\u{00a0}";
@@ -19,6 +18,12 @@ pub struct WordWrapper<'a, 'b> {
     next_line: Vec<StyledGrapheme<'a>>,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// Number of columns the first line of each source line is indented by
+    first_line_indent: u16,
+    /// Number of columns continuation lines are indented by
+    hanging_indent: u16,
+    /// Whether the last line was wrapped, the next one being its continuation
+    wrapped: bool,
 }
 
 impl<'a, 'b> WordWrapper<'a, 'b> {
@@ -33,8 +38,19 @@ impl<'a, 'b> WordWrapper<'a, 'b> {
             current_line: vec![],
             next_line: vec![],
             trim,
+            first_line_indent: 0,
+            hanging_indent: 0,
+            wrapped: false,
         }
     }
+
+    pub fn set_first_line_indent(&mut self, first_line_indent: u16) {
+        self.first_line_indent = first_line_indent;
+    }
+
+    pub fn set_hanging_indent(&mut self, hanging_indent: u16) {
+        self.hanging_indent = hanging_indent;
+    }
 }
 
 impl<'a, 'b> LineComposer<'a> for WordWrapper<'a, 'b> {
@@ -42,27 +58,44 @@ impl<'a, 'b> LineComposer<'a> for WordWrapper<'a, 'b> {
         if self.max_line_width == 0 {
             return None;
         }
-        std::mem::swap(&mut self.current_line, &mut self.next_line);
-        self.next_line.truncate(0);
-
-        let mut current_line_width = self
-            .current_line
-            .iter()
-            .map(|StyledGrapheme { symbol, .. }| symbol.width() as u16)
-            .sum();
+        // The remainder of the previous line is wrapped again, its indent possibly differing
+        let mut remainder = std::mem::take(&mut self.next_line).into_iter();
+        self.current_line.truncate(0);
 
-        let mut symbols_to_last_word_end: usize = 0;
-        let mut width_to_last_word_end: u16 = 0;
+        // Continuation lines start with the hanging indent and the other lines with the first line
+        // indent, unless it leaves no room for text
+        let indent = if self.wrapped {
+            self.hanging_indent
+        } else {
+            self.first_line_indent
+        };
+        let indent = if indent < self.max_line_width {
+            indent
+        } else {
+            0
+        };
+        self.current_line
+            .extend((0..indent).map(|_| StyledGrapheme {
+                symbol: " ",
+                style: Style::DEFAULT,
+            }));
+        let indent_len = usize::from(indent);
+        self.wrapped = false;
+
+        let mut current_line_width = indent;
+
+        let mut symbols_to_last_word_end: usize = indent_len;
+        let mut width_to_last_word_end: u16 = indent;
         let mut prev_whitespace = false;
         let mut symbols_exhausted = true;
-        for StyledGrapheme { symbol, style } in &mut self.symbols {
+        for StyledGrapheme { symbol, style } in (&mut remainder).chain(&mut self.symbols) {
             symbols_exhausted = false;
             let symbol_whitespace = symbol.chars().all(&char::is_whitespace) && symbol != NBSP;
 
             // Ignore characters wider that the total max width.
-            if symbol.width() as u16 > self.max_line_width
+            if symbol.width() as u16 > self.max_line_width - indent
                 // Skip leading whitespace when trim is enabled.
-                || self.trim && symbol_whitespace && symbol != "\n" && current_line_width == 0
+                || self.trim && symbol_whitespace && symbol != "\n" && current_line_width == indent
             {
                 continue;
             }
@@ -87,10 +120,13 @@ impl<'a, 'b> LineComposer<'a> for WordWrapper<'a, 'b> {
 
             if current_line_width > self.max_line_width {
                 // If there was no word break in the text, wrap at the end of the line.
-                let (truncate_at, truncated_width) = if symbols_to_last_word_end != 0 {
+                let (truncate_at, truncated_width) = if symbols_to_last_word_end > indent_len {
                     (symbols_to_last_word_end, width_to_last_word_end)
                 } else {
-                    (self.current_line.len() - 1, self.max_line_width)
+                    (
+                        self.current_line.len() - 1,
+                        current_line_width - symbol.width() as u16,
+                    )
                 };
 
                 // Push the remainder to the next line but strip leading whitespace:
@@ -107,16 +143,28 @@ impl<'a, 'b> LineComposer<'a> for WordWrapper<'a, 'b> {
                 }
                 self.current_line.truncate(truncate_at);
                 current_line_width = truncated_width;
+                self.wrapped = true;
                 break;
             }
 
             prev_whitespace = symbol_whitespace;
         }
+        // What is left of the previous remainder follows the new one
+        let skip_whitespace = self.next_line.is_empty();
+        self.next_line
+            .extend(remainder.skip_while(|StyledGrapheme { symbol, .. }| {
+                skip_whitespace && symbol.chars().all(&char::is_whitespace)
+            }));
 
         // Even if the iterator is exhausted, pass the previous remainder.
-        if symbols_exhausted && self.current_line.is_empty() {
+        if symbols_exhausted && self.current_line.len() == indent_len {
             None
         } else {
+            // Empty lines are not indented
+            if self.current_line.len() == indent_len {
+                self.current_line.clear();
+                current_line_width = 0;
+            }
             Some((&self.current_line[..], current_line_width))
         }
     }
@@ -174,26 +222,39 @@ impl<'a, 'b> LineComposer<'a> for LineTruncator<'a, 'b> {
                 break;
             }
 
-            if current_line_width + symbol.width() as u16 > self.max_line_width {
+            // Skip the columns scrolled out of view. A wide symbol cut by the left edge is
+            // replaced by spaces for its visible columns, so that the following symbols stay
+            // aligned with the other lines.
+            let width = symbol.width();
+            let (symbol, width) = if horizontal_offset == 0 {
+                (symbol, width)
+            } else if width <= horizontal_offset {
+                horizontal_offset -= width;
+                continue;
+            } else {
+                let visible = width - horizontal_offset;
+                horizontal_offset = 0;
+                for _ in 0..visible {
+                    self.current_line
+                        .push(StyledGrapheme { symbol: " ", style });
+                }
+                current_line_width += visible as u16;
+                continue;
+            };
+
+            if current_line_width + width as u16 > self.max_line_width {
+                // A wide symbol cut by the right edge is replaced by spaces as well.
+                while current_line_width < self.max_line_width {
+                    self.current_line
+                        .push(StyledGrapheme { symbol: " ", style });
+                    current_line_width += 1;
+                }
                 // Exhaust the remainder of the line.
                 skip_rest = true;
                 break;
             }
 
-            let symbol = if horizontal_offset == 0 {
-                symbol
-            } else {
-                let w = symbol.width();
-                if w > horizontal_offset {
-                    let t = trim_offset(symbol, horizontal_offset);
-                    horizontal_offset = 0;
-                    t
-                } else {
-                    horizontal_offset -= w;
-                    ""
-                }
-            };
-            current_line_width += symbol.width() as u16;
+            current_line_width += width as u16;
             self.current_line.push(StyledGrapheme { symbol, style });
         }
 
@@ -213,22 +274,6 @@ impl<'a, 'b> LineComposer<'a> for LineTruncator<'a, 'b> {
     }
 }
 
-/// This function will return a str slice which start at specified offset.
-/// As src is a unicode str, start offset has to be calculated with each character.
-fn trim_offset(src: &str, mut offset: usize) -> &str {
-    let mut start = 0;
-    for c in UnicodeSegmentation::graphemes(src, true) {
-        let w = c.width();
-        if w <= offset {
-            offset -= w;
-            start += c.len();
-        } else {
-            break;
-        }
-    }
-    &src[start..]
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -236,7 +281,14 @@ mod test {
     use unicode_segmentation::UnicodeSegmentation;
 
     enum Composer {
-        WordWrapper { trim: bool },
+        WordWrapper {
+            trim: bool,
+        },
+        IndentedWordWrapper {
+            trim: bool,
+            first_line_indent: u16,
+            hanging_indent: u16,
+        },
         LineTruncator,
     }
 
@@ -248,6 +300,16 @@ mod test {
             Composer::WordWrapper { trim } => {
                 Box::new(WordWrapper::new(&mut styled, text_area_width, trim))
             }
+            Composer::IndentedWordWrapper {
+                trim,
+                first_line_indent,
+                hanging_indent,
+            } => {
+                let mut composer = WordWrapper::new(&mut styled, text_area_width, trim);
+                composer.set_first_line_indent(first_line_indent);
+                composer.set_hanging_indent(hanging_indent);
+                Box::new(composer)
+            }
             Composer::LineTruncator => Box::new(LineTruncator::new(&mut styled, text_area_width)),
         };
         let mut lines = vec![];
@@ -532,4 +594,81 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn line_composer_word_wrapper_hanging_indent() {
+        let width = 10;
+        let text = "- AAA AAA AAAA AAAAAAAAAA\n- B";
+        let composer = Composer::IndentedWordWrapper {
+            trim: true,
+            first_line_indent: 0,
+            hanging_indent: 2,
+        };
+        let (word_wrapper, widths) = run_composer(composer, text, width);
+        assert_eq!(
+            word_wrapper,
+            vec!["- AAA AAA", "  AAAA", "  AAAAAAAA", "  AA", "- B"]
+        );
+        assert_eq!(widths, vec![9, 6, 10, 4, 3]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_first_line_indent() {
+        let width = 10;
+        let text = "AAA AAA AAAA\n\nBBB";
+        let composer = Composer::IndentedWordWrapper {
+            trim: true,
+            first_line_indent: 4,
+            hanging_indent: 1,
+        };
+        let (word_wrapper, widths) = run_composer(composer, text, width);
+        assert_eq!(word_wrapper, vec!["    AAA", " AAA AAAA", "", "    BBB"]);
+        assert_eq!(widths, vec![7, 9, 0, 7]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_hanging_indent_wider_than_line() {
+        let width = 4;
+        let text = "AAA AAA";
+        let composer = Composer::IndentedWordWrapper {
+            trim: true,
+            first_line_indent: 0,
+            hanging_indent: 4,
+        };
+        let (word_wrapper, _) = run_composer(composer, text, width);
+        assert_eq!(word_wrapper, vec!["AAA", "AAA"]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_hanging_indent_wraps_the_remainder_again() {
+        let width = 10;
+        let text = "x AAAAAAAAA\nB";
+        let composer = Composer::IndentedWordWrapper {
+            trim: true,
+            first_line_indent: 0,
+            hanging_indent: 5,
+        };
+        let (word_wrapper, widths) = run_composer(composer, text, width);
+        assert_eq!(word_wrapper, vec!["x", "     AAAAA", "     AAAA", "B"]);
+        assert_eq!(widths, vec![1, 10, 9, 1]);
+    }
+
+    #[test]
+    fn line_composer_line_truncator_horizontal_offset_double_width_chars() {
+        let style = Style::DEFAULT;
+        let text = "段落现在\nabcdefgh";
+        let mut styled =
+            UnicodeSegmentation::graphemes(text, true).map(|g| StyledGrapheme { symbol: g, style });
+        let mut composer = LineTruncator::new(&mut styled, 4);
+        composer.set_horizontal_offset(1);
+        let mut lines = vec![];
+        while let Some((line, width)) = composer.next_line() {
+            let line: String = line.iter().map(|g| g.symbol).collect();
+            lines.push((line, width));
+        }
+        assert_eq!(
+            lines,
+            vec![(" 落 ".to_string(), 4), ("bcde".to_string(), 4)]
+        );
+    }
 }
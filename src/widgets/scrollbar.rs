@@ -0,0 +1,340 @@
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    symbols::scrollbar,
+    widgets::{ListState, StatefulWidget, TableState},
+};
+
+/// Where a [`Scrollbar`] is drawn within its area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollbarOrientation {
+    /// Along the right edge of the area
+    VerticalRight,
+    /// Along the left edge of the area
+    VerticalLeft,
+    /// Along the bottom edge of the area
+    HorizontalBottom,
+    /// Along the top edge of the area
+    HorizontalTop,
+}
+
+impl ScrollbarOrientation {
+    pub fn is_vertical(self) -> bool {
+        matches!(
+            self,
+            ScrollbarOrientation::VerticalRight | ScrollbarOrientation::VerticalLeft
+        )
+    }
+}
+
+/// The scroll state rendered by a [`Scrollbar`].
+///
+/// `position` is the index of the first visible element of the content (the scroll offset) and
+/// `viewport_length` the number of elements visible at once. A `viewport_length` of 0 is taken
+/// as 1, the position going up to the last element.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{ListState, ScrollbarState};
+/// let mut state = ScrollbarState::new(100).viewport_length(10);
+/// state.next();
+/// assert_eq!(state.get_position(), 1);
+/// state.last();
+/// assert_eq!(state.get_position(), 90);
+///
+/// let list_state = ListState::default();
+/// let state = ScrollbarState::for_list(&list_state, 20);
+/// assert_eq!(state.get_position(), 0);
+/// assert_eq!(state.get_content_length(), 20);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrollbarState {
+    content_length: usize,
+    position: usize,
+    viewport_length: usize,
+}
+
+impl ScrollbarState {
+    pub fn new(content_length: usize) -> ScrollbarState {
+        ScrollbarState {
+            content_length,
+            position: 0,
+            viewport_length: 0,
+        }
+    }
+
+    /// Scroll state of a [`List`](crate::widgets::List) holding `len` items.
+    pub fn for_list(state: &ListState, len: usize) -> ScrollbarState {
+        ScrollbarState::new(len).position(state.offset())
+    }
+
+    /// Scroll state of a [`Table`](crate::widgets::Table) holding `len` rows.
+    pub fn for_table(state: &TableState, len: usize) -> ScrollbarState {
+        ScrollbarState::new(len).position(state.offset())
+    }
+
+    /// Vertical scroll state of a [`Paragraph`](crate::widgets::Paragraph) scrolled by
+    /// `scroll` (the value given to [`Paragraph::scroll`](crate::widgets::Paragraph::scroll))
+    /// and holding `lines` lines.
+    pub fn for_paragraph(scroll: (u16, u16), lines: usize) -> ScrollbarState {
+        ScrollbarState::new(lines).position(usize::from(scroll.0))
+    }
+
+    pub fn position(mut self, position: usize) -> ScrollbarState {
+        self.position = position;
+        self
+    }
+
+    pub fn content_length(mut self, content_length: usize) -> ScrollbarState {
+        self.content_length = content_length;
+        self
+    }
+
+    pub fn viewport_length(mut self, viewport_length: usize) -> ScrollbarState {
+        self.viewport_length = viewport_length;
+        self
+    }
+
+    pub fn get_position(&self) -> usize {
+        self.position
+    }
+
+    pub fn get_content_length(&self) -> usize {
+        self.content_length
+    }
+
+    pub fn get_viewport_length(&self) -> usize {
+        self.viewport_length
+    }
+
+    pub fn set_position(&mut self, position: usize) {
+        self.position = position.min(self.max_position());
+    }
+
+    /// Scroll to the start of the content.
+    pub fn first(&mut self) {
+        self.position = 0;
+    }
+
+    /// Scroll to the end of the content.
+    pub fn last(&mut self) {
+        self.position = self.max_position();
+    }
+
+    /// Scroll one element forward, stopping at the end of the content.
+    pub fn next(&mut self) {
+        self.set_position(self.position.saturating_add(1));
+    }
+
+    /// Scroll one element backward, stopping at the start of the content.
+    pub fn prev(&mut self) {
+        self.position = self.position.saturating_sub(1);
+    }
+
+    fn max_position(&self) -> usize {
+        self.content_length
+            .saturating_sub(self.viewport_length.max(1))
+    }
+}
+
+/// A widget to display a scrollbar next to some scrollable content.
+///
+/// The scrollbar is drawn on a single row or column of the given area, depending on its
+/// [`ScrollbarOrientation`], so it can be rendered over the area of the scrolled widget.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Scrollbar, ScrollbarOrientation};
+/// # use tui::style::{Style, Color};
+/// Scrollbar::new(ScrollbarOrientation::VerticalRight)
+///     .thumb_style(Style::DEFAULT.fg(Color::Yellow))
+///     .begin_symbol(None)
+///     .end_symbol(None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scrollbar {
+    orientation: ScrollbarOrientation,
+    /// Style applied to the whole scrollbar
+    style: Style,
+    thumb_symbol: &'static str,
+    thumb_style: Style,
+    track_symbol: &'static str,
+    track_style: Style,
+    /// Symbol drawn before the track, if any
+    begin_symbol: Option<&'static str>,
+    begin_style: Style,
+    /// Symbol drawn after the track, if any
+    end_symbol: Option<&'static str>,
+    end_style: Style,
+}
+
+impl Default for Scrollbar {
+    fn default() -> Scrollbar {
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+    }
+}
+
+impl Scrollbar {
+    pub fn new(orientation: ScrollbarOrientation) -> Scrollbar {
+        let symbols = if orientation.is_vertical() {
+            scrollbar::VERTICAL
+        } else {
+            scrollbar::HORIZONTAL
+        };
+        Scrollbar {
+            orientation,
+            style: Style::DEFAULT,
+            thumb_symbol: symbols.thumb,
+            thumb_style: Style::DEFAULT,
+            track_symbol: symbols.track,
+            track_style: Style::DEFAULT,
+            begin_symbol: Some(symbols.begin),
+            begin_style: Style::DEFAULT,
+            end_symbol: Some(symbols.end),
+            end_style: Style::DEFAULT,
+        }
+    }
+
+    /// Set the orientation, keeping the current symbols.
+    pub fn orientation(mut self, orientation: ScrollbarOrientation) -> Scrollbar {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set all the symbols at once.
+    pub fn symbols(mut self, symbols: scrollbar::Set) -> Scrollbar {
+        self.thumb_symbol = symbols.thumb;
+        self.track_symbol = symbols.track;
+        self.begin_symbol = Some(symbols.begin);
+        self.end_symbol = Some(symbols.end);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Scrollbar {
+        self.style = style;
+        self
+    }
+
+    pub fn thumb_symbol(mut self, symbol: &'static str) -> Scrollbar {
+        self.thumb_symbol = symbol;
+        self
+    }
+
+    pub fn thumb_style(mut self, style: Style) -> Scrollbar {
+        self.thumb_style = style;
+        self
+    }
+
+    pub fn track_symbol(mut self, symbol: &'static str) -> Scrollbar {
+        self.track_symbol = symbol;
+        self
+    }
+
+    pub fn track_style(mut self, style: Style) -> Scrollbar {
+        self.track_style = style;
+        self
+    }
+
+    pub fn begin_symbol(mut self, symbol: Option<&'static str>) -> Scrollbar {
+        self.begin_symbol = symbol;
+        self
+    }
+
+    pub fn begin_style(mut self, style: Style) -> Scrollbar {
+        self.begin_style = style;
+        self
+    }
+
+    pub fn end_symbol(mut self, symbol: Option<&'static str>) -> Scrollbar {
+        self.end_symbol = symbol;
+        self
+    }
+
+    pub fn end_style(mut self, style: Style) -> Scrollbar {
+        self.end_style = style;
+        self
+    }
+
+    /// Returns the start and length of the thumb within a track of `track_length` cells.
+    fn thumb(&self, state: &ScrollbarState, track_length: u16) -> (u16, u16) {
+        let track = f64::from(track_length);
+        let content = state.content_length;
+        if content == 0 {
+            return (0, track_length);
+        }
+        let viewport = state.viewport_length.max(1);
+        let thumb_length = (track * viewport as f64 / content as f64)
+            .round()
+            .clamp(1.0, track) as u16;
+        let max_position = content.saturating_sub(viewport);
+        let start = if max_position == 0 {
+            0
+        } else {
+            let position = state.position.min(max_position) as f64;
+            (position * f64::from(track_length - thumb_length) / max_position as f64).round() as u16
+        };
+        (start, thumb_length)
+    }
+}
+
+impl Styled for Scrollbar {
+    fn patch_style(mut self, style: Style) -> Scrollbar {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl StatefulWidget for Scrollbar {
+    type State = ScrollbarState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if area.area() == 0 {
+            return;
+        }
+        let vertical = self.orientation.is_vertical();
+        let (x, y, length) = match self.orientation {
+            ScrollbarOrientation::VerticalRight => (area.right() - 1, area.top(), area.height),
+            ScrollbarOrientation::VerticalLeft => (area.left(), area.top(), area.height),
+            ScrollbarOrientation::HorizontalBottom => (area.left(), area.bottom() - 1, area.width),
+            ScrollbarOrientation::HorizontalTop => (area.left(), area.top(), area.width),
+        };
+        let cell_at = |i: u16| if vertical { (x, y + i) } else { (x + i, y) };
+        let mut set = |i: u16, symbol: &str, style: Style| {
+            let (x, y) = cell_at(i);
+            buf.get_mut(x, y)
+                .set_symbol(symbol)
+                .set_style(self.style.patch(style));
+        };
+
+        let mut track_start = 0;
+        let mut track_end = length;
+        if let Some(symbol) = self.begin_symbol {
+            if track_end > track_start {
+                set(track_start, symbol, self.begin_style);
+                track_start += 1;
+            }
+        }
+        if let Some(symbol) = self.end_symbol {
+            if track_end > track_start {
+                track_end -= 1;
+                set(track_end, symbol, self.end_style);
+            }
+        }
+        let track_length = track_end - track_start;
+        if track_length == 0 {
+            return;
+        }
+
+        let (thumb_start, thumb_length) = self.thumb(state, track_length);
+        for i in 0..track_length {
+            if i >= thumb_start && i < thumb_start + thumb_length {
+                set(track_start + i, self.thumb_symbol, self.thumb_style);
+            } else {
+                set(track_start + i, self.track_symbol, self.track_style);
+            }
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    text::Spans,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, StatefulWidget, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Keeps the choice of a [`Select`], and whether its list of options is open.
+///
+/// While the list is open, one of its options is highlighted: it is moved with
+/// [`SelectState::highlight_next`] and [`SelectState::highlight_previous`] and chosen with
+/// [`SelectState::confirm`].
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::SelectState;
+/// let mut state = SelectState::default();
+/// state.open();
+/// state.highlight_next(3, false);
+/// state.highlight_next(3, false);
+/// state.confirm();
+/// assert_eq!(state.selected(), Some(1));
+/// assert!(!state.is_open());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SelectState {
+    selected: Option<usize>,
+    open: bool,
+    /// State of the list of options, whose selection is the highlighted option
+    list: ListState,
+}
+
+impl SelectState {
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Open the list of options, highlighting the selected one.
+    pub fn open(&mut self) {
+        self.open = true;
+        self.list.select(self.selected);
+    }
+
+    /// Close the list of options without changing the selection.
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn toggle(&mut self) {
+        if self.open {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    pub fn highlighted(&self) -> Option<usize> {
+        self.list.selected()
+    }
+
+    /// Highlights the option after the highlighted one, see [`ListState::select_next`].
+    pub fn highlight_next(&mut self, len: usize, wrap: bool) {
+        self.list.select_next(len, wrap);
+    }
+
+    /// Highlights the option before the highlighted one, see [`ListState::select_previous`].
+    pub fn highlight_previous(&mut self, len: usize, wrap: bool) {
+        self.list.select_previous(len, wrap);
+    }
+
+    /// Select the highlighted option, if any, and close the list of options.
+    pub fn confirm(&mut self) {
+        if let Some(index) = self.list.selected() {
+            self.selected = Some(index);
+        }
+        self.close();
+    }
+}
+
+/// A widget displaying the option chosen in a [`SelectState`] on one line and, when the state is
+/// open, the list of options below it.
+///
+/// The list of options is drawn outside of the render area, over what was drawn before, like a
+/// [`Popup`](crate::widgets::Popup): render the select after the widgets it may cover. It is
+/// drawn above the render area if there is more room there.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, Select};
+/// # use tui::style::{Style, Modifier};
+/// Select::new(vec!["Light".into(), "Dark".into(), "System".into()])
+///     .block(Block::default().title("Theme").borders(Borders::ALL))
+///     .placeholder("Choose a theme")
+///     .highlight_style(Style::DEFAULT.add_modifier(Modifier::REVERSED))
+///     .max_height(5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Select<'a> {
+    block: Option<Block<'a>>,
+    options: Vec<Spans<'a>>,
+    /// Displayed when no option is selected
+    placeholder: Option<Spans<'a>>,
+    style: Style,
+    /// Style patched over the style of the select when it is focused
+    focused_style: Style,
+    /// Style of the highlighted option in the list of options
+    highlight_style: Style,
+    /// Maximum number of options visible at once in the list of options
+    max_height: u16,
+    focused: bool,
+}
+
+const CLOSED_SYMBOL: &str = "▼";
+const OPEN_SYMBOL: &str = "▲";
+
+impl<'a> Select<'a> {
+    pub fn new(options: Vec<Spans<'a>>) -> Select<'a> {
+        Select {
+            block: None,
+            options,
+            placeholder: None,
+            style: Style::DEFAULT,
+            focused_style: Style::DEFAULT,
+            highlight_style: Style::DEFAULT,
+            max_height: 8,
+            focused: false,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Select<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn placeholder<T>(mut self, placeholder: T) -> Select<'a>
+    where
+        T: Into<Spans<'a>>,
+    {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Select<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn focused_style(mut self, style: Style) -> Select<'a> {
+        self.focused_style = style;
+        self
+    }
+
+    pub fn highlight_style(mut self, style: Style) -> Select<'a> {
+        self.highlight_style = style;
+        self
+    }
+
+    pub fn max_height(mut self, max_height: u16) -> Select<'a> {
+        self.max_height = max_height;
+        self
+    }
+
+    pub fn focused(mut self, focused: bool) -> Select<'a> {
+        self.focused = focused;
+        self
+    }
+
+    /// Returns the area of the list of options for the given render area, within the area of the
+    /// buffer.
+    fn options_area(&self, area: Rect, buf_area: Rect) -> Option<Rect> {
+        let height = (self.options.len() as u16).min(self.max_height) + 2;
+        let below = buf_area.bottom().saturating_sub(area.bottom());
+        let above = area.top().saturating_sub(buf_area.top());
+        let options_area = if below >= height || below >= above {
+            Rect {
+                y: area.bottom(),
+                height: height.min(below),
+                ..area
+            }
+        } else {
+            Rect {
+                y: area.top() - height.min(above),
+                height: height.min(above),
+                ..area
+            }
+        };
+        options_area.intersection(buf_area)
+    }
+}
+
+impl<'a> Styled for Select<'a> {
+    fn patch_style(mut self, style: Style) -> Select<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> StatefulWidget for Select<'a> {
+    type State = SelectState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut SelectState) {
+        let style = if self.focused {
+            self.style.patch(self.focused_style)
+        } else {
+            self.style
+        };
+        buf.set_style(area, style);
+        let choice_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+
+        if choice_area.width > 0 && choice_area.height > 0 {
+            let symbol = if state.open {
+                OPEN_SYMBOL
+            } else {
+                CLOSED_SYMBOL
+            };
+            let symbol_x = choice_area.right() - symbol.width() as u16;
+            buf.set_string(symbol_x, choice_area.top(), symbol, Style::DEFAULT);
+            let choice = state
+                .selected
+                .and_then(|i| self.options.get(i))
+                .or(self.placeholder.as_ref());
+            if let Some(choice) = choice {
+                let width = symbol_x.saturating_sub(choice_area.left() + 1);
+                buf.set_spans(choice_area.left(), choice_area.top(), choice, width);
+            }
+        }
+
+        if !state.open {
+            return;
+        }
+        let options_area = match self.options_area(area, *buf.area()) {
+            Some(options_area) => options_area,
+            None => return,
+        };
+        Clear.render(options_area, buf);
+        let items: Vec<ListItem> = self.options.into_iter().map(ListItem::new).collect();
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL))
+            .style(self.style)
+            .highlight_style(self.highlight_style);
+        StatefulWidget::render(list, options_area, buf, &mut state.list);
+    }
+}
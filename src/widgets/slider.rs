@@ -0,0 +1,258 @@
+use crate::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Style, Styled},
+    symbols,
+    widgets::{Block, LabelFormat, StatefulWidget, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Keeps the value of a [`Slider`], within a range and changed by steps.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::SliderState;
+/// let mut state = SliderState::new(0.0, 100.0).step(10.0);
+/// state.set_value(95.0);
+/// state.increment();
+/// assert_eq!(state.value(), 100.0);
+/// state.decrement();
+/// assert_eq!(state.value(), 90.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliderState {
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+}
+
+impl SliderState {
+    /// Creates a state with the given range, whose value is the minimum and whose step is 1.
+    pub fn new(min: f64, max: f64) -> SliderState {
+        SliderState {
+            value: min,
+            min,
+            max,
+            step: 1.0,
+        }
+    }
+
+    /// Set the amount by which the value is incremented and decremented.
+    pub fn step(mut self, step: f64) -> SliderState {
+        self.step = step;
+        self
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Set the value, clamped to the range.
+    pub fn set_value(&mut self, value: f64) {
+        self.value = value.max(self.min).min(self.max);
+    }
+
+    pub fn increment(&mut self) {
+        self.set_value(self.value + self.step);
+    }
+
+    pub fn decrement(&mut self) {
+        self.set_value(self.value - self.step);
+    }
+
+    /// Position of the value in the range, from 0 to 1
+    fn ratio(&self) -> f64 {
+        let ratio = (self.value - self.min) / (self.max - self.min);
+        if ratio.is_finite() {
+            ratio.clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// A widget displaying the value of a [`SliderState`] as a thumb on a track, optionally followed
+/// by the formatted value.
+///
+/// The track goes from the minimum on the left to the maximum on the right or, with
+/// [`Direction::Vertical`], from the minimum at the bottom to the maximum at the top, the label
+/// being below the track.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{LabelFormat, Slider};
+/// # use tui::style::{Style, Color};
+/// Slider::default()
+///     .label_format(LabelFormat::custom(|volume| format!("{:.0}%", volume)))
+///     .thumb_style(Style::DEFAULT.fg(Color::Yellow));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Slider<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    /// Style patched over the style of the slider when it is focused
+    focused_style: Style,
+    track_style: Style,
+    thumb_style: Style,
+    track_symbol: Option<&'a str>,
+    thumb_symbol: &'a str,
+    /// Format of the value displayed after the track, if any
+    label_format: Option<LabelFormat<'a>>,
+    direction: Direction,
+    focused: bool,
+}
+
+impl<'a> Default for Slider<'a> {
+    fn default() -> Slider<'a> {
+        Slider {
+            block: None,
+            style: Style::DEFAULT,
+            focused_style: Style::DEFAULT,
+            track_style: Style::DEFAULT,
+            thumb_style: Style::DEFAULT,
+            track_symbol: None,
+            thumb_symbol: "●",
+            label_format: None,
+            direction: Direction::Horizontal,
+            focused: false,
+        }
+    }
+}
+
+impl<'a> Slider<'a> {
+    pub fn block(mut self, block: Block<'a>) -> Slider<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Slider<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn focused_style(mut self, style: Style) -> Slider<'a> {
+        self.focused_style = style;
+        self
+    }
+
+    pub fn track_style(mut self, style: Style) -> Slider<'a> {
+        self.track_style = style;
+        self
+    }
+
+    pub fn thumb_style(mut self, style: Style) -> Slider<'a> {
+        self.thumb_style = style;
+        self
+    }
+
+    /// Set the symbol of the track, a horizontal or vertical line by default depending on the
+    /// direction.
+    pub fn track_symbol(mut self, symbol: &'a str) -> Slider<'a> {
+        self.track_symbol = Some(symbol);
+        self
+    }
+
+    pub fn thumb_symbol(mut self, symbol: &'a str) -> Slider<'a> {
+        self.thumb_symbol = symbol;
+        self
+    }
+
+    pub fn label_format(mut self, format: LabelFormat<'a>) -> Slider<'a> {
+        self.label_format = Some(format);
+        self
+    }
+
+    pub fn direction(mut self, direction: Direction) -> Slider<'a> {
+        self.direction = direction;
+        self
+    }
+
+    pub fn focused(mut self, focused: bool) -> Slider<'a> {
+        self.focused = focused;
+        self
+    }
+}
+
+impl<'a> Styled for Slider<'a> {
+    fn patch_style(mut self, style: Style) -> Slider<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> StatefulWidget for Slider<'a> {
+    type State = SliderState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut SliderState) {
+        let style = if self.focused {
+            self.style.patch(self.focused_style)
+        } else {
+            self.style
+        };
+        buf.set_style(area, style);
+        let slider_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+        if slider_area.width < 1 || slider_area.height < 1 {
+            return;
+        }
+
+        let label = self.label_format.map(|format| format.format(state.value));
+        let horizontal = self.direction == Direction::Horizontal;
+        // Cells left for the track after the label
+        let length = match (&label, horizontal) {
+            (Some(label), true) => slider_area.width.saturating_sub(label.width() as u16 + 1),
+            (Some(_), false) => slider_area.height - 1,
+            (None, true) => slider_area.width,
+            (None, false) => slider_area.height,
+        };
+        let track_symbol = self.track_symbol.unwrap_or(if horizontal {
+            symbols::line::HORIZONTAL
+        } else {
+            symbols::line::VERTICAL
+        });
+        let thumb = (state.ratio() * f64::from(length.saturating_sub(1))).round() as u16;
+        for i in 0..length {
+            let (x, y) = if horizontal {
+                (slider_area.left() + i, slider_area.top())
+            } else {
+                (slider_area.left(), slider_area.top() + length - 1 - i)
+            };
+            let (symbol, style) = if i == thumb {
+                (self.thumb_symbol, self.thumb_style)
+            } else {
+                (track_symbol, self.track_style)
+            };
+            buf.get_mut(x, y).set_symbol(symbol).set_style(style);
+        }
+
+        if let Some(label) = label {
+            let (x, y) = if horizontal {
+                (slider_area.left() + length + 1, slider_area.top())
+            } else {
+                (slider_area.left(), slider_area.bottom() - 1)
+            };
+            // Skipped when there is no cell left for it after the track
+            if x < slider_area.right() {
+                let width = slider_area.right() - x;
+                buf.set_stringn(x, y, label, usize::from(width), Style::DEFAULT);
+            }
+        }
+    }
+}
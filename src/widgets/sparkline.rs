@@ -1,11 +1,21 @@
 use crate::{
     buffer::Buffer,
     layout::Rect,
-    style::Style,
+    style::{Modifier, Style},
     symbols,
     widgets::{Block, Widget},
 };
-use std::cmp::min;
+use std::{borrow::Cow, cmp::min};
+
+/// The direction in which the values of a [`Sparkline`] are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderDirection {
+    /// The first value is on the left edge
+    LeftToRight,
+    /// The first value is on the right edge, so that the newest value stays pinned to the right
+    /// when values are pushed at the front of the data
+    RightToLeft,
+}
 
 /// Widget to render a sparkline over one or more lines.
 ///
@@ -27,12 +37,25 @@ pub struct Sparkline<'a> {
     /// Widget style
     style: Style,
     /// A slice of the data to display
-    data: &'a [u64],
+    data: Cow<'a, [u64]>,
+    /// Styles of the individual data points, patched over the style of the widget
+    data_styles: Vec<Option<Style>>,
+    /// Display the maximum and minimum values over the sparkline
+    min_max_labels: bool,
     /// The maximum value to take to compute the maximum bar height (if nothing is specified, the
     /// widget uses the max of the dataset)
     max: Option<u64>,
     /// A set of bar symbols used to represent the give data
     bar_set: symbols::bar::Set,
+    /// Value from which the bars are drawn, upward for higher values and downward for lower ones
+    baseline: Option<u64>,
+    /// Row separating the upward bars (above) from the downward ones (at and below)
+    axis: Option<u16>,
+    /// Style of the bars below the baseline
+    below_style: Option<Style>,
+    direction: RenderDirection,
+    /// Draw the values with braille dots instead of bars
+    braille: bool,
 }
 
 impl<'a> Default for Sparkline<'a> {
@@ -40,9 +63,16 @@ impl<'a> Default for Sparkline<'a> {
         Sparkline {
             block: None,
             style: Style::DEFAULT,
-            data: &[],
+            data: Cow::Borrowed(&[]),
+            data_styles: Vec::new(),
+            min_max_labels: false,
             max: None,
             bar_set: symbols::bar::NINE_LEVELS,
+            baseline: None,
+            axis: None,
+            below_style: None,
+            direction: RenderDirection::LeftToRight,
+            braille: false,
         }
     }
 }
@@ -59,7 +89,34 @@ impl<'a> Sparkline<'a> {
     }
 
     pub fn data(mut self, data: &'a [u64]) -> Sparkline<'a> {
-        self.data = data;
+        self.data = Cow::Borrowed(data);
+        self.data_styles.clear();
+        self
+    }
+
+    /// Set the data along with the style of each data point, if any, e.g. to highlight
+    /// anomalies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::Sparkline;
+    /// # use tui::style::{Style, Color};
+    /// let alert = Some(Style::DEFAULT.fg(Color::Red));
+    /// Sparkline::default()
+    ///     .data_styled(&[(1, None), (2, None), (9, alert), (3, None)])
+    ///     .min_max_labels(true);
+    /// ```
+    pub fn data_styled(mut self, data: &[(u64, Option<Style>)]) -> Sparkline<'a> {
+        self.data = Cow::Owned(data.iter().map(|(value, _)| *value).collect());
+        self.data_styles = data.iter().map(|(_, style)| *style).collect();
+        self
+    }
+
+    /// Display the maximum of the displayed values, or the one set with [`Sparkline::max`], in the
+    /// top left corner and the minimum of the displayed values in the bottom left corner.
+    pub fn min_max_labels(mut self, labels: bool) -> Sparkline<'a> {
+        self.min_max_labels = labels;
         self
     }
 
@@ -72,23 +129,184 @@ impl<'a> Sparkline<'a> {
         self.bar_set = bar_set;
         self
     }
-}
 
-impl<'a> Widget for Sparkline<'a> {
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
-        let spark_area = match self.block.take() {
-            Some(b) => {
-                let inner_area = b.inner(area);
-                b.render(area, buf);
-                inner_area
+    /// Draw the bars from a baseline: values above it are drawn upward and values below it
+    /// downward, e.g. for deltas shifted by the baseline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::Sparkline;
+    /// # use tui::style::{Style, Color};
+    /// // Deltas between -50 and 50, shifted by 100
+    /// Sparkline::default()
+    ///     .data(&[100, 120, 150, 90, 50])
+    ///     .baseline(100)
+    ///     .below_style(Style::DEFAULT.fg(Color::Red));
+    /// ```
+    pub fn baseline(mut self, baseline: u64) -> Sparkline<'a> {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Set the row of the area (from its top) where the downward bars start, the upward ones
+    /// ending just above. By default, the rows are split so that both sides share the same
+    /// scale.
+    pub fn axis(mut self, row: u16) -> Sparkline<'a> {
+        self.axis = Some(row);
+        self
+    }
+
+    /// Set the style of the bars below the baseline. Defaults to the style of the widget.
+    pub fn below_style(mut self, style: Style) -> Sparkline<'a> {
+        self.below_style = Some(style);
+        self
+    }
+
+    pub fn direction(mut self, direction: RenderDirection) -> Sparkline<'a> {
+        self.direction = direction;
+        self
+    }
+
+    /// Draw the values with braille dots instead of bars. Each cell then holds two values, with
+    /// four levels per row, which suits long series in narrow areas.
+    pub fn braille(mut self, braille: bool) -> Sparkline<'a> {
+        self.braille = braille;
+        self
+    }
+
+    /// Style of the data point at the given index, patched over `style`.
+    fn point_style(&self, style: Style, index: usize) -> Style {
+        match self.data_styles.get(index) {
+            Some(Some(point_style)) => style.patch(*point_style),
+            _ => style,
+        }
+    }
+
+    /// Column of the area where the value at the given index is drawn.
+    fn column(&self, spark_area: Rect, index: usize) -> u16 {
+        match self.direction {
+            RenderDirection::LeftToRight => spark_area.left() + index as u16,
+            RenderDirection::RightToLeft => spark_area.right() - 1 - index as u16,
+        }
+    }
+
+    fn render_braille(&self, spark_area: Rect, buf: &mut Buffer) {
+        let columns = usize::from(spark_area.width) * 2;
+        let data = &self.data[..min(columns, self.data.len())];
+        let baseline = self.baseline.unwrap_or(0);
+        let rows = spark_area.height.saturating_mul(4);
+        let (max, min_value, axis) = self.scale(data, baseline, rows, 4);
+        let below_style = self.below_style.unwrap_or(self.style);
+
+        let width = usize::from(spark_area.width);
+        let mut cells =
+            vec![(symbols::braille::BLANK, self.style); width * usize::from(spark_area.height)];
+        for (i, &value) in data.iter().enumerate() {
+            let column = match self.direction {
+                RenderDirection::LeftToRight => i,
+                RenderDirection::RightToLeft => columns - 1 - i,
+            };
+            let (dots, style) = if value >= baseline {
+                let length = if max > baseline {
+                    ((value - baseline) * u64::from(axis) / (max - baseline)).min(u64::from(axis))
+                } else {
+                    0
+                };
+                (axis - length as u16..axis, self.point_style(self.style, i))
+            } else {
+                let length = (baseline - value) * u64::from(rows - axis) / (baseline - min_value);
+                (axis..axis + length as u16, self.point_style(below_style, i))
+            };
+            for row in dots {
+                let cell = &mut cells[usize::from(row / 4) * width + column / 2];
+                cell.0 |= symbols::braille::DOTS[usize::from(row % 4)][column % 2];
+                cell.1 = style;
             }
-            None => area,
-        };
+        }
+        for (i, (symbol, style)) in cells.into_iter().enumerate() {
+            let x = spark_area.left() + (i % width) as u16;
+            let y = spark_area.top() + (i / width) as u16;
+            buf.get_mut(x, y)
+                .set_symbol(&String::from_utf16(&[symbol]).unwrap())
+                .set_style(style);
+        }
+    }
 
-        if spark_area.height < 1 {
-            return;
+    fn symbol(&self, eighths: u64) -> &'static str {
+        match eighths {
+            0 => self.bar_set.empty,
+            1 => self.bar_set.one_eighth,
+            2 => self.bar_set.one_quarter,
+            3 => self.bar_set.three_eighths,
+            4 => self.bar_set.half,
+            5 => self.bar_set.five_eighths,
+            6 => self.bar_set.three_quarters,
+            7 => self.bar_set.seven_eighths,
+            _ => self.bar_set.full,
         }
+    }
+
+    /// Returns the maximum and minimum values of the scale and the position of the axis, given
+    /// the `length` of the scale in units that are `unit` times smaller than a row.
+    fn scale(&self, data: &[u64], baseline: u64, length: u16, unit: u16) -> (u64, u64, u16) {
+        let max = self
+            .max
+            .unwrap_or_else(|| data.iter().copied().max().unwrap_or(baseline))
+            .max(baseline);
+        let min_value = data.iter().copied().min().unwrap_or(baseline).min(baseline);
+        let axis =
+            match self.axis {
+                Some(axis) => axis.saturating_mul(unit).min(length),
+                None if max == min_value => length,
+                None => (f64::from(length) * (max - baseline) as f64 / (max - min_value) as f64)
+                    .round() as u16,
+            };
+        (max, min_value, axis)
+    }
 
+    fn render_from_baseline(&self, baseline: u64, spark_area: Rect, buf: &mut Buffer) {
+        let data = &self.data[..min(spark_area.width as usize, self.data.len())];
+        let height = spark_area.height;
+        let (max, min_value, axis) = self.scale(data, baseline, height, 1);
+        let below_style = self.below_style.unwrap_or(self.style);
+
+        for (i, &value) in data.iter().enumerate() {
+            let x = self.column(spark_area, i);
+            let style = self.point_style(self.style, i);
+            let below_style = self.point_style(below_style, i);
+            if value >= baseline {
+                let mut eighths = if max > baseline {
+                    (value - baseline) * u64::from(axis) * 8 / (max - baseline)
+                } else {
+                    0
+                };
+                for j in (0..axis).rev() {
+                    buf.get_mut(x, spark_area.top() + j)
+                        .set_symbol(self.symbol(eighths))
+                        .set_style(style);
+                    eighths = eighths.saturating_sub(8);
+                }
+            } else {
+                let mut eighths =
+                    (baseline - value) * u64::from(height - axis) * 8 / (baseline - min_value);
+                for j in axis..height {
+                    // There are no upper eighths of blocks: the lower part of the cell is drawn
+                    // reversed instead
+                    let cell = buf.get_mut(x, spark_area.top() + j);
+                    if eighths > 0 && eighths < 8 {
+                        cell.set_symbol(self.symbol(8 - eighths))
+                            .set_style(below_style.add_modifier(Modifier::REVERSED));
+                    } else {
+                        cell.set_symbol(self.symbol(eighths)).set_style(below_style);
+                    }
+                    eighths = eighths.saturating_sub(8);
+                }
+            }
+        }
+    }
+
+    fn render_bars(&self, spark_area: Rect, buf: &mut Buffer) {
         let max = match self.max {
             Some(v) => v,
             None => *self.data.iter().max().unwrap_or(&1u64),
@@ -108,20 +326,10 @@ impl<'a> Widget for Sparkline<'a> {
             .collect::<Vec<u64>>();
         for j in (0..spark_area.height).rev() {
             for (i, d) in data.iter_mut().enumerate() {
-                let symbol = match *d {
-                    0 => self.bar_set.empty,
-                    1 => self.bar_set.one_eighth,
-                    2 => self.bar_set.one_quarter,
-                    3 => self.bar_set.three_eighths,
-                    4 => self.bar_set.half,
-                    5 => self.bar_set.five_eighths,
-                    6 => self.bar_set.three_quarters,
-                    7 => self.bar_set.seven_eighths,
-                    _ => self.bar_set.full,
-                };
-                buf.get_mut(spark_area.left() + i as u16, spark_area.top() + j)
+                let symbol = self.symbol(*d);
+                buf.get_mut(self.column(spark_area, i), spark_area.top() + j)
                     .set_symbol(symbol)
-                    .set_style(self.style);
+                    .set_style(self.point_style(self.style, i));
 
                 if *d > 8 {
                     *d -= 8;
@@ -133,9 +341,62 @@ impl<'a> Widget for Sparkline<'a> {
     }
 }
 
+impl<'a> Widget for Sparkline<'a> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let spark_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+
+        if spark_area.height < 1 {
+            return;
+        }
+        if self.braille {
+            self.render_braille(spark_area, buf);
+        } else if let Some(baseline) = self.baseline {
+            self.render_from_baseline(baseline, spark_area, buf);
+        } else {
+            self.render_bars(spark_area, buf);
+        }
+
+        if self.min_max_labels {
+            // Labels of the displayed values, the maximum being the one of the scale if set
+            let columns = if self.braille {
+                usize::from(spark_area.width) * 2
+            } else {
+                usize::from(spark_area.width)
+            };
+            let data = &self.data[..min(columns, self.data.len())];
+            if let Some(max) = data.iter().max().map(|max| self.max.unwrap_or(*max)) {
+                buf.set_stringn(
+                    spark_area.left(),
+                    spark_area.top(),
+                    max.to_string(),
+                    spark_area.width as usize,
+                    self.style,
+                );
+            }
+            if let (Some(min), true) = (data.iter().min(), spark_area.height > 1) {
+                buf.set_stringn(
+                    spark_area.left(),
+                    spark_area.bottom() - 1,
+                    min.to_string(),
+                    spark_area.width as usize,
+                    self.style,
+                );
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::style::Color;
 
     #[test]
     fn it_does_not_panic_if_max_is_zero() {
@@ -152,4 +413,86 @@ mod tests {
         let mut buffer = Buffer::empty(area);
         widget.render(area, &mut buffer);
     }
+
+    #[test]
+    fn it_can_draw_values_from_right_to_left() {
+        let widget = Sparkline::default()
+            .data(&[8, 4, 0])
+            .direction(RenderDirection::RightToLeft);
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(vec!["   ▄█"]));
+    }
+
+    #[test]
+    fn it_can_draw_two_values_per_cell_with_braille() {
+        let widget = Sparkline::default()
+            .data(&[0, 1, 2, 3, 4, 5, 6, 7, 8])
+            .braille(true);
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(vec!["⠀⣀⣤⣶⡇"]));
+    }
+
+    #[test]
+    fn it_draws_values_below_the_baseline_downward() {
+        let widget = Sparkline::default()
+            .data(&[2, 4, 1, 0])
+            .baseline(2)
+            .below_style(Style::DEFAULT.fg(Color::Red));
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        let mut expected = Buffer::with_lines(vec![" █  ", "  ▄█"]);
+        expected.get_mut(2, 1).set_style(
+            Style::DEFAULT
+                .fg(Color::Red)
+                .add_modifier(Modifier::REVERSED),
+        );
+        expected.get_mut(3, 1).set_fg(Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_styles_individual_data_points() {
+        let widget = Sparkline::default()
+            .data_styled(&[
+                (1, None),
+                (8, Some(Style::DEFAULT.fg(Color::Red))),
+                (4, None),
+            ])
+            .style(Style::DEFAULT.fg(Color::Green));
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        let mut expected = Buffer::with_lines(vec!["▁█▄"]);
+        expected.set_style(area, Style::DEFAULT.fg(Color::Green));
+        expected.get_mut(1, 0).set_fg(Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_draws_min_max_labels() {
+        let widget = Sparkline::default()
+            .data(&[3, 8, 0, 4])
+            .min_max_labels(true);
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(vec!["8█  ", "0█ █"]));
+    }
+
+    #[test]
+    fn it_draws_min_max_labels_of_the_displayed_values() {
+        let widget = Sparkline::default()
+            .data(&[3, 8, 1, 4, 0, 9])
+            .max(10)
+            .min_max_labels(true);
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(vec!["10  ", "1█▁▆"]));
+    }
 }
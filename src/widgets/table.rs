@@ -1,10 +1,15 @@
 use crate::{
     buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
-    style::Style,
-    text::Text,
-    widgets::{Block, StatefulWidget, Widget},
+    style::{Style, Styled},
+    text::{StyledGrapheme, Text},
+    widgets::{
+        navigation,
+        reflow::{LineComposer, WordWrapper},
+        Block, StatefulWidget, Widget, Wrap,
+    },
 };
+use std::iter;
 use unicode_width::UnicodeWidthStr;
 
 /// A [`Cell`] contains the [`Text`] to be displayed in a [`Row`] of a [`Table`].
@@ -31,10 +36,18 @@ use unicode_width::UnicodeWidthStr;
 ///
 /// You can apply a [`Style`] on the entire [`Cell`] using [`Cell::style`] or rely on the styling
 /// capabilities of [`Text`].
+///
+/// A cell can also be merged with the next ones of its row using [`Cell::col_span`]:
+/// ```rust
+/// # use tui::widgets::{Cell, Row};
+/// Row::new(vec![Cell::from("Name"), Cell::from("Address").col_span(2)]);
+/// Row::new(vec!["", "Street", "City"]);
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Cell<'a> {
     content: Text<'a>,
     style: Style,
+    col_span: u16,
 }
 
 impl<'a> Cell<'a> {
@@ -43,6 +56,13 @@ impl<'a> Cell<'a> {
         self.style = style;
         self
     }
+
+    /// Set the number of columns this cell spans. Its width is then the combined width of these
+    /// columns and of the spacing between them. The next cell of the row starts after them.
+    pub fn col_span(mut self, columns: u16) -> Self {
+        self.col_span = columns.max(1);
+        self
+    }
 }
 
 impl<'a, T> From<T> for Cell<'a>
@@ -53,10 +73,18 @@ where
         Cell {
             content: content.into(),
             style: Style::DEFAULT,
+            col_span: 1,
         }
     }
 }
 
+impl<'a> Styled for Cell<'a> {
+    fn patch_style(mut self, style: Style) -> Cell<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
 /// Holds data to be displayed in a [`Table`] widget.
 ///
 /// A [`Row`] is a collection of cells. It can be created from simple strings:
@@ -85,7 +113,8 @@ where
 /// ]);
 /// ```
 ///
-/// By default, a row has a height of 1 but you can change this using [`Row::height`].
+/// By default, a row has a height of 1 but you can change this using [`Row::height`], or let it
+/// grow to fit its content with [`Table::wrap`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Row<'a> {
     cells: Vec<Cell<'a>>,
@@ -133,6 +162,52 @@ impl<'a> Row<'a> {
     fn total_height(&self) -> u16 {
         self.height.saturating_add(self.bottom_margin)
     }
+
+    /// Returns the cells along with the index of the first column they span.
+    fn cells_by_column(&self) -> impl Iterator<Item = (usize, &Cell<'a>)> {
+        self.cells.iter().scan(0, |column, cell| {
+            let start = *column;
+            *column += usize::from(cell.col_span);
+            Some((start, cell))
+        })
+    }
+
+    /// Returns the cells which are at least partly visible, along with the index of the first
+    /// column they span and their area. `columns` holds the index, position and width of the
+    /// visible columns.
+    fn cell_areas(
+        &self,
+        columns: &[(usize, u16, u16)],
+        y: u16,
+        height: u16,
+    ) -> Vec<(usize, &Cell<'a>, Rect)> {
+        self.cells_by_column()
+            .filter_map(|(start, cell)| {
+                let end = start + usize::from(cell.col_span);
+                let mut spanned = columns
+                    .iter()
+                    .filter(|(index, _, _)| (start..end).contains(index));
+                let &(_, x, width) = spanned.next()?;
+                let right = spanned
+                    .next_back()
+                    .map_or(x + width, |&(_, x, width)| x + width);
+                let area = Rect {
+                    x,
+                    y,
+                    width: right - x,
+                    height,
+                };
+                Some((start, cell, area))
+            })
+            .collect()
+    }
+}
+
+impl<'a> Styled for Row<'a> {
+    fn patch_style(mut self, style: Style) -> Row<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
 }
 
 /// A widget to display data in formatted columns.
@@ -177,8 +252,9 @@ impl<'a> Row<'a> {
 /// )
 /// // As any other widget, a Table can be wrapped in a Block.
 /// .block(Block::default().title("Table"))
-/// // Columns widths are constrained in the same way as Layout...
-/// .widths(&[Constraint::Length(5), Constraint::Length(5), Constraint::Length(10)])
+/// // Columns widths are constrained in the same way as Layout, `Constraint::Fit` sizing a column
+/// // to its widest cell, plus its share of the space left...
+/// .widths(&[Constraint::Length(5), Constraint::Fit, Constraint::Length(10)])
 /// // ...and they can be separated by a fixed spacing.
 /// .column_spacing(1)
 /// // If you wish to highlight a row in any specific way when it is selected...
@@ -202,6 +278,22 @@ pub struct Table<'a> {
     highlight_symbol: Option<&'a str>,
     /// Optional header
     header: Option<Row<'a>>,
+    /// Optional footer
+    footer: Option<Row<'a>>,
+    /// Columns that can be sorted, all of them if `None`
+    sortable_columns: Option<&'a [usize]>,
+    /// Symbols displayed in the header of the sorted column, ascending and descending
+    sort_symbols: (&'a str, &'a str),
+    /// Number of leading columns which stay in place when scrolling horizontally
+    frozen_columns: usize,
+    /// Number of rows kept visible around the selected one when scrolling
+    scroll_padding: usize,
+    /// Wrap the content of the cells, rows growing to fit it
+    wrap: Option<Wrap>,
+    /// Symbol and style of the horizontal rule drawn between rows
+    row_separator: Option<(&'a str, Style)>,
+    /// Styles alternately applied to the rows
+    zebra: Option<(Style, Style)>,
     /// Data to display in each row
     rows: Vec<Row<'a>>,
 }
@@ -219,6 +311,14 @@ impl<'a> Table<'a> {
             highlight_style: Style::DEFAULT,
             highlight_symbol: None,
             header: None,
+            footer: None,
+            sortable_columns: None,
+            sort_symbols: ("▲", "▼"),
+            frozen_columns: 0,
+            scroll_padding: 0,
+            wrap: None,
+            row_separator: None,
+            zebra: None,
             rows: rows.into_iter().collect(),
         }
     }
@@ -233,6 +333,81 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set a row always visible at the bottom of the table, for totals or summaries. Its bottom
+    /// margin is ignored.
+    pub fn footer(mut self, footer: Row<'a>) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
+    /// Restrict the columns showing a sort indicator when sorted, see [`TableState::sort`].
+    pub fn sortable_columns(mut self, columns: &'a [usize]) -> Self {
+        self.sortable_columns = Some(columns);
+        self
+    }
+
+    /// Set the symbols displayed at the end of the header of the sorted column.
+    pub fn sort_symbols(mut self, ascending: &'a str, descending: &'a str) -> Self {
+        self.sort_symbols = (ascending, descending);
+        self
+    }
+
+    /// Keep the first `columns` columns in place when scrolling horizontally, see
+    /// [`TableState::set_col_offset`].
+    pub fn frozen_columns(mut self, columns: usize) -> Self {
+        self.frozen_columns = columns;
+        self
+    }
+
+    /// Keep up to `rows` rows visible before and after the selected row when scrolling, as long
+    /// as the table is high enough.
+    pub fn scroll_padding(mut self, rows: usize) -> Self {
+        self.scroll_padding = rows;
+        self
+    }
+
+    /// Wrap the content of the cells on word boundaries, like [`Paragraph`](crate::widgets::Paragraph)
+    /// does. Each row, header included, then grows to the height of its tallest cell, its
+    /// [`Row::height`] acting as a minimum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::{Row, Table, Wrap};
+    /// # use tui::layout::Constraint;
+    /// Table::new(vec![Row::new(vec!["A long text wrapped on several lines"])])
+    ///     .widths(&[Constraint::Length(10)])
+    ///     .wrap(Wrap { trim: true });
+    /// ```
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+
+    /// Draw a horizontal rule made of `symbol` between each row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::{Row, Table};
+    /// # use tui::style::{Color, Style};
+    /// # use tui::symbols;
+    /// Table::new(vec![Row::new(vec!["Row1"]), Row::new(vec!["Row2"])])
+    ///     .row_separator(symbols::line::HORIZONTAL, Style::DEFAULT.fg(Color::DarkGray))
+    ///     .zebra(Style::DEFAULT, Style::DEFAULT.bg(Color::Black));
+    /// ```
+    pub fn row_separator(mut self, symbol: &'a str, style: Style) -> Self {
+        self.row_separator = Some((symbol, style));
+        self
+    }
+
+    /// Alternate the style of the rows, starting with `even` for the first row. The style of each
+    /// [`Row`] is applied on top of it.
+    pub fn zebra(mut self, even: Style, odd: Style) -> Self {
+        self.zebra = Some((even, odd));
+        self
+    }
+
     pub fn widths(mut self, widths: &'a [Constraint]) -> Self {
         let between_0_and_100 = |&w| match w {
             Constraint::Percentage(p) => p <= 100,
@@ -266,18 +441,34 @@ impl<'a> Table<'a> {
         self
     }
 
-    fn get_columns_widths(&self, max_width: u16, has_selection: bool) -> Vec<u16> {
-        let mut constraints = Vec::with_capacity(self.widths.len() * 2 + 1);
+    /// Indices of the columns displayed: the frozen ones, then the others from `col_offset`.
+    fn visible_columns(&self, col_offset: usize) -> Vec<usize> {
+        let frozen = self.frozen_columns.min(self.widths.len());
+        (0..frozen)
+            .chain(frozen + col_offset..self.widths.len())
+            .collect()
+    }
+
+    fn get_columns_widths(
+        &self,
+        max_width: u16,
+        has_selection: bool,
+        columns: &[usize],
+    ) -> Vec<u16> {
+        let mut constraints = Vec::with_capacity(columns.len() * 2 + 1);
         if has_selection {
             let highlight_symbol_width =
                 self.highlight_symbol.map(|s| s.width() as u16).unwrap_or(0);
             constraints.push(Constraint::Length(highlight_symbol_width));
         }
-        for constraint in self.widths {
-            constraints.push(*constraint);
+        for &column in columns {
+            constraints.push(match self.widths[column] {
+                Constraint::Fit => Constraint::Length(self.content_width(column)),
+                constraint => constraint,
+            });
             constraints.push(Constraint::Length(self.column_spacing));
         }
-        if !self.widths.is_empty() {
+        if !columns.is_empty() {
             constraints.pop();
         }
         let mut chunks = Layout::default()
@@ -290,48 +481,70 @@ impl<'a> Table<'a> {
                 width: max_width,
                 height: 1,
             });
+        let used = chunks.last().map_or(0, |c| c.right());
         if has_selection {
             chunks.remove(0);
         }
-        chunks.iter().step_by(2).map(|c| c.width).collect()
-    }
-
-    fn get_row_bounds(
-        &self,
-        selected: Option<usize>,
-        offset: usize,
-        max_height: u16,
-    ) -> (usize, usize) {
-        let offset = offset.min(self.rows.len().saturating_sub(1));
-        let mut start = offset;
-        let mut end = offset;
-        let mut height = 0;
-        for item in self.rows.iter().skip(offset) {
-            if height + item.height > max_height {
-                break;
+        let mut widths: Vec<u16> = chunks.iter().step_by(2).map(|c| c.width).collect();
+        // The space left is shared between the `Fit` columns, the first ones getting the remainder
+        let fits: Vec<usize> = (0..widths.len())
+            .filter(|&i| self.widths[columns[i]] == Constraint::Fit)
+            .collect();
+        if !fits.is_empty() {
+            let left = max_width.saturating_sub(used);
+            let (share, remainder) = (left / fits.len() as u16, left % fits.len() as u16);
+            for (n, &i) in fits.iter().enumerate() {
+                widths[i] += share + u16::from((n as u16) < remainder);
             }
-            height += item.total_height();
-            end += 1;
         }
+        widths
+    }
 
-        let selected = selected.unwrap_or(0).min(self.rows.len() - 1);
-        while selected >= end {
-            height = height.saturating_add(self.rows[end].total_height());
-            end += 1;
-            while height > max_height {
-                height = height.saturating_sub(self.rows[start].total_height());
-                start += 1;
-            }
+    /// Width of the widest cell of the given column, header and footer included. Cells spanning
+    /// several columns are not taken into account.
+    fn content_width(&self, column: usize) -> u16 {
+        self.header
+            .iter()
+            .chain(self.footer.iter())
+            .chain(self.rows.iter())
+            .flat_map(|row| row.cells_by_column())
+            .filter(|(start, cell)| *start == column && cell.col_span == 1)
+            .map(|(_, cell)| cell.content.width())
+            .max()
+            .unwrap_or(0) as u16
+    }
+}
+
+/// Order in which the rows of a [`Table`] are sorted by the values of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn reverse(self) -> SortDirection {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
         }
-        while selected < start {
-            start -= 1;
-            height = height.saturating_add(self.rows[start].total_height());
-            while height > max_height {
-                end -= 1;
-                height = height.saturating_sub(self.rows[end].total_height());
-            }
+    }
+
+    /// Adapts the ordering of two values to this direction, to be used when sorting rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::SortDirection;
+    /// let mut values = vec![2, 3, 1];
+    /// values.sort_by(|a, b| SortDirection::Descending.apply(a.cmp(b)));
+    /// assert_eq!(values, [3, 2, 1]);
+    /// ```
+    pub fn apply(self, ordering: std::cmp::Ordering) -> std::cmp::Ordering {
+        match self {
+            SortDirection::Ascending => ordering,
+            SortDirection::Descending => ordering.reverse(),
         }
-        (start, end)
     }
 }
 
@@ -339,6 +552,8 @@ impl<'a> Table<'a> {
 pub struct TableState {
     offset: usize,
     selected: Option<usize>,
+    sort: Option<(usize, SortDirection)>,
+    col_offset: usize,
 }
 
 impl TableState {
@@ -346,12 +561,115 @@ impl TableState {
         self.selected
     }
 
+    /// Index of the first item displayed, as of the last render.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
     pub fn select(&mut self, index: Option<usize>) {
         self.selected = index;
         if index.is_none() {
             self.offset = 0;
         }
     }
+
+    /// Index of the first column displayed after the frozen ones.
+    pub fn col_offset(&self) -> usize {
+        self.col_offset
+    }
+
+    /// Scroll horizontally so that the given column is the first displayed after the frozen ones.
+    /// The offset is clamped to the last column during the next render.
+    pub fn set_col_offset(&mut self, col_offset: usize) {
+        self.col_offset = col_offset;
+    }
+
+    pub fn scroll_right(&mut self) {
+        self.col_offset = self.col_offset.saturating_add(1);
+    }
+
+    pub fn scroll_left(&mut self) {
+        self.col_offset = self.col_offset.saturating_sub(1);
+    }
+
+    /// The column the rows should be sorted by and in which direction, if any. The table does not
+    /// reorder its rows, this is up to the application.
+    pub fn sorted_by(&self) -> Option<(usize, SortDirection)> {
+        self.sort
+    }
+
+    /// Sort the rows by the given column. The header of that column then shows a sort indicator.
+    pub fn sort(&mut self, column: usize, direction: SortDirection) {
+        self.sort = Some((column, direction));
+    }
+
+    /// Sort by the given column in ascending order, or reverse the direction if the rows are
+    /// already sorted by this column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tui::widgets::{SortDirection, TableState};
+    /// let mut state = TableState::default();
+    /// state.toggle_sort(1);
+    /// assert_eq!(state.sorted_by(), Some((1, SortDirection::Ascending)));
+    /// state.toggle_sort(1);
+    /// assert_eq!(state.sorted_by(), Some((1, SortDirection::Descending)));
+    /// state.toggle_sort(0);
+    /// assert_eq!(state.sorted_by(), Some((0, SortDirection::Ascending)));
+    /// ```
+    pub fn toggle_sort(&mut self, column: usize) {
+        let direction = match self.sort {
+            Some((sorted, direction)) if sorted == column => direction.reverse(),
+            _ => SortDirection::Ascending,
+        };
+        self.sort = Some((column, direction));
+    }
+
+    pub fn clear_sort(&mut self) {
+        self.sort = None;
+    }
+
+    /// Highlights the row after the highlighted one, or the first row if none is. At the end of
+    /// the `len` rows, goes back to the first one if `wrap` is set and stays on the last one
+    /// otherwise.
+    pub fn select_next(&mut self, len: usize, wrap: bool) {
+        self.select(navigation::next(self.selected, len, wrap));
+    }
+
+    /// Highlights the row before the highlighted one, or the last row if none is. At the start
+    /// of the `len` rows, goes to the last one if `wrap` is set and stays on the first one
+    /// otherwise.
+    pub fn select_previous(&mut self, len: usize, wrap: bool) {
+        self.select(navigation::previous(self.selected, len, wrap));
+    }
+
+    /// Highlights the first of the `len` rows, if any.
+    pub fn select_first(&mut self, len: usize) {
+        self.select(navigation::first(len));
+    }
+
+    /// Highlights the last of the `len` rows, if any.
+    pub fn select_last(&mut self, len: usize) {
+        self.select(navigation::last(len));
+    }
+
+    /// Moves the highlight `page` rows down, stopping at the last of the `len` rows.
+    pub fn scroll_page_down(&mut self, len: usize, page: usize) {
+        self.select(navigation::page_down(self.selected, len, page));
+    }
+
+    /// Moves the highlight `page` rows up, stopping at the first row.
+    pub fn scroll_page_up(&mut self, len: usize, page: usize) {
+        self.select(navigation::page_up(self.selected, len, page));
+    }
+}
+
+impl<'a> Styled for Table<'a> {
+    fn patch_style(mut self, style: Style) -> Table<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
 }
 
 impl<'a> StatefulWidget for Table<'a> {
@@ -372,9 +690,46 @@ impl<'a> StatefulWidget for Table<'a> {
         };
 
         let has_selection = state.selected.is_some();
-        let columns_widths = self.get_columns_widths(table_area.width, has_selection);
+        if self.widths.len() > self.frozen_columns {
+            state.col_offset = state
+                .col_offset
+                .min(self.widths.len() - self.frozen_columns - 1);
+        } else {
+            state.col_offset = 0;
+        }
+        let columns = self.visible_columns(state.col_offset);
+        let columns_widths = self.get_columns_widths(table_area.width, has_selection, &columns);
         let highlight_symbol = self.highlight_symbol.unwrap_or("");
         let blank_symbol = " ".repeat(highlight_symbol.width());
+        let mut x = table_area.left();
+        if has_selection {
+            x += (highlight_symbol.width() as u16).min(table_area.width);
+        }
+        let columns: Vec<(usize, u16, u16)> = columns
+            .iter()
+            .zip(columns_widths.iter())
+            .map(|(&i, &width)| {
+                let column = (i, x, width);
+                x += width + self.column_spacing;
+                column
+            })
+            .collect();
+        if let Some(wrap) = self.wrap {
+            for row in self
+                .header
+                .iter_mut()
+                .chain(self.footer.iter_mut())
+                .chain(self.rows.iter_mut())
+            {
+                let content_height = row
+                    .cell_areas(&columns, 0, 0)
+                    .into_iter()
+                    .map(|(_, cell, area)| wrap_cell(cell, area.width, wrap).len() as u16)
+                    .max()
+                    .unwrap_or(0);
+                row.height = row.height.max(content_height);
+            }
+        }
         let mut current_height = 0;
         let mut rows_height = table_area.height;
 
@@ -390,32 +745,74 @@ impl<'a> StatefulWidget for Table<'a> {
                 },
                 header.style,
             );
-            let mut col = table_area.left();
-            if has_selection {
-                col += (highlight_symbol.width() as u16).min(table_area.width);
-            }
-            for (width, cell) in columns_widths.iter().zip(header.cells.iter()) {
-                render_cell(
-                    buf,
-                    cell,
-                    Rect {
-                        x: col,
-                        y: table_area.top(),
-                        width: *width,
-                        height: max_header_height,
-                    },
-                );
-                col += *width + self.column_spacing;
+            let sorted = state.sort.filter(|(column, _)| {
+                self.sortable_columns
+                    .map_or(true, |columns| columns.contains(column))
+            });
+            for (i, cell, cell_area) in
+                header.cell_areas(&columns, table_area.top(), max_header_height)
+            {
+                render_cell(buf, cell, cell_area, self.wrap);
+                match sorted {
+                    Some((column, direction)) if column == i && cell_area.area() > 0 => {
+                        let symbol = match direction {
+                            SortDirection::Ascending => self.sort_symbols.0,
+                            SortDirection::Descending => self.sort_symbols.1,
+                        };
+                        let symbol_width = (symbol.width() as u16).min(cell_area.width);
+                        buf.set_stringn(
+                            cell_area.right() - symbol_width,
+                            cell_area.top(),
+                            symbol,
+                            usize::from(symbol_width),
+                            Style::DEFAULT,
+                        );
+                    }
+                    _ => {}
+                }
             }
             current_height += max_header_height;
             rows_height = rows_height.saturating_sub(max_header_height);
         }
 
+        // Draw footer
+        if let Some(ref footer) = self.footer {
+            let footer_height = rows_height.min(footer.height);
+            let footer_area = Rect {
+                x: table_area.left(),
+                y: table_area.bottom() - footer_height,
+                width: table_area.width,
+                height: footer_height,
+            };
+            buf.set_style(footer_area, footer.style);
+            for (_, cell, cell_area) in footer.cell_areas(&columns, footer_area.y, footer_height) {
+                render_cell(buf, cell, cell_area, self.wrap);
+            }
+            rows_height -= footer_height;
+        }
+
         // Draw rows
         if self.rows.is_empty() {
             return;
         }
-        let (start, end) = self.get_row_bounds(state.selected, state.offset, rows_height);
+        if self.row_separator.is_some() {
+            // The separator is drawn on the first line of the bottom margin of each row but the last
+            let last = self.rows.len() - 1;
+            for row in self.rows[..last].iter_mut() {
+                row.bottom_margin = row.bottom_margin.saturating_add(1);
+            }
+        }
+        let (start, end) = navigation::bounds(
+            self.rows.len(),
+            state.selected,
+            state.offset,
+            usize::from(rows_height),
+            self.scroll_padding,
+            |i| {
+                let row = &self.rows[i];
+                (usize::from(row.height), usize::from(row.bottom_margin))
+            },
+        );
         state.offset = start;
         for (i, table_row) in self
             .rows
@@ -432,43 +829,87 @@ impl<'a> StatefulWidget for Table<'a> {
                 width: table_area.width,
                 height: table_row.height,
             };
-            buf.set_style(table_row_area, table_row.style);
+            let row_style = match self.zebra {
+                Some((even, odd)) => {
+                    let zebra_style = if i % 2 == 0 { even } else { odd };
+                    zebra_style.patch(table_row.style)
+                }
+                None => table_row.style,
+            };
+            buf.set_style(table_row_area, row_style);
             let is_selected = state.selected.map(|s| s == i).unwrap_or(false);
-            let table_row_start_col = if has_selection {
+            if has_selection {
                 let symbol = if is_selected {
                     highlight_symbol
                 } else {
                     &blank_symbol
                 };
-                let (col, _) =
-                    buf.set_stringn(col, row, symbol, table_area.width as usize, table_row.style);
-                col
-            } else {
-                col
-            };
-            let mut col = table_row_start_col;
-            for (width, cell) in columns_widths.iter().zip(table_row.cells.iter()) {
-                render_cell(
-                    buf,
-                    cell,
-                    Rect {
-                        x: col,
-                        y: row,
-                        width: *width,
-                        height: table_row.height,
-                    },
-                );
-                col += *width + self.column_spacing;
+                buf.set_stringn(col, row, symbol, table_area.width as usize, row_style);
+            }
+            for (_, cell, cell_area) in table_row.cell_areas(&columns, row, table_row.height) {
+                render_cell(buf, cell, cell_area, self.wrap);
             }
             if is_selected {
                 buf.set_style(table_row_area, self.highlight_style);
             }
+            let separator_y = row + table_row.height;
+            match self.row_separator {
+                // Only between two displayed rows
+                Some((symbol, style)) if i + 1 < end => {
+                    let width = symbol.width().max(1);
+                    for x in (table_area.left()..table_area.right()).step_by(width) {
+                        buf.set_stringn(
+                            x,
+                            separator_y,
+                            symbol,
+                            usize::from(table_area.right() - x),
+                            style,
+                        );
+                    }
+                }
+                _ => {}
+            }
         }
     }
 }
 
-fn render_cell(buf: &mut Buffer, cell: &Cell, area: Rect) {
+/// Lines of the content of a cell, wrapped to the given width.
+fn wrap_cell<'a>(cell: &'a Cell, width: u16, wrap: Wrap) -> Vec<Vec<StyledGrapheme<'a>>> {
+    let mut graphemes = cell.content.lines.iter().flat_map(|spans| {
+        spans
+            .0
+            .iter()
+            .flat_map(|span| span.styled_graphemes(Style::DEFAULT))
+            .chain(iter::once(StyledGrapheme {
+                symbol: "\n",
+                style: Style::DEFAULT,
+            }))
+    });
+    let mut line_composer = WordWrapper::new(&mut graphemes, width, wrap.trim);
+    let mut lines = Vec::new();
+    while let Some((line, _)) = line_composer.next_line() {
+        lines.push(line.to_vec());
+    }
+    lines
+}
+
+fn render_cell(buf: &mut Buffer, cell: &Cell, area: Rect, wrap: Option<Wrap>) {
     buf.set_style(area, cell.style);
+    if let Some(wrap) = wrap {
+        for (y, line) in wrap_cell(cell, area.width, wrap).iter().enumerate() {
+            if y as u16 >= area.height {
+                break;
+            }
+            let mut x = area.x;
+            for StyledGrapheme { symbol, style } in line {
+                buf.get_mut(x, area.y + y as u16)
+                    .set_symbol(symbol)
+                    .set_style(*style);
+                x += symbol.width() as u16;
+            }
+        }
+        return;
+    }
     for (i, spans) in cell.content.lines.iter().enumerate() {
         if i as u16 >= area.height {
             break;
@@ -1,14 +1,164 @@
 use crate::{
     buffer::Buffer,
-    layout::Rect,
-    style::Style,
+    layout::{Direction, Rect},
+    style::{Style, Styled},
     symbols,
     text::{Span, Spans},
     widgets::{Block, Widget},
 };
+use unicode_width::UnicodeWidthStr;
+
+/// Keeps track of the selected tab among a fixed number of tabs.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::TabsState;
+/// let mut state = TabsState::new(3);
+/// state.next();
+/// assert_eq!(state.selected(), 1);
+/// state.select(2);
+/// state.next();
+/// assert_eq!(state.selected(), 0);
+/// state.prev();
+/// assert_eq!(state.selected(), 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TabsState {
+    selected: usize,
+    len: usize,
+}
+
+impl TabsState {
+    pub fn new(len: usize) -> TabsState {
+        TabsState { selected: 0, len }
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Select the tab at the given index. The index is clamped to the last tab.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.len.saturating_sub(1));
+    }
+
+    /// Select the next tab, going back to the first one after the last.
+    pub fn next(&mut self) {
+        if self.len > 0 {
+            self.selected = (self.selected + 1) % self.len;
+        }
+    }
+
+    /// Select the previous tab, going to the last one before the first.
+    pub fn prev(&mut self) {
+        if self.len > 0 {
+            self.selected = (self.selected + self.len - 1) % self.len;
+        }
+    }
+}
+
+/// A tab of [`Tabs`], with its title, an optional badge count and its own style.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::Tab;
+/// # use tui::style::{Style, Color};
+/// Tab::new("Logs").badge(3).style(Style::DEFAULT.fg(Color::Red));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab<'a> {
+    title: Spans<'a>,
+    /// Count displayed in parentheses after the title
+    badge: Option<u64>,
+    /// Style of the tab, patched over the style of the tabs and under the highlight style
+    style: Style,
+}
+
+impl<'a> Tab<'a> {
+    pub fn new<T>(title: T) -> Tab<'a>
+    where
+        T: Into<Spans<'a>>,
+    {
+        Tab {
+            title: title.into(),
+            badge: None,
+            style: Style::DEFAULT,
+        }
+    }
+
+    pub fn badge(mut self, badge: u64) -> Tab<'a> {
+        self.badge = Some(badge);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Tab<'a> {
+        self.style = style;
+        self
+    }
+
+    fn badge_text(&self) -> Option<String> {
+        self.badge.map(|badge| format!(" ({})", badge))
+    }
+
+    fn width(&self) -> usize {
+        self.title.width() + self.badge_text().map_or(0, |badge| badge.width())
+    }
+
+    /// Draws the tab from (x, y) in at most `width` columns and returns the column following it.
+    /// The badge is only drawn if it fits whole after the title.
+    fn render(&self, x: u16, y: u16, width: u16, buf: &mut Buffer) -> u16 {
+        let title_width = (self.title.width() as u16).min(width);
+        let badge = self
+            .badge_text()
+            .filter(|badge| badge.width() as u16 <= width - title_width);
+        let badge_width = badge.as_ref().map_or(0, |badge| badge.width() as u16);
+        let area = Rect {
+            x,
+            y,
+            width: title_width + badge_width,
+            height: 1,
+        };
+        buf.set_style(area, self.style);
+        let (end, _) = buf.set_spans(x, y, &self.title, width);
+        match badge {
+            Some(badge) => {
+                buf.set_stringn(end, y, badge, usize::from(badge_width), Style::DEFAULT)
+                    .0
+            }
+            None => end,
+        }
+    }
+}
+
+impl<'a, T> From<T> for Tab<'a>
+where
+    T: Into<Spans<'a>>,
+{
+    fn from(title: T) -> Tab<'a> {
+        Tab::new(title)
+    }
+}
 
 /// A widget to display available tabs in a multiple panels context.
 ///
+/// When the titles do not fit in the available width, the tabs are scrolled so that the selected
+/// one is visible, and `◀` and `▶` indicators show that tabs are hidden on either side.
+///
+/// With [`Direction::Vertical`], the titles are stacked in a column, as in a navigation sidebar,
+/// and the selected one is marked by the highlight symbol.
+///
+/// Tabs with badges or their own styles are given as [`Tab`]s with [`Tabs::with_tabs`].
+///
 /// # Examples
 ///
 /// ```
@@ -23,12 +173,21 @@ use crate::{
 ///     .highlight_style(Style::DEFAULT.fg(Color::Yellow))
 ///     .divider(DOT);
 /// ```
+///
+/// ```
+/// # use tui::widgets::{Tab, Tabs};
+/// # use tui::style::{Style, Color};
+/// Tabs::with_tabs(vec![
+///     Tab::new("Files"),
+///     Tab::new("Logs").badge(3),
+///     Tab::new("Build").style(Style::DEFAULT.fg(Color::Red)),
+/// ]);
+/// ```
 #[derive(Debug, Clone)]
 pub struct Tabs<'a> {
     /// A block to wrap this widget in if necessary
     block: Option<Block<'a>>,
-    /// One title for each tab
-    titles: Vec<Spans<'a>>,
+    tabs: Vec<Tab<'a>>,
     /// The index of the selected tabs
     selected: usize,
     /// The style used to draw the text
@@ -37,17 +196,27 @@ pub struct Tabs<'a> {
     highlight_style: Style,
     /// Tab divider
     divider: Span<'a>,
+    /// Whether the titles are laid out in a row or in a column
+    direction: Direction,
+    /// Symbol in front of the selected title when the titles are in a column
+    highlight_symbol: &'a str,
 }
 
 impl<'a> Tabs<'a> {
     pub fn new(titles: Vec<Spans<'a>>) -> Tabs<'a> {
+        Tabs::with_tabs(titles.into_iter().map(Tab::new).collect())
+    }
+
+    pub fn with_tabs(tabs: Vec<Tab<'a>>) -> Tabs<'a> {
         Tabs {
             block: None,
-            titles,
+            tabs,
             selected: 0,
             style: Style::DEFAULT,
             highlight_style: Style::DEFAULT,
             divider: Span::raw(symbols::line::VERTICAL),
+            direction: Direction::Horizontal,
+            highlight_symbol: symbols::line::THICK_VERTICAL,
         }
     }
 
@@ -61,6 +230,12 @@ impl<'a> Tabs<'a> {
         self
     }
 
+    /// Highlight the tab selected in the given [`TabsState`].
+    pub fn state(mut self, state: &TabsState) -> Tabs<'a> {
+        self.selected = state.selected;
+        self
+    }
+
     pub fn style(mut self, style: Style) -> Tabs<'a> {
         self.style = style;
         self
@@ -78,6 +253,85 @@ impl<'a> Tabs<'a> {
         self.divider = divider.into();
         self
     }
+
+    /// Set the direction in which the titles are laid out. The divider is only drawn between
+    /// titles in a row.
+    pub fn direction(mut self, direction: Direction) -> Tabs<'a> {
+        self.direction = direction;
+        self
+    }
+
+    /// Set the symbol marking the selected title when the titles are in a column.
+    pub fn highlight_symbol(mut self, highlight_symbol: &'a str) -> Tabs<'a> {
+        self.highlight_symbol = highlight_symbol;
+        self
+    }
+}
+
+impl<'a> Tabs<'a> {
+    /// Width taken by the tabs in the given range, with their padding and the dividers between
+    /// them.
+    fn tabs_width(&self, first: usize, last: usize) -> usize {
+        let titles: usize = self.tabs[first..=last]
+            .iter()
+            .map(|tab| tab.width() + 2)
+            .sum();
+        titles + self.divider.width() * (last - first)
+    }
+
+    fn render_column(self, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        let height = usize::from(area.height);
+        let selected = self.selected.min(self.tabs.len() - 1);
+        let offset = (selected + 1).saturating_sub(height);
+        let symbol_width = self.highlight_symbol.width() as u16;
+        let blank_symbol = " ".repeat(usize::from(symbol_width));
+        for (row, (i, tab)) in self
+            .tabs
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(height)
+            .enumerate()
+        {
+            let y = area.top() + row as u16;
+            let symbol = if i == selected {
+                self.highlight_symbol
+            } else {
+                &blank_symbol
+            };
+            let (x, _) =
+                buf.set_stringn(area.left(), y, symbol, usize::from(area.width), self.style);
+            let x = x.saturating_add(1);
+            let remaining_width = area.right().saturating_sub(x);
+            if remaining_width > 0 {
+                tab.render(x, y, remaining_width, buf);
+            }
+            if i == selected {
+                buf.set_style(
+                    Rect {
+                        x: area.left(),
+                        y,
+                        width: area.width,
+                        height: 1,
+                    },
+                    self.highlight_style,
+                );
+            }
+        }
+    }
+}
+
+const LEFT_OVERFLOW: &str = "◀";
+const RIGHT_OVERFLOW: &str = "▶";
+
+impl<'a> Styled for Tabs<'a> {
+    fn patch_style(mut self, style: Style) -> Tabs<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
 }
 
 impl<'a> Widget for Tabs<'a> {
@@ -92,32 +346,65 @@ impl<'a> Widget for Tabs<'a> {
             None => area,
         };
 
-        if tabs_area.height < 1 {
+        if tabs_area.height < 1 || self.tabs.is_empty() {
             return;
         }
+        if self.direction == Direction::Vertical {
+            self.render_column(tabs_area, buf);
+            return;
+        }
+        if tabs_area.area() == 0 {
+            return;
+        }
+
+        let titles_length = self.tabs.len();
+        let selected = self.selected.min(titles_length - 1);
+        let mut offset = 0;
+        let mut titles_area = tabs_area;
+        if self.tabs_width(0, titles_length - 1) > usize::from(tabs_area.width) {
+            // Keep a column on each side for the overflow indicators
+            titles_area.x = titles_area.x.saturating_add(1);
+            titles_area.width = titles_area.width.saturating_sub(2);
+            while offset < selected
+                && self.tabs_width(offset, selected) > usize::from(titles_area.width)
+            {
+                offset += 1;
+            }
+            if offset > 0 {
+                buf.set_string(tabs_area.left(), tabs_area.top(), LEFT_OVERFLOW, self.style);
+            }
+            if self.tabs_width(offset, titles_length - 1) > usize::from(titles_area.width) {
+                buf.set_string(
+                    tabs_area.right().saturating_sub(1),
+                    tabs_area.top(),
+                    RIGHT_OVERFLOW,
+                    self.style,
+                );
+            }
+        }
 
+        let tabs_area = titles_area;
         let mut x = tabs_area.left();
-        let titles_length = self.titles.len();
-        for (i, title) in self.titles.into_iter().enumerate() {
+        for (i, tab) in self.tabs.iter().enumerate().skip(offset) {
             let last_title = titles_length - 1 == i;
             x = x.saturating_add(1);
             let remaining_width = tabs_area.right().saturating_sub(x);
             if remaining_width == 0 {
                 break;
             }
-            let pos = buf.set_spans(x, tabs_area.top(), &title, remaining_width);
+            let end = tab.render(x, tabs_area.top(), remaining_width, buf);
             if i == self.selected {
                 buf.set_style(
                     Rect {
                         x,
                         y: tabs_area.top(),
-                        width: pos.0.saturating_sub(x),
+                        width: end.saturating_sub(x),
                         height: 1,
                     },
                     self.highlight_style,
                 );
             }
-            x = pos.0.saturating_add(1);
+            x = end.saturating_add(1);
             let remaining_width = tabs_area.right().saturating_sub(x);
             if remaining_width == 0 || last_title {
                 break;
@@ -127,3 +414,33 @@ impl<'a> Widget for Tabs<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tabs_state_wraps_around() {
+        let mut state = TabsState::new(3);
+        state.prev();
+        assert_eq!(state.selected(), 2);
+        state.next();
+        assert_eq!(state.selected(), 0);
+        state.next();
+        state.next();
+        assert_eq!(state.selected(), 2);
+    }
+
+    #[test]
+    fn tabs_state_select_is_clamped() {
+        let mut state = TabsState::new(3);
+        state.select(10);
+        assert_eq!(state.selected(), 2);
+
+        let mut state = TabsState::new(0);
+        state.select(1);
+        state.next();
+        state.prev();
+        assert_eq!(state.selected(), 0);
+    }
+}
@@ -0,0 +1,498 @@
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    widgets::{Block, StatefulWidget, Widget},
+};
+use unicode_width::UnicodeWidthChar;
+
+/// Converts a char index into a byte index of `line`.
+pub(super) fn byte_index(line: &str, col: usize) -> usize {
+    line.char_indices()
+        .nth(col)
+        .map(|(i, _)| i)
+        .unwrap_or_else(|| line.len())
+}
+
+pub(super) fn char_count(line: &str) -> usize {
+    line.chars().count()
+}
+
+/// Display width of the first `col` chars of `line`.
+pub(super) fn display_width(line: &str, col: usize) -> usize {
+    line.chars().take(col).map(|c| c.width().unwrap_or(0)).sum()
+}
+
+/// The content and editing state of a [`TextArea`].
+///
+/// Positions are `(row, column)` pairs where the column counts chars, not bytes. Every editing
+/// operation replaces the current selection if there is one.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::TextAreaState;
+/// let mut state = TextAreaState::default();
+/// state.insert_str("Hello\nWorld");
+/// assert_eq!(state.cursor(), (1, 5));
+/// state.move_home();
+/// state.start_selection();
+/// state.move_end();
+/// assert_eq!(state.selected_text().as_deref(), Some("World"));
+/// state.insert_str("there");
+/// assert_eq!(state.text(), "Hello\nthere");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextAreaState {
+    lines: Vec<String>,
+    cursor: (usize, usize),
+    /// Other end of the selection, the cursor being the first one
+    anchor: Option<(usize, usize)>,
+    /// First visible row and display column, as of the last render
+    offset: (usize, usize),
+    /// Position of the cursor on screen, as of the last render
+    screen_cursor: Option<(u16, u16)>,
+}
+
+impl Default for TextAreaState {
+    fn default() -> TextAreaState {
+        TextAreaState::new("")
+    }
+}
+
+impl TextAreaState {
+    /// Creates a state holding the given text, with the cursor at the start.
+    pub fn new(text: &str) -> TextAreaState {
+        TextAreaState {
+            lines: text.split('\n').map(String::from).collect(),
+            cursor: (0, 0),
+            anchor: None,
+            offset: (0, 0),
+            screen_cursor: None,
+        }
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// The whole content, lines being separated by `\n`.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// Index of the first visible row and column, as of the last render.
+    pub fn offset(&self) -> (usize, usize) {
+        self.offset
+    }
+
+    /// Position of the cursor on screen as of the last render, to be given to
+    /// [`Frame::set_cursor`](crate::terminal::Frame::set_cursor). `None` if the area was empty.
+    pub fn screen_cursor(&self) -> Option<(u16, u16)> {
+        self.screen_cursor
+    }
+
+    /// Moves the cursor to the given position, clamped to the content.
+    pub fn set_cursor(&mut self, row: usize, col: usize) {
+        let row = row.min(self.lines.len() - 1);
+        self.cursor = (row, col.min(char_count(&self.lines[row])));
+    }
+
+    /// Starts selecting text from the current cursor position. The selection then spans from
+    /// this position to the cursor until it is cancelled or edited.
+    pub fn start_selection(&mut self) {
+        self.anchor = Some(self.cursor);
+    }
+
+    pub fn cancel_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Selects the whole content.
+    pub fn select_all(&mut self) {
+        self.anchor = Some((0, 0));
+        self.move_bottom();
+    }
+
+    /// Start and end (exclusive) of the selection, in order.
+    pub fn selection(&self) -> Option<((usize, usize), (usize, usize))> {
+        let anchor = self.anchor?;
+        if anchor == self.cursor {
+            None
+        } else if anchor < self.cursor {
+            Some((anchor, self.cursor))
+        } else {
+            Some((self.cursor, anchor))
+        }
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+        if start.0 == end.0 {
+            let line = &self.lines[start.0];
+            return Some(line[byte_index(line, start.1)..byte_index(line, end.1)].to_string());
+        }
+        let first = &self.lines[start.0];
+        let last = &self.lines[end.0];
+        let mut text = first[byte_index(first, start.1)..].to_string();
+        for line in &self.lines[start.0 + 1..end.0] {
+            text.push('\n');
+            text.push_str(line);
+        }
+        text.push('\n');
+        text.push_str(&last[..byte_index(last, end.1)]);
+        Some(text)
+    }
+
+    /// Removes the selected text. Returns `false` if nothing was selected.
+    pub fn delete_selection(&mut self) -> bool {
+        let (start, end) = match self.selection() {
+            Some(selection) => selection,
+            None => {
+                self.anchor = None;
+                return false;
+            }
+        };
+        let last = &self.lines[end.0];
+        let tail = last[byte_index(last, end.1)..].to_string();
+        let first = &mut self.lines[start.0];
+        first.truncate(byte_index(first, start.1));
+        first.push_str(&tail);
+        self.lines.drain(start.0 + 1..=end.0);
+        self.cursor = start;
+        self.anchor = None;
+        true
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        if c == '\n' {
+            self.insert_newline();
+            return;
+        }
+        self.delete_selection();
+        let (row, col) = self.cursor;
+        let line = &mut self.lines[row];
+        line.insert(byte_index(line, col), c);
+        self.cursor.1 += 1;
+    }
+
+    /// Inserts some text at the cursor, which may contain `\n`.
+    pub fn insert_str(&mut self, text: &str) {
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.insert_newline();
+            }
+            self.delete_selection();
+            let (row, col) = self.cursor;
+            let line = &mut self.lines[row];
+            line.insert_str(byte_index(line, col), part);
+            self.cursor.1 += char_count(part);
+        }
+    }
+
+    /// Splits the current line at the cursor.
+    pub fn insert_newline(&mut self) {
+        self.delete_selection();
+        let (row, col) = self.cursor;
+        let line = &mut self.lines[row];
+        let tail = line.split_off(byte_index(line, col));
+        self.lines.insert(row + 1, tail);
+        self.cursor = (row + 1, 0);
+    }
+
+    /// Deletes the char before the cursor, joining lines at the start of a line (backspace).
+    pub fn delete_backward(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+        let (row, col) = self.cursor;
+        if col > 0 {
+            let line = &mut self.lines[row];
+            line.remove(byte_index(line, col - 1));
+            self.cursor.1 -= 1;
+        } else if row > 0 {
+            let line = self.lines.remove(row);
+            let previous = &mut self.lines[row - 1];
+            self.cursor = (row - 1, char_count(previous));
+            previous.push_str(&line);
+        }
+    }
+
+    /// Deletes the char under the cursor, joining lines at the end of a line (delete).
+    pub fn delete_forward(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+        let (row, col) = self.cursor;
+        let line = &mut self.lines[row];
+        if col < char_count(line) {
+            line.remove(byte_index(line, col));
+        } else if row + 1 < self.lines.len() {
+            let next = self.lines.remove(row + 1);
+            self.lines[row].push_str(&next);
+        }
+    }
+
+    /// Moves one char to the left, going to the end of the previous line at the start of a line.
+    pub fn move_left(&mut self) {
+        let (row, col) = self.cursor;
+        if col > 0 {
+            self.cursor.1 -= 1;
+        } else if row > 0 {
+            self.cursor = (row - 1, char_count(&self.lines[row - 1]));
+        }
+    }
+
+    /// Moves one char to the right, going to the start of the next line at the end of a line.
+    pub fn move_right(&mut self) {
+        let (row, col) = self.cursor;
+        if col < char_count(&self.lines[row]) {
+            self.cursor.1 += 1;
+        } else if row + 1 < self.lines.len() {
+            self.cursor = (row + 1, 0);
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        if self.cursor.0 > 0 {
+            self.set_cursor(self.cursor.0 - 1, self.cursor.1);
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        self.set_cursor(self.cursor.0 + 1, self.cursor.1);
+    }
+
+    /// Moves to the start of the current line.
+    pub fn move_home(&mut self) {
+        self.cursor.1 = 0;
+    }
+
+    /// Moves to the end of the current line.
+    pub fn move_end(&mut self) {
+        self.cursor.1 = char_count(&self.lines[self.cursor.0]);
+    }
+
+    /// Moves to the start of the content.
+    pub fn move_top(&mut self) {
+        self.cursor = (0, 0);
+    }
+
+    /// Moves to the end of the content.
+    pub fn move_bottom(&mut self) {
+        let row = self.lines.len() - 1;
+        self.cursor = (row, char_count(&self.lines[row]));
+    }
+
+    /// Scrolls just enough to keep the cursor within a viewport of the given size.
+    fn scroll_to_cursor(&mut self, width: usize, height: usize) {
+        let (row, col) = self.cursor;
+        if row < self.offset.0 {
+            self.offset.0 = row;
+        } else if row >= self.offset.0 + height {
+            self.offset.0 = row + 1 - height;
+        }
+        let x = display_width(&self.lines[row], col);
+        if x < self.offset.1 {
+            self.offset.1 = x;
+        } else if x >= self.offset.1 + width {
+            self.offset.1 = x + 1 - width;
+        }
+    }
+}
+
+/// A widget to edit multiple lines of text.
+///
+/// The content and cursor live in a [`TextAreaState`], which the application updates from its
+/// input events. The widget scrolls to keep the cursor visible, and
+/// [`TextAreaState::screen_cursor`] gives where to place the terminal cursor after rendering.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, TextArea};
+/// # use tui::style::{Style, Color};
+/// TextArea::default()
+///     .block(Block::default().title("Message").borders(Borders::ALL))
+///     .selection_style(Style::DEFAULT.bg(Color::Blue));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextArea<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    /// Style applied to the selected text
+    selection_style: Style,
+}
+
+impl<'a> Default for TextArea<'a> {
+    fn default() -> TextArea<'a> {
+        TextArea {
+            block: None,
+            style: Style::DEFAULT,
+            selection_style: Style::DEFAULT.add_modifier(Modifier::REVERSED),
+        }
+    }
+}
+
+impl<'a> TextArea<'a> {
+    pub fn block(mut self, block: Block<'a>) -> TextArea<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> TextArea<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn selection_style(mut self, style: Style) -> TextArea<'a> {
+        self.selection_style = style;
+        self
+    }
+}
+
+impl<'a> Styled for TextArea<'a> {
+    fn patch_style(mut self, style: Style) -> TextArea<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> StatefulWidget for TextArea<'a> {
+    type State = TextAreaState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+        state.screen_cursor = None;
+        if area.area() == 0 {
+            return;
+        }
+
+        state.scroll_to_cursor(usize::from(area.width), usize::from(area.height));
+        let selection = state.selection();
+        let (row_offset, x_offset) = state.offset;
+        for (y, (row, line)) in state
+            .lines
+            .iter()
+            .enumerate()
+            .skip(row_offset)
+            .take(usize::from(area.height))
+            .enumerate()
+        {
+            let y = area.top() + y as u16;
+            let mut x = 0;
+            for (col, c) in line.chars().enumerate() {
+                let width = c.width().unwrap_or(0);
+                if width == 0 {
+                    continue;
+                }
+                if x >= x_offset + usize::from(area.width) {
+                    break;
+                }
+                // Wide chars crossing one of the edges are not drawn
+                if x >= x_offset && x + width <= x_offset + usize::from(area.width) {
+                    let cell_x = area.left() + (x - x_offset) as u16;
+                    let selected = selection
+                        .map(|(start, end)| start <= (row, col) && (row, col) < end)
+                        .unwrap_or(false);
+                    let style = if selected {
+                        self.style.patch(self.selection_style)
+                    } else {
+                        self.style
+                    };
+                    buf.get_mut(cell_x, y).set_char(c).set_style(style);
+                    for i in 1..width {
+                        buf.get_mut(cell_x + i as u16, y).reset();
+                    }
+                }
+                x += width;
+            }
+        }
+
+        let (row, col) = state.cursor;
+        let x = display_width(&state.lines[row], col);
+        state.screen_cursor = Some((
+            area.left() + (x - x_offset) as u16,
+            area.top() + (row - row_offset) as u16,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn textarea_state_editing() {
+        let mut state = TextAreaState::new("ab");
+        state.move_end();
+        state.insert_newline();
+        state.insert_char('c');
+        assert_eq!(state.lines(), ["ab", "c"]);
+        state.move_top();
+        state.delete_forward();
+        assert_eq!(state.text(), "b\nc");
+        state.move_end();
+        state.delete_forward();
+        assert_eq!(state.text(), "bc");
+        state.delete_backward();
+        state.delete_backward();
+        state.delete_backward();
+        assert_eq!(state.text(), "c");
+        assert_eq!(state.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn textarea_state_handles_multibyte_chars() {
+        let mut state = TextAreaState::new("héllo");
+        state.set_cursor(0, 2);
+        state.delete_backward();
+        assert_eq!(state.text(), "hllo");
+        state.insert_str("ê");
+        assert_eq!(state.text(), "hêllo");
+        assert_eq!(state.cursor(), (0, 2));
+    }
+
+    #[test]
+    fn textarea_state_cursor_movement() {
+        let mut state = TextAreaState::new("long line\nab\nthird");
+        state.set_cursor(0, 7);
+        state.move_down();
+        assert_eq!(state.cursor(), (1, 2));
+        state.move_right();
+        assert_eq!(state.cursor(), (2, 0));
+        state.move_left();
+        state.move_left();
+        assert_eq!(state.cursor(), (1, 1));
+        state.move_bottom();
+        assert_eq!(state.cursor(), (2, 5));
+        state.set_cursor(42, 42);
+        assert_eq!(state.cursor(), (2, 5));
+    }
+
+    #[test]
+    fn textarea_state_selection_across_lines() {
+        let mut state = TextAreaState::new("one\ntwo\nthree");
+        state.set_cursor(2, 2);
+        state.start_selection();
+        state.set_cursor(0, 1);
+        assert_eq!(state.selection(), Some(((0, 1), (2, 2))));
+        assert_eq!(state.selected_text().as_deref(), Some("ne\ntwo\nth"));
+        state.delete_backward();
+        assert_eq!(state.text(), "oree");
+        assert_eq!(state.cursor(), (0, 1));
+        assert_eq!(state.selection(), None);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    symbols,
+    text::Spans,
+    widgets::{Block, StatefulWidget, Widget},
+};
+
+/// Keeps track of the frame shown by a [`Throbber`].
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::ThrobberState;
+/// let mut state = ThrobberState::default();
+/// state.tick();
+/// assert_eq!(state.index(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThrobberState {
+    index: usize,
+}
+
+impl ThrobberState {
+    /// Index of the current frame, which may exceed the number of frames of the throbber (it is
+    /// taken modulo that number).
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Advance to the next frame.
+    pub fn tick(&mut self) {
+        self.index = self.index.wrapping_add(1);
+    }
+
+    pub fn reset(&mut self) {
+        self.index = 0;
+    }
+}
+
+/// A widget cycling through the frames of an animation, followed by an optional label, to show
+/// that some work is in progress.
+///
+/// The animation advances when the [`ThrobberState`] is ticked, usually once per frame or at a
+/// fixed interval. Rendered as a plain [`Widget`], the throbber shows its first frame.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::Throbber;
+/// # use tui::style::{Style, Color};
+/// # use tui::symbols;
+/// Throbber::default()
+///     .label("Downloading…")
+///     .throbber_set(symbols::throbber::ARC)
+///     .throbber_style(Style::DEFAULT.fg(Color::Cyan));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Throbber<'a> {
+    block: Option<Block<'a>>,
+    label: Option<Spans<'a>>,
+    /// Style of the widget area
+    style: Style,
+    /// Style of the animation
+    throbber_style: Style,
+    throbber_set: symbols::throbber::Set,
+}
+
+impl<'a> Default for Throbber<'a> {
+    fn default() -> Throbber<'a> {
+        Throbber {
+            block: None,
+            label: None,
+            style: Style::DEFAULT,
+            throbber_style: Style::DEFAULT,
+            throbber_set: symbols::throbber::DOTS,
+        }
+    }
+}
+
+impl<'a> Throbber<'a> {
+    pub fn block(mut self, block: Block<'a>) -> Throbber<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn label<T>(mut self, label: T) -> Throbber<'a>
+    where
+        T: Into<Spans<'a>>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Throbber<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn throbber_style(mut self, style: Style) -> Throbber<'a> {
+        self.throbber_style = style;
+        self
+    }
+
+    pub fn throbber_set(mut self, set: symbols::throbber::Set) -> Throbber<'a> {
+        self.throbber_set = set;
+        self
+    }
+}
+
+impl<'a> Styled for Throbber<'a> {
+    fn patch_style(mut self, style: Style) -> Throbber<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> StatefulWidget for Throbber<'a> {
+    type State = ThrobberState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut ThrobberState) {
+        buf.set_style(area, self.style);
+        let throbber_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+        if throbber_area.height < 1 || throbber_area.width < 1 {
+            return;
+        }
+
+        let frames = self.throbber_set.frames;
+        let mut x = throbber_area.left();
+        if !frames.is_empty() {
+            let frame = frames[state.index % frames.len()];
+            x = buf
+                .set_stringn(
+                    x,
+                    throbber_area.top(),
+                    frame,
+                    usize::from(throbber_area.width),
+                    self.throbber_style,
+                )
+                .0
+                .saturating_add(1);
+        }
+        if let Some(label) = &self.label {
+            let remaining_width = throbber_area.right().saturating_sub(x);
+            if remaining_width > 0 {
+                buf.set_spans(x, throbber_area.top(), label, remaining_width);
+            }
+        }
+    }
+}
+
+impl<'a> Widget for Throbber<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ThrobberState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
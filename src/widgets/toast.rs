@@ -0,0 +1,247 @@
+use crate::{
+    buffer::Buffer,
+    layout::{Corner, Rect},
+    style::{Color, Style, Styled},
+    text::Text,
+    widgets::{Block, Borders, Clear, Paragraph, StatefulWidget, Widget},
+};
+use std::time::Duration;
+
+/// Severity of a [`Toast`], setting the color of its border
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    fn index(self) -> usize {
+        match self {
+            ToastLevel::Info => 0,
+            ToastLevel::Success => 1,
+            ToastLevel::Warning => 2,
+            ToastLevel::Error => 3,
+        }
+    }
+}
+
+/// A message shown by [`Toasts`] until its time to live runs out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Toast {
+    message: String,
+    level: ToastLevel,
+    remaining: Duration,
+}
+
+impl Toast {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn level(&self) -> ToastLevel {
+        self.level
+    }
+
+    /// Time left before the toast expires
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+}
+
+/// Keeps the notifications displayed by [`Toasts`], from the oldest to the newest.
+///
+/// The toasts expire as time is reported with [`ToastState::tick`], usually once per frame with
+/// the time elapsed since the previous one.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use tui::widgets::{ToastLevel, ToastState};
+/// let mut state = ToastState::default();
+/// state.push("Saved", ToastLevel::Success, Duration::from_secs(2));
+/// state.push("Disk almost full", ToastLevel::Warning, Duration::from_secs(5));
+/// state.tick(Duration::from_secs(3));
+/// assert_eq!(state.len(), 1);
+/// assert_eq!(state.toasts()[0].message(), "Disk almost full");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ToastState {
+    toasts: Vec<Toast>,
+}
+
+impl ToastState {
+    /// Add a toast, expiring after the given time to live.
+    pub fn push<T>(&mut self, message: T, level: ToastLevel, ttl: Duration)
+    where
+        T: Into<String>,
+    {
+        self.toasts.push(Toast {
+            message: message.into(),
+            level,
+            remaining: ttl,
+        });
+    }
+
+    /// Count down the time to live of the toasts, removing the expired ones.
+    pub fn tick(&mut self, elapsed: Duration) {
+        for toast in &mut self.toasts {
+            toast.remaining = toast.remaining.saturating_sub(elapsed);
+        }
+        self.toasts.retain(|toast| toast.remaining > Duration::ZERO);
+    }
+
+    /// Remove the toast at the given index, if any.
+    pub fn dismiss(&mut self, index: usize) {
+        if index < self.toasts.len() {
+            self.toasts.remove(index);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    pub fn toasts(&self) -> &[Toast] {
+        &self.toasts
+    }
+
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+/// A widget stacking the notifications of a [`ToastState`] in a corner of the render area.
+///
+/// Each toast is drawn in a bordered box over what was drawn before, the newest one being the
+/// nearest to the corner. The toasts that do not fit in the area are not drawn. Render the toasts
+/// last, over the whole frame.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Toasts, ToastLevel};
+/// # use tui::layout::Corner;
+/// # use tui::style::{Style, Color};
+/// Toasts::default()
+///     .corner(Corner::TopRight)
+///     .width(30)
+///     .level_style(ToastLevel::Info, Style::DEFAULT.fg(Color::Cyan));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Toasts {
+    /// Style of the toasts
+    style: Style,
+    /// Style of the borders of the toasts of each level
+    level_styles: [Style; 4],
+    corner: Corner,
+    /// Width of the toasts
+    width: u16,
+}
+
+impl Default for Toasts {
+    fn default() -> Toasts {
+        Toasts {
+            style: Style::DEFAULT,
+            level_styles: [
+                Style::DEFAULT.fg(Color::Blue),
+                Style::DEFAULT.fg(Color::Green),
+                Style::DEFAULT.fg(Color::Yellow),
+                Style::DEFAULT.fg(Color::Red),
+            ],
+            corner: Corner::BottomRight,
+            width: 40,
+        }
+    }
+}
+
+impl Toasts {
+    pub fn style(mut self, style: Style) -> Toasts {
+        self.style = style;
+        self
+    }
+
+    pub fn level_style(mut self, level: ToastLevel, style: Style) -> Toasts {
+        self.level_styles[level.index()] = style;
+        self
+    }
+
+    pub fn corner(mut self, corner: Corner) -> Toasts {
+        self.corner = corner;
+        self
+    }
+
+    pub fn width(mut self, width: u16) -> Toasts {
+        self.width = width;
+        self
+    }
+}
+
+impl Styled for Toasts {
+    fn patch_style(mut self, style: Style) -> Toasts {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl StatefulWidget for Toasts {
+    type State = ToastState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut ToastState) {
+        let width = self.width.min(area.width);
+        let x = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => area.left(),
+            Corner::TopRight | Corner::BottomRight => area.right() - width,
+        };
+        let mut stacked_height = 0;
+        for toast in state.toasts.iter().rev() {
+            let text = Text::raw(toast.message.as_str());
+            let height = text.height() as u16 + 2;
+            if stacked_height + height > area.height {
+                break;
+            }
+            let y = match self.corner {
+                Corner::TopLeft | Corner::TopRight => area.top() + stacked_height,
+                Corner::BottomLeft | Corner::BottomRight => area.bottom() - stacked_height - height,
+            };
+            stacked_height += height;
+            let toast_area = Rect {
+                x,
+                y,
+                width,
+                height,
+            };
+            Clear.render(toast_area, buf);
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(self.level_styles[toast.level.index()]);
+            Paragraph::new(text)
+                .block(block)
+                .style(self.style)
+                .render(toast_area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toasts_expire_after_their_time_to_live() {
+        let mut state = ToastState::default();
+        state.push("a", ToastLevel::Info, Duration::from_millis(100));
+        state.push("b", ToastLevel::Error, Duration::from_millis(250));
+        state.tick(Duration::from_millis(100));
+        assert_eq!(state.len(), 1);
+        assert_eq!(state.toasts()[0].remaining(), Duration::from_millis(150));
+        state.tick(Duration::from_millis(200));
+        assert!(state.is_empty());
+    }
+}
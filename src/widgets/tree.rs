@@ -0,0 +1,488 @@
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    text::{Span, Spans},
+    widgets::{Block, StatefulWidget, Widget},
+};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt,
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Selection, expanded nodes and scroll offset of a [`Tree`].
+///
+/// Nodes are identified by their path, the index of the node among its siblings at each depth:
+/// `[1, 0]` is the first child of the second root node.
+///
+/// The navigation methods work on the nodes visible during the last render.
+///
+/// The children of [`lazy`](TreeItem::lazy) nodes are kept once loaded, until
+/// [`reload`](TreeState::reload) is called.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::TreeState;
+/// let mut state = TreeState::default();
+/// state.open(vec![1]);
+/// state.select(Some(vec![1, 0]));
+/// assert!(state.is_open(&[1]));
+/// assert_eq!(state.selected(), Some(&[1, 0][..]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TreeState {
+    offset: usize,
+    selected: Option<Vec<usize>>,
+    opened: HashSet<Vec<usize>>,
+    /// Paths of the visible nodes, in display order, as of the last render
+    visible: Vec<Vec<usize>>,
+    /// Children of the lazy nodes loaded so far, by path
+    loaded: HashMap<Vec<usize>, Vec<TreeItem<'static>>>,
+}
+
+impl TreeState {
+    pub fn selected(&self) -> Option<&[usize]> {
+        self.selected.as_deref()
+    }
+
+    pub fn select(&mut self, path: Option<Vec<usize>>) {
+        self.selected = path;
+        if self.selected.is_none() {
+            self.offset = 0;
+        }
+    }
+
+    /// Index of the first row displayed, as of the last render.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn is_open(&self, path: &[usize]) -> bool {
+        self.opened.contains(path)
+    }
+
+    pub fn open(&mut self, path: Vec<usize>) {
+        self.opened.insert(path);
+    }
+
+    /// Collapses a node. Its descendants keep their own state, and are displayed as they were
+    /// once it is opened again.
+    pub fn close(&mut self, path: &[usize]) {
+        self.opened.remove(path);
+    }
+
+    pub fn toggle(&mut self, path: Vec<usize>) {
+        if !self.opened.remove(&path) {
+            self.opened.insert(path);
+        }
+    }
+
+    /// Forgets the children loaded for the node at `path` and its descendants, so that they are
+    /// loaded again the next time they are displayed. An empty path forgets all of them.
+    pub fn reload(&mut self, path: &[usize]) {
+        self.loaded.retain(|loaded, _| !loaded.starts_with(path));
+    }
+
+    /// Expands or collapses the selected node.
+    pub fn toggle_selected(&mut self) {
+        if let Some(path) = self.selected.clone() {
+            self.toggle(path);
+        }
+    }
+
+    /// Collapses the selected node if it is open, selects its parent otherwise.
+    pub fn close_selected(&mut self) {
+        let path = match self.selected.as_mut() {
+            Some(path) => path,
+            None => return,
+        };
+        if !self.opened.remove(path.as_slice()) && path.len() > 1 {
+            path.pop();
+        }
+    }
+
+    /// Selects the next visible node, or the first one if nothing is selected.
+    pub fn select_next(&mut self) {
+        let next = match self.visible_index() {
+            Some(i) => (i + 1).min(self.visible.len().saturating_sub(1)),
+            None => 0,
+        };
+        if let Some(path) = self.visible.get(next) {
+            self.selected = Some(path.clone());
+        }
+    }
+
+    /// Selects the previous visible node, or the last one if nothing is selected.
+    pub fn select_previous(&mut self) {
+        let previous = match self.visible_index() {
+            Some(i) => i.saturating_sub(1),
+            None => self.visible.len().saturating_sub(1),
+        };
+        if let Some(path) = self.visible.get(previous) {
+            self.selected = Some(path.clone());
+        }
+    }
+
+    fn visible_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+        self.visible.iter().position(|path| path == selected)
+    }
+}
+
+/// A node of a [`Tree`].
+///
+/// Nodes with children can be expanded. Nodes marked as [`lazy`](TreeItem::lazy) can be expanded
+/// too, their children being asked to the loader of the [`Tree`] when they are displayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeItem<'a> {
+    content: Spans<'a>,
+    style: Style,
+    children: Vec<TreeItem<'a>>,
+    lazy: bool,
+}
+
+impl<'a> TreeItem<'a> {
+    pub fn new<T>(content: T) -> TreeItem<'a>
+    where
+        T: Into<Spans<'a>>,
+    {
+        TreeItem {
+            content: content.into(),
+            style: Style::DEFAULT,
+            children: Vec::new(),
+            lazy: false,
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> TreeItem<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn children(mut self, children: Vec<TreeItem<'a>>) -> TreeItem<'a> {
+        self.children = children;
+        self
+    }
+
+    /// Mark this node as having children that are not loaded yet.
+    pub fn lazy(mut self) -> TreeItem<'a> {
+        self.lazy = true;
+        self
+    }
+
+    fn is_expandable(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+
+    /// Returns the item with its content copied, to keep it in a [`TreeState`].
+    fn into_owned(self) -> TreeItem<'static> {
+        let spans = self.content.0.into_iter().map(|span| Span {
+            content: Cow::Owned(span.content.into_owned()),
+            style: span.style,
+        });
+        TreeItem {
+            content: Spans(spans.collect()),
+            style: self.style,
+            children: self
+                .children
+                .into_iter()
+                .map(TreeItem::into_owned)
+                .collect(),
+            lazy: self.lazy,
+        }
+    }
+}
+
+/// A row of a [`Tree`] once flattened.
+struct Row<'a> {
+    path: Vec<usize>,
+    prefix: String,
+    content: Spans<'a>,
+    style: Style,
+}
+
+type Loader<'a> = Box<dyn Fn(&[usize]) -> Vec<TreeItem<'a>> + 'a>;
+
+/// A widget to display a hierarchy of items, each node being expandable like in a file browser.
+///
+/// # Examples
+///
+/// ```
+/// # use tui::widgets::{Block, Borders, Tree, TreeItem};
+/// # use tui::style::{Style, Modifier};
+/// let items = vec![
+///     TreeItem::new("src").children(vec![TreeItem::new("lib.rs")]),
+///     TreeItem::new("target").lazy(),
+///     TreeItem::new("Cargo.toml"),
+/// ];
+/// Tree::new(items)
+///     .block(Block::default().title("Files").borders(Borders::ALL))
+///     .highlight_style(Style::DEFAULT.add_modifier(Modifier::BOLD))
+///     .loader(|path| vec![TreeItem::new(format!("{:?}", path))]);
+/// ```
+pub struct Tree<'a> {
+    block: Option<Block<'a>>,
+    items: Vec<TreeItem<'a>>,
+    style: Style,
+    highlight_style: Style,
+    highlight_symbol: Option<&'a str>,
+    /// Draw lines connecting the nodes to their parent
+    guides: bool,
+    /// Symbols displayed before expandable nodes, when closed and when opened
+    expand_symbols: (&'a str, &'a str),
+    loader: Option<Loader<'a>>,
+}
+
+impl<'a> fmt::Debug for Tree<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tree")
+            .field("block", &self.block)
+            .field("items", &self.items)
+            .field("style", &self.style)
+            .field("highlight_style", &self.highlight_style)
+            .field("highlight_symbol", &self.highlight_symbol)
+            .field("guides", &self.guides)
+            .field("expand_symbols", &self.expand_symbols)
+            .field("loader", &self.loader.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+impl<'a> Tree<'a> {
+    pub fn new(items: Vec<TreeItem<'a>>) -> Tree<'a> {
+        Tree {
+            block: None,
+            items,
+            style: Style::DEFAULT,
+            highlight_style: Style::DEFAULT,
+            highlight_symbol: None,
+            guides: true,
+            expand_symbols: ("▶ ", "▼ "),
+            loader: None,
+        }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Tree<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Tree<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn highlight_style(mut self, style: Style) -> Tree<'a> {
+        self.highlight_style = style;
+        self
+    }
+
+    pub fn highlight_symbol(mut self, highlight_symbol: &'a str) -> Tree<'a> {
+        self.highlight_symbol = Some(highlight_symbol);
+        self
+    }
+
+    /// Whether to draw indentation guides. Nodes are only indented when disabled.
+    pub fn guides(mut self, guides: bool) -> Tree<'a> {
+        self.guides = guides;
+        self
+    }
+
+    /// Set the symbols displayed before closed and opened nodes.
+    pub fn expand_symbols(mut self, closed: &'a str, opened: &'a str) -> Tree<'a> {
+        self.expand_symbols = (closed, opened);
+        self
+    }
+
+    /// Set the function called with the path of each opened [`lazy`](TreeItem::lazy) node to get
+    /// its children. It is only called the first time the node is displayed opened: the children
+    /// are then kept in the [`TreeState`] until [`TreeState::reload`] is called.
+    pub fn loader<F>(mut self, loader: F) -> Tree<'a>
+    where
+        F: Fn(&[usize]) -> Vec<TreeItem<'a>> + 'a,
+    {
+        self.loader = Some(Box::new(loader));
+        self
+    }
+
+    /// Appends the rows of the visible nodes among `items`, whose parent is at `path`.
+    /// `last_ancestors` tells for each ancestor below the roots if it is the last of its siblings.
+    fn flatten(
+        &self,
+        items: &[TreeItem<'a>],
+        path: &mut Vec<usize>,
+        last_ancestors: &mut Vec<bool>,
+        state: &mut TreeState,
+        rows: &mut Vec<Row<'a>>,
+    ) {
+        for (i, item) in items.iter().enumerate() {
+            path.push(i);
+            let is_last = i + 1 == items.len();
+            let mut prefix = String::new();
+            if path.len() > 1 {
+                for &last in last_ancestors.iter() {
+                    prefix.push_str(match (self.guides, last) {
+                        (true, false) => "│ ",
+                        _ => "  ",
+                    });
+                }
+                prefix.push_str(match (self.guides, is_last) {
+                    (true, false) => "├─",
+                    (true, true) => "└─",
+                    (false, _) => "  ",
+                });
+            }
+            let is_open = item.is_expandable() && state.is_open(path);
+            if item.is_expandable() {
+                prefix.push_str(if is_open {
+                    self.expand_symbols.1
+                } else {
+                    self.expand_symbols.0
+                });
+            } else {
+                prefix.push_str(&" ".repeat(self.expand_symbols.0.width()));
+            }
+            rows.push(Row {
+                path: path.clone(),
+                prefix,
+                content: item.content.clone(),
+                style: item.style,
+            });
+
+            if is_open {
+                let loaded;
+                let children = match (&self.loader, item.lazy && item.children.is_empty()) {
+                    (Some(loader), true) => {
+                        loaded = state
+                            .loaded
+                            .entry(path.clone())
+                            .or_insert_with(|| {
+                                loader(path).into_iter().map(TreeItem::into_owned).collect()
+                            })
+                            .clone();
+                        &loaded
+                    }
+                    _ => &item.children,
+                };
+                if path.len() > 1 {
+                    last_ancestors.push(is_last);
+                }
+                self.flatten(children, path, last_ancestors, state, rows);
+                if path.len() > 1 {
+                    last_ancestors.pop();
+                }
+            }
+            path.pop();
+        }
+    }
+}
+
+impl<'a> Styled for Tree<'a> {
+    fn patch_style(mut self, style: Style) -> Tree<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> StatefulWidget for Tree<'a> {
+    type State = TreeState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let tree_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+
+        let mut rows = Vec::new();
+        self.flatten(
+            &self.items,
+            &mut Vec::new(),
+            &mut Vec::new(),
+            state,
+            &mut rows,
+        );
+        state.visible = rows.iter().map(|row| row.path.clone()).collect();
+
+        if tree_area.width < 1 || tree_area.height < 1 || rows.is_empty() {
+            return;
+        }
+
+        let height = usize::from(tree_area.height);
+        let selected = state.visible_index();
+        let mut offset = state.offset.min(rows.len().saturating_sub(height));
+        if let Some(selected) = selected {
+            if selected < offset {
+                offset = selected;
+            } else if selected >= offset + height {
+                offset = selected + 1 - height;
+            }
+        }
+        state.offset = offset;
+
+        let highlight_symbol = self.highlight_symbol.unwrap_or("");
+        let blank_symbol = " ".repeat(highlight_symbol.width());
+        for (i, row) in rows.iter().enumerate().skip(offset).take(height) {
+            let y = tree_area.top() + (i - offset) as u16;
+            let row_area = Rect {
+                x: tree_area.left(),
+                y,
+                width: tree_area.width,
+                height: 1,
+            };
+            let is_selected = selected == Some(i);
+            buf.set_style(row_area, self.style.patch(row.style));
+            let mut x = tree_area.left();
+            if selected.is_some() {
+                let symbol = if is_selected {
+                    highlight_symbol
+                } else {
+                    &blank_symbol
+                };
+                x = buf
+                    .set_stringn(x, y, symbol, usize::from(tree_area.width), row.style)
+                    .0;
+            }
+            let remaining = usize::from(tree_area.right() - x);
+            x = buf.set_stringn(x, y, &row.prefix, remaining, row.style).0;
+            buf.set_spans(x, y, &row.content, tree_area.right() - x);
+            if is_selected {
+                buf.set_style(row_area, self.highlight_style);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tree_state_navigates_visible_nodes() {
+        let mut state = TreeState {
+            visible: vec![vec![0], vec![0, 0], vec![0, 1], vec![1]],
+            ..TreeState::default()
+        };
+        state.select_next();
+        assert_eq!(state.selected(), Some(&[0][..]));
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected(), Some(&[0, 1][..]));
+        state.close_selected();
+        assert_eq!(state.selected(), Some(&[0][..]));
+        state.toggle_selected();
+        assert!(state.is_open(&[0]));
+        state.close_selected();
+        assert!(!state.is_open(&[0]));
+        assert_eq!(state.selected(), Some(&[0][..]));
+        state.select_previous();
+        assert_eq!(state.selected(), Some(&[0][..]));
+    }
+}
@@ -1,7 +1,9 @@
 use std::error::Error;
 use tui::{
     backend::{Backend, TestBackend},
+    buffer::Buffer,
     layout::Rect,
+    style::{Color, ColorDepth, Style},
     widgets::Paragraph,
     Terminal,
 };
@@ -34,3 +36,67 @@ fn terminal_draw_returns_the_completed_frame() -> Result<(), Box<dyn Error>> {
     assert_eq!(frame.area, Rect::new(0, 0, 8, 8));
     Ok(())
 }
+
+#[test]
+fn terminal_swap_backend_redraws_everything_on_the_new_backend() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        let paragrah = Paragraph::new("Test");
+        f.render_widget(paragrah, f.size());
+    })?;
+    let mut terminal = terminal.swap_backend(TestBackend::new(10, 2))?;
+    assert_eq!(
+        terminal.backend().buffer(),
+        &Buffer::with_lines(vec!["          "; 2])
+    );
+    terminal.draw(|f| {
+        let paragrah = Paragraph::new("Test");
+        f.render_widget(paragrah, f.size());
+    })?;
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["Test      ", "          "]));
+    Ok(())
+}
+
+#[test]
+fn terminal_restore_snapshot_redraws_the_snapshot() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend)?;
+    let snapshot = terminal
+        .draw(|f| {
+            let paragrah = Paragraph::new("Test");
+            f.render_widget(paragrah, f.size());
+        })?
+        .buffer
+        .snapshot();
+    terminal.draw(|f| {
+        let paragrah = Paragraph::new("\nOther");
+        f.render_widget(paragrah, f.size());
+    })?;
+    terminal.restore_snapshot(&snapshot)?;
+    terminal.flush()?;
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["Test      ", "          "]));
+
+    let smaller = Buffer::empty(Rect::new(0, 0, 5, 2)).snapshot();
+    assert!(terminal.restore_snapshot(&smaller).is_err());
+    Ok(())
+}
+
+#[test]
+fn terminal_downgrades_colors_to_the_backend_color_depth() -> Result<(), Box<dyn Error>> {
+    let mut backend = TestBackend::new(2, 1);
+    backend.set_color_depth(ColorDepth::Ansi16);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        let paragrah = Paragraph::new("ab").style(Style::DEFAULT.fg(Color::Rgb(250, 10, 10)));
+        f.render_widget(paragrah, f.size());
+    })?;
+    let mut expected = Buffer::with_lines(vec!["ab"]);
+    expected.set_style(Rect::new(0, 0, 2, 1), Style::DEFAULT.fg(Color::LightRed));
+    terminal.backend().assert_buffer(&expected);
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use tui::backend::TestBackend;
 use tui::buffer::Buffer;
-use tui::widgets::{BarChart, Block, Borders};
+use tui::layout::{Direction, Rect};
+use tui::style::{Color, Style};
+use tui::widgets::{BarChart, BarMode, BarScale, BarSeries, Block, Borders};
 use tui::Terminal;
 
 #[test]
@@ -38,3 +40,127 @@ fn widgets_barchart_not_full_below_max_value() {
         "└────────────────────────────┘",
     ]));
 }
+
+#[test]
+fn widgets_barchart_can_group_and_stack_series() {
+    let test_case = |mode, expected| {
+        let backend = TestBackend::new(12, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                let size = f.size();
+                let barchart = BarChart::default()
+                    .categories(&["a", "b"])
+                    .series(vec![
+                        BarSeries::new("x", &[4, 2]).style(Style::DEFAULT.fg(Color::Red)),
+                        BarSeries::new("y", &[2, 4]).style(Style::DEFAULT.fg(Color::Blue)),
+                    ])
+                    .mode(mode)
+                    .legend(true)
+                    .bar_width(2);
+                f.render_widget(barchart, size);
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    let mut expected = Buffer::with_lines(vec![
+        "■ x  ■ y    ",
+        "██     ██   ",
+        "██     ██   ",
+        "████ ████   ",
+        "4█2█ 2█4█   ",
+        "a    b      ",
+    ]);
+    expected.set_style(Rect::new(0, 0, 2, 1), Style::DEFAULT.fg(Color::Red));
+    expected.set_style(Rect::new(5, 0, 2, 1), Style::DEFAULT.fg(Color::Blue));
+    expected.set_style(Rect::new(0, 1, 2, 4), Style::DEFAULT.fg(Color::Red));
+    expected.set_style(Rect::new(2, 3, 2, 2), Style::DEFAULT.fg(Color::Blue));
+    expected.set_style(Rect::new(5, 3, 2, 2), Style::DEFAULT.fg(Color::Red));
+    expected.set_style(Rect::new(7, 1, 2, 4), Style::DEFAULT.fg(Color::Blue));
+    test_case(BarMode::Grouped, expected);
+
+    // boundaries between stacked values are rounded to whole cells
+    let mut expected = Buffer::with_lines(vec![
+        "■ x  ■ y    ",
+        "██ ██       ",
+        "██ ██       ",
+        "██ ██       ",
+        "6█ 6█       ",
+        "a  b        ",
+    ]);
+    expected.set_style(Rect::new(0, 0, 2, 1), Style::DEFAULT.fg(Color::Red));
+    expected.set_style(Rect::new(5, 0, 2, 1), Style::DEFAULT.fg(Color::Blue));
+    expected.set_style(Rect::new(0, 1, 2, 1), Style::DEFAULT.fg(Color::Blue));
+    expected.set_style(Rect::new(0, 2, 2, 3), Style::DEFAULT.fg(Color::Red));
+    expected.set_style(Rect::new(3, 1, 2, 3), Style::DEFAULT.fg(Color::Blue));
+    expected.set_style(Rect::new(3, 4, 2, 1), Style::DEFAULT.fg(Color::Red));
+    test_case(BarMode::Stacked, expected);
+}
+
+#[test]
+fn widgets_barchart_can_be_horizontal() {
+    let backend = TestBackend::new(12, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let barchart = BarChart::default()
+                .data(&[("long", 8), ("b", 4)])
+                .direction(Direction::Horizontal);
+            f.render_widget(barchart, size);
+        })
+        .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "long 8██████",
+        "            ",
+        "b    4██▌   ",
+    ]));
+}
+
+#[test]
+fn widgets_barchart_ignores_bars_without_width() {
+    let backend = TestBackend::new(12, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let barchart = BarChart::default()
+                .data(&[("a", 8), ("b", 4)])
+                .bar_width(0)
+                .direction(Direction::Horizontal);
+            f.render_widget(barchart, size);
+        })
+        .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "a           ",
+        "b           ",
+        "            ",
+    ]));
+}
+
+#[test]
+fn widgets_barchart_can_scale_and_format_values() {
+    let unit = String::from("k");
+    let backend = TestBackend::new(10, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let barchart = BarChart::default()
+                .data(&[("a", 99), ("b", 9999)])
+                .direction(Direction::Horizontal)
+                .scale(BarScale::Log10)
+                .max(999)
+                .value_formatter(|value| format!("{}{}", value / 1000, unit));
+            f.render_widget(barchart, size);
+        })
+        .unwrap();
+    // the second bar exceeds the maximum and is cut
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "a 0k███▎  ",
+        "          ",
+        "b 9k██████",
+    ]));
+}
@@ -0,0 +1,50 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Span, Spans},
+    widgets::{BigText, PixelSize},
+    Terminal,
+};
+
+fn render(big_text: BigText, width: u16, height: u16) -> Buffer {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| f.render_widget(big_text, f.size()))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn widgets_big_text_draws_a_pixel_per_cell() {
+    let text = Spans::from(vec![
+        Span::raw("h"),
+        Span::styled("i", Style::DEFAULT.fg(Color::Red)),
+    ]);
+    let mut expected = Buffer::with_lines(vec![
+        "█ █ ███ ",
+        "█ █  █  ",
+        "███  █  ",
+        "█ █  █  ",
+        "█ █ ███ ",
+        "        ",
+    ]);
+    expected.set_style(Rect::new(4, 0, 3, 5), Style::DEFAULT.fg(Color::Red));
+    for (x, y) in [(4, 1), (6, 1), (4, 2), (6, 2), (4, 3), (6, 3)] {
+        expected
+            .get_mut(x, y)
+            .set_style(Style::DEFAULT.fg(Color::Reset));
+    }
+    assert_eq!(render(BigText::new(text), 8, 6), expected);
+}
+
+#[test]
+fn widgets_big_text_draws_quadrants() {
+    let big_text = BigText::new("HI")
+        .pixel_size(PixelSize::Quadrant)
+        .alignment(Alignment::Right);
+    let expected = Buffer::with_lines(vec!["  ▌▌▜▘", "  ▛▌▐ ", "  ▘▘▀▘"]);
+    assert_eq!(render(big_text, 6, 3), expected);
+}
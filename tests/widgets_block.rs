@@ -1,10 +1,11 @@
 use tui::{
     backend::TestBackend,
     buffer::Buffer,
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style},
+    symbols,
     text::Span,
-    widgets::{Block, Borders},
+    widgets::{Block, BorderPart, Borders, Title, TitlePosition},
     Terminal,
 };
 
@@ -344,3 +345,183 @@ fn widgets_block_title_alignment() {
         Buffer::with_lines(vec!["         Title ", "               "]),
     );
 }
+
+#[test]
+fn widgets_block_title_bottom() {
+    let test_case = |alignment, borders, expected| {
+        let backend = TestBackend::new(15, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        let block = Block::default()
+            .title("Top")
+            .title_bottom("[q] quit")
+            .title_bottom_alignment(alignment)
+            .borders(borders);
+
+        terminal
+            .draw(|f| {
+                f.render_widget(block, f.size());
+            })
+            .unwrap();
+
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    test_case(
+        Alignment::Left,
+        Borders::ALL,
+        Buffer::with_lines(vec![
+            "┌Top──────────┐",
+            "│             │",
+            "└[q] quit─────┘",
+        ]),
+    );
+    test_case(
+        Alignment::Right,
+        Borders::ALL,
+        Buffer::with_lines(vec![
+            "┌Top──────────┐",
+            "│             │",
+            "└─────[q] quit┘",
+        ]),
+    );
+    test_case(
+        Alignment::Center,
+        Borders::BOTTOM,
+        Buffer::with_lines(vec![
+            "Top            ",
+            "               ",
+            "───[q] quit────",
+        ]),
+    );
+    // no bottom border, no bottom title
+    test_case(
+        Alignment::Left,
+        Borders::TOP,
+        Buffer::with_lines(vec![
+            "Top────────────",
+            "               ",
+            "               ",
+        ]),
+    );
+}
+
+#[test]
+fn widgets_block_border_parts() {
+    let backend = TestBackend::new(6, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::DEFAULT.fg(Color::Gray))
+        .border_part_style(BorderPart::Left, Style::DEFAULT.fg(Color::Yellow))
+        .border_part_symbols(BorderPart::Left, symbols::line::THICK)
+        .border_part_symbols(BorderPart::TopRight, symbols::line::ROUNDED);
+    terminal
+        .draw(|f| {
+            f.render_widget(block, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["┌────╮", "┃    │", "└────┘"]);
+    expected.set_style(Rect::new(0, 0, 6, 1), Style::DEFAULT.fg(Color::Gray));
+    expected.set_style(Rect::new(5, 1, 1, 1), Style::DEFAULT.fg(Color::Gray));
+    expected.set_style(Rect::new(0, 2, 6, 1), Style::DEFAULT.fg(Color::Gray));
+    expected.get_mut(0, 1).set_fg(Color::Yellow);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_block_multiple_titles() {
+    let backend = TestBackend::new(20, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .add_title("Files")
+        .add_title(Title::from("[q]").alignment(Alignment::Right))
+        .add_title(Title::from("[?]").alignment(Alignment::Right))
+        .add_title(Title::from("*").alignment(Alignment::Center))
+        .add_title(
+            Title::from("3 selected")
+                .alignment(Alignment::Right)
+                .position(TitlePosition::Bottom),
+        )
+        .add_title(Title::from("a long status").position(TitlePosition::Bottom));
+    terminal
+        .draw(|f| {
+            f.render_widget(block, f.size());
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec![
+        "┌Files───*──[?]─[q]┐",
+        "│                  │",
+        "└a long status─3 se┘",
+    ]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_block_border_sets() {
+    let test_case = |block: Block, expected| {
+        let backend = TestBackend::new(6, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                f.render_widget(block, f.size());
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    test_case(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_set(symbols::border::QUADRANT_OUTSIDE),
+        Buffer::with_lines(vec!["▛▀▀▀▀▜", "▌    ▐", "▙▄▄▄▄▟"]),
+    );
+    test_case(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_set(symbols::border::QUADRANT_INSIDE),
+        Buffer::with_lines(vec!["▗▄▄▄▄▖", "▐    ▌", "▝▀▀▀▀▘"]),
+    );
+    // mixed border types
+    test_case(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_set(symbols::border::DOUBLE)
+            .border_part_symbols(BorderPart::Bottom, symbols::border::PLAIN),
+        Buffer::with_lines(vec!["╔════╗", "║    ║", "╚────╝"]),
+    );
+}
+
+#[test]
+fn widgets_block_collapsed_borders() {
+    let backend = TestBackend::new(9, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let rows = Layout::new(
+                Direction::Vertical,
+                [Constraint::Length(2), Constraint::Fill(1)],
+            )
+            .collapse_borders(true)
+            .split(f.size());
+            for row in rows {
+                let columns = Layout::new(Direction::Horizontal, [4, 5])
+                    .collapse_borders(true)
+                    .split(row);
+                for column in columns {
+                    let block = Block::default().borders(Borders::ALL).merge_borders(true);
+                    f.render_widget(block, column);
+                }
+            }
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec![
+        "┌───┬───┐",
+        "│   │   │",
+        "├───┼───┤",
+        "│   │   │",
+        "└───┴───┘",
+    ]);
+    terminal.backend().assert_buffer(&expected);
+}
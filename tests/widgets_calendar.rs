@@ -0,0 +1,106 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::calendar::{CalendarEventStore, Date, DateStyler, Monthly, Weekday},
+    Terminal,
+};
+
+fn render<S>(calendar: Monthly<S>, width: u16, height: u16) -> Buffer
+where
+    S: DateStyler,
+{
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| f.render_widget(calendar, f.size()))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn widgets_calendar_renders_month_with_headers_and_events() {
+    let mut events = CalendarEventStore::default();
+    events.add(
+        Date::new(2024, 1, 10).unwrap(),
+        Style::DEFAULT.fg(Color::Red),
+    );
+    let calendar = Monthly::new(Date::new(2024, 1, 17).unwrap(), events)
+        .show_month_header(Style::DEFAULT.add_modifier(Modifier::BOLD))
+        .show_weekdays_header(Style::DEFAULT)
+        .show_surrounding(Style::DEFAULT.add_modifier(Modifier::DIM));
+    let buffer = render(calendar, 20, 7);
+    let mut expected = Buffer::with_lines(vec![
+        "    January 2024    ",
+        "Mo Tu We Th Fr Sa Su",
+        " 1  2  3  4  5  6  7",
+        " 8  9 10 11 12 13 14",
+        "15 16 17 18 19 20 21",
+        "22 23 24 25 26 27 28",
+        "29 30 31  1  2  3  4",
+    ]);
+    expected.set_style(
+        Rect::new(4, 0, 12, 1),
+        Style::DEFAULT.add_modifier(Modifier::BOLD),
+    );
+    expected.set_style(Rect::new(6, 3, 2, 1), Style::DEFAULT.fg(Color::Red));
+    expected.set_style(
+        Rect::new(9, 6, 11, 1),
+        Style::DEFAULT.add_modifier(Modifier::DIM),
+    );
+    // The space between surrounding days is not styled
+    for x in [11, 14, 17] {
+        expected.get_mut(x, 6).set_style(Style::RESET);
+    }
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+fn widgets_calendar_renders_several_months_from_sunday() {
+    let calendar = Monthly::new(
+        Date::new(2024, 1, 1).unwrap(),
+        CalendarEventStore::default(),
+    )
+    .first_day_of_week(Weekday::Sunday)
+    .show_weekdays_header(Style::DEFAULT)
+    .months(2);
+    let buffer = render(calendar, 42, 7);
+    let expected = Buffer::with_lines(vec![
+        "Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa",
+        "    1  2  3  4  5  6               1  2  3",
+        " 7  8  9 10 11 12 13   4  5  6  7  8  9 10",
+        "14 15 16 17 18 19 20  11 12 13 14 15 16 17",
+        "21 22 23 24 25 26 27  18 19 20 21 22 23 24",
+        "28 29 30 31           25 26 27 28 29      ",
+        "                                          ",
+    ]);
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+fn widgets_calendar_wraps_months_on_narrow_areas() {
+    let calendar = Monthly::new(
+        Date::new(2024, 2, 1).unwrap(),
+        CalendarEventStore::default(),
+    )
+    .months(2);
+    let buffer = render(calendar, 20, 14);
+    let expected = Buffer::with_lines(vec![
+        "          1  2  3  4",
+        " 5  6  7  8  9 10 11",
+        "12 13 14 15 16 17 18",
+        "19 20 21 22 23 24 25",
+        "26 27 28 29         ",
+        "                    ",
+        "                    ",
+        "             1  2  3",
+        " 4  5  6  7  8  9 10",
+        "11 12 13 14 15 16 17",
+        "18 19 20 21 22 23 24",
+        "25 26 27 28 29 30 31",
+        "                    ",
+        "                    ",
+    ]);
+    assert_eq!(buffer, expected);
+}
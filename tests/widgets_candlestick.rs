@@ -0,0 +1,101 @@
+use tui::backend::TestBackend;
+use tui::buffer::Buffer;
+use tui::layout::Rect;
+use tui::style::{Color, Style};
+use tui::text::Span;
+use tui::widgets::{Axis, Candle, Candlestick};
+use tui::Terminal;
+
+#[test]
+fn widgets_candlestick_can_render_candles() {
+    let backend = TestBackend::new(8, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let candles = [
+        Candle::new(1.0, 4.0, 0.0, 3.0),
+        Candle::new(3.0, 3.0, 1.0, 2.0),
+        Candle::new(2.0, 3.0, 1.0, 2.0),
+    ];
+    terminal
+        .draw(|f| {
+            let candlestick = Candlestick::new(&candles).y_axis(
+                Axis::default()
+                    .bounds([0.0, 4.0])
+                    .labels(vec![Span::raw("0"), Span::raw("4")]),
+            );
+            f.render_widget(candlestick, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![
+        "4││     ",
+        " │█ █ │ ",
+        " │█ █ ─ ",
+        " │█ │ │ ",
+        "0││     ",
+    ]);
+    expected.set_style(Rect::new(2, 0, 1, 5), Style::DEFAULT.fg(Color::Green));
+    expected.set_style(Rect::new(4, 1, 1, 3), Style::DEFAULT.fg(Color::Red));
+    expected.set_style(Rect::new(6, 1, 1, 3), Style::DEFAULT.fg(Color::Green));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_candlestick_displays_the_most_recent_candles() {
+    let backend = TestBackend::new(3, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let candles = [
+        Candle::new(0.0, 1.0, 0.0, 1.0),
+        Candle::new(1.0, 1.0, 0.0, 0.0),
+        Candle::new(0.0, 1.0, 0.0, 0.0),
+    ];
+    terminal
+        .draw(|f| {
+            let candlestick = Candlestick::new(&candles)
+                .y_axis(Axis::default().bounds([0.0, 1.0]))
+                .down_style(Style::DEFAULT);
+            f.render_widget(candlestick, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["█ │", "█ ─"]);
+    expected.set_style(Rect::new(2, 0, 1, 2), Style::DEFAULT.fg(Color::Green));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_candlestick_can_have_a_single_y_axis_label() {
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let candles = [Candle::new(1.0, 2.0, 0.0, 1.0)];
+    terminal
+        .draw(|f| {
+            let candlestick =
+                Candlestick::new(&candles).y_axis(Axis::default().labels(vec![Span::raw("x")]));
+            f.render_widget(candlestick, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![
+        " │─       ",
+        " ││       ",
+        " ││       ",
+        " ││       ",
+        "x││       ",
+    ]);
+    expected.set_style(Rect::new(2, 0, 1, 5), Style::DEFAULT.fg(Color::Green));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_candlestick_should_not_panic_on_large_sizes() {
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let candles = [Candle::new(1.0, 2.0, 0.0, 1.0)];
+    for (width, gap) in [(u16::MAX, u16::MAX), (1, u16::MAX), (u16::MAX, 0)] {
+        terminal
+            .draw(|f| {
+                let candlestick = Candlestick::new(&candles)
+                    .candle_width(width)
+                    .candle_gap(gap);
+                f.render_widget(candlestick, f.size());
+            })
+            .unwrap();
+    }
+}
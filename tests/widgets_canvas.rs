@@ -1,9 +1,17 @@
 use tui::{
     backend::TestBackend,
     buffer::Buffer,
+    layout::Rect,
     style::{Color, Style},
+    symbols::Marker,
     text::Span,
-    widgets::canvas::Canvas,
+    widgets::{
+        canvas::{
+            Arc, BezierCurve, Canvas, CanvasState, Circle, Ellipse, Image, ImageMode, Line,
+            Painter, Points, Polygon, QuadraticBezierCurve, Rectangle,
+        },
+        Block, Borders,
+    },
     Terminal,
 };
 
@@ -40,3 +48,487 @@ fn widgets_canvas_draw_labels() {
     }
     terminal.backend().assert_buffer(&expected)
 }
+
+#[test]
+fn widgets_canvas_draws_bezier_curves_without_gaps() {
+    let backend = TestBackend::new(10, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let canvas = Canvas::default()
+                .marker(Marker::Dot)
+                .x_bounds([0.0, 9.0])
+                .y_bounds([0.0, 3.0])
+                .paint(|ctx| {
+                    ctx.draw(&BezierCurve {
+                        p0: (0.0, 0.0),
+                        p1: (3.0, 4.0),
+                        p2: (6.0, 4.0),
+                        p3: (9.0, 0.0),
+                        color: Color::Reset,
+                    });
+                });
+            f.render_widget(canvas, f.size());
+        })
+        .unwrap();
+
+    let expected = Buffer::with_lines(vec!["  •••••   ", " •     •  ", "•       • ", "•        •"]);
+    terminal.backend().assert_buffer(&expected)
+}
+
+#[test]
+fn widgets_canvas_can_fill_polygons() {
+    let backend = TestBackend::new(9, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let canvas = Canvas::default()
+                .marker(Marker::Dot)
+                .x_bounds([0.0, 8.0])
+                .y_bounds([0.0, 4.0])
+                .paint(|ctx| {
+                    ctx.draw(&Polygon {
+                        points: &[(0.0, 0.0), (4.0, 4.0), (8.0, 0.0)],
+                        filled: true,
+                        color: Color::Reset,
+                    });
+                });
+            f.render_widget(canvas, f.size());
+        })
+        .unwrap();
+
+    let expected = Buffer::with_lines(vec![
+        "   ••    ",
+        "  ••••   ",
+        " ••••••  ",
+        "•••••••• ",
+        "•••••••••",
+    ]);
+    terminal.backend().assert_buffer(&expected)
+}
+
+#[test]
+fn widgets_canvas_can_flood_fill() {
+    let backend = TestBackend::new(9, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let canvas = Canvas::default()
+                .marker(Marker::Dot)
+                .x_bounds([0.0, 8.0])
+                .y_bounds([0.0, 4.0])
+                .paint(|ctx| {
+                    ctx.draw(&Rectangle {
+                        x: 1.0,
+                        y: 1.0,
+                        width: 4.0,
+                        height: 2.0,
+                        color: Color::Reset,
+                    });
+                    Painter::from(ctx).flood_fill(2, 2, Color::Red);
+                });
+            f.render_widget(canvas, f.size());
+        })
+        .unwrap();
+
+    let mut expected = Buffer::with_lines(vec![
+        "         ",
+        " •••••   ",
+        " •••••   ",
+        " •••••   ",
+        "         ",
+    ]);
+    expected.set_style(Rect::new(2, 2, 3, 1), Style::DEFAULT.fg(Color::Red));
+    terminal.backend().assert_buffer(&expected)
+}
+
+#[test]
+fn widgets_canvas_flood_fill_ignores_empty_canvases() {
+    let backend = TestBackend::new(4, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            for marker in [Marker::Dot, Marker::Block, Marker::Braille] {
+                for area in [Rect::new(0, 0, 0, 2), Rect::new(0, 0, 4, 0)] {
+                    let canvas = Canvas::default()
+                        .marker(marker)
+                        .x_bounds([0.0, 4.0])
+                        .y_bounds([0.0, 2.0])
+                        .paint(|ctx| Painter::from(ctx).flood_fill(0, 0, Color::Red));
+                    f.render_widget(canvas, area);
+                }
+            }
+        })
+        .unwrap();
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["    ", "    "]));
+}
+
+#[test]
+fn widgets_canvas_draws_quadratic_curves() {
+    let backend = TestBackend::new(9, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let canvas = Canvas::default()
+                .marker(Marker::Braille)
+                .x_bounds([0.0, 8.0])
+                .y_bounds([0.0, 4.0])
+                .paint(|ctx| {
+                    ctx.draw(&QuadraticBezierCurve {
+                        p0: (0.0, 0.0),
+                        p1: (4.0, 8.0),
+                        p2: (8.0, 0.0),
+                        color: Color::Reset,
+                    });
+                });
+            f.render_widget(canvas, f.size());
+        })
+        .unwrap();
+
+    let expected = Buffer::with_lines(vec![" ⢀⡔⠋⠉⠓⣄  ", "⢠⠎    ⠈⢦ ", "⡏      ⠈⢇"]);
+    terminal.backend().assert_buffer(&expected)
+}
+
+#[test]
+fn widgets_canvas_draws_images_with_half_blocks() {
+    let backend = TestBackend::new(2, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    #[rustfmt::skip]
+    let pixels = [
+        255, 0, 0, 255, 0, 0, 255, 255,
+        0, 255, 0, 255, 255, 255, 255, 255,
+        255, 0, 0, 255, 0, 0, 0, 0,
+        0, 255, 0, 255, 0, 0, 0, 0,
+    ];
+    terminal
+        .draw(|f| {
+            let canvas = Canvas::default()
+                .x_bounds([0.0, 2.0])
+                .y_bounds([0.0, 2.0])
+                .paint(|ctx| {
+                    ctx.draw(&Image {
+                        x: 0.0,
+                        y: 0.0,
+                        width: 2.0,
+                        height: 2.0,
+                        pixels: &pixels,
+                        pixels_per_row: 2,
+                        alpha: true,
+                        mode: ImageMode::HalfBlock,
+                    });
+                });
+            f.render_widget(canvas, f.size());
+        })
+        .unwrap();
+
+    let mut expected = Buffer::with_lines(vec!["▀▀", "▀ "]);
+    expected.set_style(
+        Rect::new(0, 0, 1, 1),
+        Style::DEFAULT
+            .fg(Color::Rgb(255, 0, 0))
+            .bg(Color::Rgb(0, 255, 0)),
+    );
+    expected.set_style(
+        Rect::new(1, 0, 1, 1),
+        Style::DEFAULT
+            .fg(Color::Rgb(0, 0, 255))
+            .bg(Color::Rgb(255, 255, 255)),
+    );
+    expected.set_style(
+        Rect::new(0, 1, 1, 1),
+        Style::DEFAULT
+            .fg(Color::Rgb(255, 0, 0))
+            .bg(Color::Rgb(0, 255, 0)),
+    );
+    terminal.backend().assert_buffer(&expected)
+}
+
+#[test]
+fn widgets_canvas_draws_images_with_a_threshold() {
+    let backend = TestBackend::new(3, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let pixels = [255, 255, 255, 0, 0, 0, 10, 10, 10, 200, 200, 200];
+    terminal
+        .draw(|f| {
+            let canvas = Canvas::default()
+                .marker(Marker::Dot)
+                .x_bounds([0.0, 2.0])
+                .y_bounds([0.0, 1.0])
+                .paint(|ctx| {
+                    ctx.draw(&Image {
+                        x: 0.0,
+                        y: 0.0,
+                        width: 1.0,
+                        height: 1.0,
+                        pixels: &pixels,
+                        pixels_per_row: 2,
+                        alpha: false,
+                        mode: ImageMode::Threshold(128),
+                    });
+                });
+            f.render_widget(canvas, f.size());
+        })
+        .unwrap();
+
+    let mut expected = Buffer::with_lines(vec!["•  ", " • "]);
+    expected.set_style(
+        Rect::new(0, 0, 1, 1),
+        Style::DEFAULT.fg(Color::Rgb(255, 255, 255)),
+    );
+    expected.set_style(
+        Rect::new(1, 1, 1, 1),
+        Style::DEFAULT.fg(Color::Rgb(200, 200, 200)),
+    );
+    terminal.backend().assert_buffer(&expected)
+}
+
+#[test]
+fn widgets_canvas_keeps_named_layers_between_frames() {
+    let backend = TestBackend::new(3, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = CanvasState::default();
+    let draw = |terminal: &mut Terminal<TestBackend>, state: &mut CanvasState| {
+        terminal
+            .draw(|f| {
+                let canvas = Canvas::default()
+                    .marker(Marker::Dot)
+                    .x_bounds([0.0, 2.0])
+                    .y_bounds([0.0, 1.0])
+                    .paint(|ctx| {
+                        if !ctx.has_layer("background") {
+                            ctx.draw(&Line {
+                                x1: 0.0,
+                                y1: 0.0,
+                                x2: 1.0,
+                                y2: 0.0,
+                                color: Color::Blue,
+                            });
+                            ctx.named_layer("background", 1);
+                        }
+                        ctx.draw(&Line {
+                            x1: 1.0,
+                            y1: 0.0,
+                            x2: 2.0,
+                            y2: 0.0,
+                            color: Color::Red,
+                        });
+                    });
+                f.render_stateful_widget(canvas, f.size(), state);
+            })
+            .unwrap();
+    };
+
+    // The background is drawn over the other layer because of its z-index
+    let mut expected = Buffer::with_lines(vec!["•••"]);
+    expected.set_style(Rect::new(0, 0, 2, 1), Style::DEFAULT.fg(Color::Blue));
+    expected.set_style(Rect::new(2, 0, 1, 1), Style::DEFAULT.fg(Color::Red));
+    draw(&mut terminal, &mut state);
+    terminal.backend().assert_buffer(&expected);
+    assert!(state.has_layer("background"));
+
+    // The background is kept without being drawn again
+    draw(&mut terminal, &mut state);
+    terminal.backend().assert_buffer(&expected);
+
+    state.set_layer_z_index("background", -1);
+    draw(&mut terminal, &mut state);
+    expected.set_style(Rect::new(1, 0, 1, 1), Style::DEFAULT.fg(Color::Red));
+    terminal.backend().assert_buffer(&expected);
+
+    state.set_layer_visible("background", false);
+    draw(&mut terminal, &mut state);
+    let mut expected = Buffer::with_lines(vec![" ••"]);
+    expected.set_style(Rect::new(1, 0, 2, 1), Style::DEFAULT.fg(Color::Red));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_canvas_can_zoom_and_pan() {
+    let backend = TestBackend::new(5, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = CanvasState::default();
+    let draw = |terminal: &mut Terminal<TestBackend>, state: &mut CanvasState| {
+        terminal
+            .draw(|f| {
+                let canvas = Canvas::default()
+                    .marker(Marker::Dot)
+                    .x_bounds([0.0, 4.0])
+                    .y_bounds([0.0, 1.0])
+                    .paint(|ctx| {
+                        ctx.draw(&Points {
+                            coords: &[(3.0, 0.5)],
+                            color: Color::Reset,
+                        });
+                    });
+                f.render_stateful_widget(canvas, f.size(), state);
+            })
+            .unwrap();
+    };
+
+    draw(&mut terminal, &mut state);
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["   • "]));
+
+    state.zoom(2.0, (2.0, 0.5));
+    assert_eq!(state.x_bounds(), Some([1.0, 3.0]));
+    draw(&mut terminal, &mut state);
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["    •"]));
+
+    state.pan(-1.0, 0.0);
+    draw(&mut terminal, &mut state);
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["     "]));
+
+    state.reset_bounds();
+    draw(&mut terminal, &mut state);
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["   • "]));
+}
+
+#[test]
+fn widgets_canvas_should_not_panic_on_regions_without_height() {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let canvas = Canvas::default()
+                .block(Block::default().borders(Borders::ALL))
+                .x_bounds([0.0, 10.0])
+                .y_bounds([0.0, 10.0])
+                .paint(|ctx| {
+                    ctx.draw(&Circle {
+                        x: 5.0,
+                        y: 5.0,
+                        radius: 3.0,
+                        filled: true,
+                        color: Color::Red,
+                    });
+                    ctx.draw(&Ellipse {
+                        x: 5.0,
+                        y: 5.0,
+                        x_radius: 4.0,
+                        y_radius: 2.0,
+                        filled: false,
+                        color: Color::Red,
+                    });
+                    ctx.draw(&Arc {
+                        x_center: 5.0,
+                        y_center: 5.0,
+                        radius: 3.0,
+                        start_angle: 0.0,
+                        end_angle: 90.0,
+                        filled: true,
+                        color: Color::Red,
+                    });
+                });
+            f.render_widget(canvas, f.size());
+        })
+        .unwrap();
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["┌────────┐", "└────────┘"]));
+}
+
+#[test]
+fn widgets_canvas_clips_curves_reaching_far_outside() {
+    let backend = TestBackend::new(10, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let canvas = Canvas::default()
+                .marker(Marker::Dot)
+                .x_bounds([0.0, 9.0])
+                .y_bounds([0.0, 3.0])
+                .paint(|ctx| {
+                    ctx.draw(&BezierCurve {
+                        p0: (0.0, 1.0),
+                        p1: (1e3, 1.0),
+                        p2: (1e5, 1.0),
+                        p3: (1e7, 1.0),
+                        color: Color::Reset,
+                    });
+                    ctx.draw(&Arc {
+                        x_center: 4.5,
+                        y_center: 2.0 - 1e7,
+                        radius: 1e7,
+                        start_angle: 0.0,
+                        end_angle: 180.0,
+                        filled: false,
+                        color: Color::Reset,
+                    });
+                });
+            f.render_widget(canvas, f.size());
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["          ", "••••••••••", "••••••••••", "          "]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_canvas_keeps_images_in_their_layer() {
+    let backend = TestBackend::new(2, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = CanvasState::default();
+    let pixels = [255, 0, 0, 255, 0, 0, 255, 0, 0, 255, 0, 0];
+    let mut draw = |state: &mut CanvasState| {
+        terminal
+            .draw(|f| {
+                let canvas = Canvas::default()
+                    .marker(Marker::Dot)
+                    .x_bounds([0.0, 1.0])
+                    .y_bounds([0.0, 1.0])
+                    .paint(|ctx| {
+                        if !ctx.has_layer("image") {
+                            ctx.draw(&Image {
+                                x: 0.0,
+                                y: 0.0,
+                                width: 1.0,
+                                height: 1.0,
+                                pixels: &pixels,
+                                pixels_per_row: 2,
+                                alpha: false,
+                                mode: ImageMode::HalfBlock,
+                            });
+                            ctx.named_layer("image", 1);
+                        }
+                        ctx.draw(&Line {
+                            x1: 0.0,
+                            y1: 0.0,
+                            x2: 1.0,
+                            y2: 0.0,
+                            color: Color::Blue,
+                        });
+                    });
+                f.render_stateful_widget(canvas, f.size(), state);
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    };
+
+    // The image is drawn over the line because of its z-index, even once cached
+    let red = Style::DEFAULT
+        .fg(Color::Rgb(255, 0, 0))
+        .bg(Color::Rgb(255, 0, 0));
+    let mut expected = Buffer::with_lines(vec!["▀▀", "▀▀"]);
+    expected.set_style(expected.area, red);
+    assert_eq!(draw(&mut state), expected);
+    assert_eq!(draw(&mut state), expected);
+
+    state.set_layer_z_index("image", -1);
+    let mut expected = Buffer::with_lines(vec!["▀▀", "••"]);
+    expected.set_style(expected.area, red);
+    expected.set_style(Rect::new(0, 1, 2, 1), Style::DEFAULT.fg(Color::Blue));
+    assert_eq!(draw(&mut state), expected);
+
+    state.set_layer_visible("image", false);
+    let mut expected = Buffer::with_lines(vec!["  ", "••"]);
+    expected.set_style(Rect::new(0, 1, 2, 1), Style::DEFAULT.fg(Color::Blue));
+    assert_eq!(draw(&mut state), expected);
+}
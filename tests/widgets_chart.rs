@@ -6,7 +6,10 @@ use tui::{
     style::{Color, Style},
     symbols,
     text::Span,
-    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType::Line},
+    widgets::{
+        Axis, AxisScale, Block, Borders, Chart, Dataset, GraphType, GraphType::Line, LabelFormat,
+        YAxis,
+    },
     Terminal,
 };
 
@@ -255,6 +258,25 @@ fn widgets_chart_handles_y_axis_labels_alignments() {
     );
 }
 
+#[test]
+fn widgets_chart_can_have_a_single_y_axis_label() {
+    let x_axis = Axis::default().labels(create_labels(&["A", "B"]));
+    let y_axis = Axis::default().labels(create_labels(&["C"]));
+    axis_test_case(
+        20,
+        5,
+        x_axis,
+        y_axis,
+        vec![
+            " │                  ",
+            " │                  ",
+            "C│                  ",
+            " └──────────────────",
+            " A                 B",
+        ],
+    );
+}
+
 #[test]
 fn widgets_chart_can_have_axis_with_zero_length_bounds() {
     let backend = TestBackend::new(100, 100);
@@ -619,3 +641,248 @@ fn widgets_chart_can_have_a_legend() {
 
     terminal.backend().assert_buffer(&expected);
 }
+
+#[test]
+fn widgets_chart_can_have_a_secondary_y_axis() {
+    let backend = TestBackend::new(20, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let datasets = vec![
+                Dataset::default()
+                    .marker(symbols::Marker::Dot)
+                    .data(&[(0.0, 0.0), (9.0, 10.0)]),
+                Dataset::default()
+                    .marker(symbols::Marker::Dot)
+                    .y_axis(YAxis::Secondary)
+                    .data(&[(4.0, 50.0)]),
+            ];
+            let chart = Chart::new(datasets)
+                .x_axis(
+                    Axis::default()
+                        .bounds([0.0, 9.0])
+                        .labels(create_labels(&["A", "B"])),
+                )
+                .y_axis(
+                    Axis::default()
+                        .bounds([0.0, 10.0])
+                        .labels(create_labels(&["0", "10"])),
+                )
+                .y_axis2(
+                    Axis::default()
+                        .bounds([0.0, 100.0])
+                        .labels(create_labels(&["0", "100"])),
+                );
+            f.render_widget(chart, f.size());
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec![
+        "10│            •│100",
+        "  │     •       │   ",
+        "0 │•            │0  ",
+        "  └─────────────┘   ",
+        "  A            B    ",
+    ]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_chart_can_fill_the_area_under_a_dataset() {
+    let backend = TestBackend::new(9, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let datasets = vec![Dataset::default()
+                .marker(symbols::Marker::Dot)
+                .graph_type(GraphType::Area)
+                .style(Style::DEFAULT.fg(Color::LightBlue))
+                .data(&[(0.0, 2.0), (4.0, -2.0), (8.0, 2.0)])];
+            let chart = Chart::new(datasets)
+                .x_axis(Axis::default().bounds([0.0, 8.0]))
+                .y_axis(Axis::default().bounds([-2.0, 2.0]));
+            f.render_widget(chart, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![
+        "•       •",
+        "••     ••",
+        "•••••••••",
+        "   •••   ",
+        "    •    ",
+    ]);
+    // The fill is dimmed and the line is drawn on top of it
+    for cell in expected.content.iter_mut().filter(|c| c.symbol == "•") {
+        cell.set_fg(Color::Blue);
+    }
+    for (x, y) in [
+        (0, 0),
+        (8, 0),
+        (1, 1),
+        (7, 1),
+        (2, 2),
+        (6, 2),
+        (3, 3),
+        (5, 3),
+        (4, 4),
+    ] {
+        expected.get_mut(x, y).set_fg(Color::LightBlue);
+    }
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_chart_can_have_a_logarithmic_axis() {
+    let backend = TestBackend::new(20, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let datasets = vec![Dataset::default().marker(symbols::Marker::Dot).data(&[
+                (0.0, 1.0),
+                (1.0, 10.0),
+                (2.0, 100.0),
+                (3.0, 0.0),
+            ])];
+            let chart = Chart::new(datasets)
+                .x_axis(Axis::default().bounds([0.0, 2.0]))
+                .y_axis(Axis::default().bounds([1.0, 100.0]).scale(AxisScale::Log10));
+            f.render_widget(chart, f.size());
+        })
+        .unwrap();
+    // labels are generated for each decade, and the point at zero is dropped
+    let expected = Buffer::with_lines(vec![
+        "100│               •",
+        "   │                ",
+        "10 │       •        ",
+        "   │                ",
+        "1  │•               ",
+    ]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_chart_can_stack_areas() {
+    let backend = TestBackend::new(5, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let datasets = vec![
+                Dataset::default()
+                    .marker(symbols::Marker::Dot)
+                    .graph_type(GraphType::StackedArea)
+                    .style(Style::DEFAULT.fg(Color::LightRed))
+                    .data(&[(0.0, 1.0), (4.0, 1.0)]),
+                Dataset::default()
+                    .marker(symbols::Marker::Dot)
+                    .graph_type(GraphType::StackedArea)
+                    .style(Style::DEFAULT.fg(Color::LightBlue))
+                    .data(&[(0.0, 2.0), (4.0, 2.0)]),
+            ];
+            let chart = Chart::new(datasets)
+                .x_axis(Axis::default().bounds([0.0, 4.0]))
+                .y_axis(Axis::default().bounds([0.0, 4.0]));
+            f.render_widget(chart, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["     ", "•••••", "•••••", "•••••", "•••••"]);
+    // The second area is filled from just above the line of the first one
+    for (y, color) in [
+        (1, Color::LightBlue),
+        (2, Color::Blue),
+        (3, Color::LightRed),
+        (4, Color::Red),
+    ] {
+        expected.set_style(Rect::new(0, y, 5, 1), Style::DEFAULT.fg(color));
+    }
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_chart_can_draw_steps() {
+    let backend = TestBackend::new(5, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let datasets = vec![Dataset::default()
+                .marker(symbols::Marker::Dot)
+                .graph_type(GraphType::Step)
+                .data(&[(0.0, 0.0), (2.0, 2.0), (4.0, 0.0)])];
+            let chart = Chart::new(datasets)
+                .x_axis(Axis::default().bounds([0.0, 4.0]))
+                .y_axis(Axis::default().bounds([0.0, 2.0]));
+            f.render_widget(chart, f.size());
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["  •••", "  • •", "••• •"]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_chart_can_place_labels_on_round_times() {
+    let backend = TestBackend::new(24, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let datasets = vec![Dataset::default()
+                .marker(symbols::Marker::Dot)
+                .data(&[(300.0, 0.5)])];
+            let chart = Chart::new(datasets)
+                .x_axis(
+                    Axis::default()
+                        .bounds([-60.0, 660.0])
+                        .auto_labels(LabelFormat::Time),
+                )
+                .y_axis(Axis::default().bounds([0.0, 1.0]));
+            f.render_widget(chart, f.size());
+        })
+        .unwrap();
+    // the labels are centered on 00:00, 00:05 and 00:10, the last one being kept in the area
+    let expected = Buffer::with_lines(vec![
+        "                        ",
+        "              •         ",
+        "                        ",
+        "     ───────────────────",
+        "     00:00  00:05  00:10",
+    ]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_chart_can_draw_points_with_symbols_and_sizes() {
+    let backend = TestBackend::new(5, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let datasets = vec![Dataset::default()
+                .marker(symbols::Marker::Dot)
+                .data(&[(0.0, 0.0), (2.0, 1.0), (4.0, 2.0)])
+                .point_symbols(&["x"])
+                .point_sizes(&[1.0, 0.0, 1.0])];
+            let chart = Chart::new(datasets)
+                .x_axis(Axis::default().bounds([0.0, 4.0]))
+                .y_axis(Axis::default().bounds([0.0, 2.0]));
+            f.render_widget(chart, f.size());
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["    █", "  ·  ", "x    "]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_chart_can_draw_sized_braille_points() {
+    let backend = TestBackend::new(4, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let datasets = vec![Dataset::default()
+                .marker(symbols::Marker::Braille)
+                .data(&[(1.0, 5.0), (6.0, 5.0)])
+                .point_sizes(&[0.5])];
+            let chart = Chart::new(datasets)
+                .x_axis(Axis::default().bounds([0.0, 7.0]))
+                .y_axis(Axis::default().bounds([0.0, 11.0]));
+            f.render_widget(chart, f.size());
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["    ", "⢴⠄ ⠄", "    "]);
+    terminal.backend().assert_buffer(&expected);
+}
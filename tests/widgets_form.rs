@@ -0,0 +1,86 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Color, Style},
+    widgets::{
+        Block, Borders, Button, ButtonState, Checkbox, CheckboxState, FocusState, RadioGroup,
+        RadioGroupState,
+    },
+    Terminal,
+};
+
+#[test]
+fn widgets_button_is_styled_when_focused_and_pressed() {
+    let backend = TestBackend::new(8, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = ButtonState::default();
+    state.press();
+    terminal
+        .draw(|f| {
+            let button = Button::new("OK")
+                .block(Block::default().borders(Borders::ALL))
+                .focused_style(Style::DEFAULT.fg(Color::Yellow))
+                .pressed_style(Style::DEFAULT.bg(Color::Blue))
+                .focused(true);
+            f.render_stateful_widget(button, f.size(), &mut state);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["┌──────┐", "│  OK  │", "└──────┘"]);
+    expected.set_style(
+        Rect::new(0, 0, 8, 3),
+        Style::DEFAULT.fg(Color::Yellow).bg(Color::Blue),
+    );
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_form_controls_follow_the_focus() {
+    let backend = TestBackend::new(24, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut focus = FocusState::new(2);
+    focus.focus_next();
+    focus.focus_next();
+    let mut checkbox = CheckboxState::default();
+    checkbox.toggle();
+    let mut radio = RadioGroupState::default();
+    radio.select(Some(0));
+    radio.highlight_previous(2, false);
+    let focused = Style::DEFAULT.fg(Color::Yellow);
+    terminal
+        .draw(|f| {
+            let checkbox_widget = Checkbox::new("Wrap")
+                .focused_style(focused)
+                .focused(focus.is_focused(0));
+            f.render_stateful_widget(checkbox_widget, Rect::new(0, 0, 24, 1), &mut checkbox);
+            let radio_widget = RadioGroup::new(vec!["Tabs".into(), "Spaces".into()])
+                .direction(Direction::Horizontal)
+                .focused_style(focused)
+                .focused(focus.is_focused(1));
+            f.render_stateful_widget(radio_widget, Rect::new(0, 2, 24, 1), &mut radio);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![
+        "[x] Wrap                ",
+        "                        ",
+        "(•) Tabs  ( ) Spaces    ",
+    ]);
+    expected.set_style(Rect::new(10, 2, 10, 1), focused);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_form_controls_should_not_panic_on_empty_areas() {
+    let backend = TestBackend::new(10, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let area = Rect::new(10, 0, 0, 1);
+            f.render_widget(Button::new("OK"), area);
+            f.render_stateful_widget(Checkbox::new("Check"), area, &mut CheckboxState::default());
+        })
+        .unwrap();
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["          "]));
+}
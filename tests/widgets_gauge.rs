@@ -2,10 +2,13 @@ use tui::{
     backend::TestBackend,
     buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Color, Gradient, Modifier, Style},
     symbols,
     text::Span,
-    widgets::{Block, Borders, Gauge, LineGauge},
+    widgets::{
+        Block, Borders, Gauge, GaugeDirection, GaugePrecision, GaugeSegment, LineGauge,
+        StackedGauge,
+    },
     Terminal,
 };
 
@@ -249,3 +252,213 @@ fn widgets_line_gauge_renders() {
     }
     terminal.backend().assert_buffer(&expected);
 }
+
+#[test]
+fn widgets_gauge_renders_with_sub_cell_precision() {
+    let backend = TestBackend::new(10, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let gauge = Gauge::default()
+                .precision(GaugePrecision::SubCell)
+                .label("")
+                .ratio(0.2625);
+            f.render_widget(gauge, f.size());
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec![format!("  {}       ", symbols::block::FIVE_EIGHTHS)]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_line_gauge_renders_with_sub_cell_precision() {
+    let backend = TestBackend::new(20, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let gauge = LineGauge::default()
+                .gauge_style(Style::DEFAULT.fg(Color::Green).bg(Color::White))
+                .precision(GaugePrecision::SubCell)
+                .ratio(0.43);
+            f.render_widget(gauge, Rect::new(0, 0, 20, 1));
+            let gauge = LineGauge::default()
+                .gauge_style(Style::DEFAULT.fg(Color::Green).bg(Color::White))
+                .line_set(symbols::line::THICK)
+                .precision(GaugePrecision::SubCell)
+                .ratio(0.43);
+            f.render_widget(gauge, Rect::new(0, 1, 20, 1));
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["43% ──────╴─────────", "43% ━━━━━━╸━━━━━━━━━"]);
+    for row in 0..2 {
+        for col in 4..11 {
+            expected.get_mut(col, row).set_fg(Color::Green);
+        }
+        for col in 11..20 {
+            expected.get_mut(col, row).set_fg(Color::White);
+        }
+    }
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_gauge_renders_gradient() {
+    let backend = TestBackend::new(5, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let gauge = Gauge::default()
+                .gauge_gradient(Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0)]))
+                .label("")
+                .ratio(0.6);
+            f.render_widget(gauge, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["     "]);
+    expected.get_mut(0, 0).set_bg(Color::Rgb(0, 0, 0));
+    expected.get_mut(1, 0).set_bg(Color::Rgb(50, 0, 0));
+    expected.get_mut(2, 0).set_bg(Color::Rgb(100, 0, 0));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_gauge_gradient_follows_the_direction() {
+    let render = |direction, width, height| {
+        let backend = TestBackend::new(width, height);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let gauge = Gauge::default()
+                    .gauge_gradient(Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0)]))
+                    .direction(direction)
+                    .label("")
+                    .ratio(0.6);
+                f.render_widget(gauge, f.size());
+            })
+            .unwrap();
+        terminal
+    };
+
+    let terminal = render(GaugeDirection::RightToLeft, 5, 1);
+    let mut expected = Buffer::with_lines(vec!["     "]);
+    expected.get_mut(4, 0).set_bg(Color::Rgb(0, 0, 0));
+    expected.get_mut(3, 0).set_bg(Color::Rgb(50, 0, 0));
+    expected.get_mut(2, 0).set_bg(Color::Rgb(100, 0, 0));
+    terminal.backend().assert_buffer(&expected);
+
+    let terminal = render(GaugeDirection::BottomToTop, 1, 5);
+    let mut expected = Buffer::with_lines(vec![" "; 5]);
+    expected.get_mut(0, 4).set_bg(Color::Rgb(0, 0, 0));
+    expected.get_mut(0, 3).set_bg(Color::Rgb(50, 0, 0));
+    expected.get_mut(0, 2).set_bg(Color::Rgb(100, 0, 0));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_gauge_can_fill_in_any_direction() {
+    let test_case = |direction, width, height, expected: Vec<&str>, filled: Rect| {
+        let backend = TestBackend::new(width, height);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let gauge = Gauge::default()
+                    .gauge_style(Style::DEFAULT.bg(Color::Blue).fg(Color::Red))
+                    .use_unicode(true)
+                    .direction(direction)
+                    .ratio(0.3)
+                    .label("");
+                f.render_widget(gauge, f.size());
+            })
+            .unwrap();
+        let mut expected = Buffer::with_lines(expected);
+        expected.set_style(expected.area, Style::DEFAULT.bg(Color::Blue).fg(Color::Red));
+        expected.set_style(filled, Style::DEFAULT.bg(Color::Red).fg(Color::Blue));
+        (terminal, expected)
+    };
+
+    // 30% of 5 cells: one full cell and a half-filled one
+    let (terminal, expected) = test_case(
+        GaugeDirection::BottomToTop,
+        2,
+        5,
+        vec!["  ", "  ", "  ", "▄▄", "  "],
+        Rect::new(0, 4, 2, 1),
+    );
+    terminal.backend().assert_buffer(&expected);
+
+    let (terminal, mut expected) = test_case(
+        GaugeDirection::TopToBottom,
+        2,
+        5,
+        vec!["  ", "▄▄", "  ", "  ", "  "],
+        Rect::new(0, 0, 2, 1),
+    );
+    for x in 0..2 {
+        expected
+            .get_mut(x, 1)
+            .set_fg(Color::Blue)
+            .set_bg(Color::Red);
+    }
+    terminal.backend().assert_buffer(&expected);
+
+    let (terminal, mut expected) = test_case(
+        GaugeDirection::RightToLeft,
+        5,
+        1,
+        vec!["   ▌ "],
+        Rect::new(4, 0, 1, 1),
+    );
+    expected
+        .get_mut(3, 0)
+        .set_fg(Color::Blue)
+        .set_bg(Color::Red);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_stacked_gauge_renders_segments_and_legend() {
+    let backend = TestBackend::new(20, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let gauge = StackedGauge::new(vec![
+                GaugeSegment::new("used", 0.4, Color::Red),
+                GaugeSegment::new("cached", 0.25, Color::Yellow),
+                GaugeSegment::new("free", 0.35, Color::Green),
+            ])
+            .legend(true);
+            f.render_widget(gauge, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["  used        free  ", "■ used  ■ cached  ■ "]);
+    expected.set_style(Rect::new(0, 0, 8, 1), Style::DEFAULT.bg(Color::Red));
+    expected.set_style(Rect::new(8, 0, 5, 1), Style::DEFAULT.bg(Color::Yellow));
+    expected.set_style(Rect::new(13, 0, 7, 1), Style::DEFAULT.bg(Color::Green));
+    expected.get_mut(0, 1).set_fg(Color::Red);
+    expected.get_mut(1, 1).set_fg(Color::Red);
+    expected.get_mut(8, 1).set_fg(Color::Yellow);
+    expected.get_mut(9, 1).set_fg(Color::Yellow);
+    expected.get_mut(18, 1).set_fg(Color::Green);
+    expected.get_mut(19, 1).set_fg(Color::Green);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_line_gauge_renders_gradient() {
+    let backend = TestBackend::new(6, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let gauge = LineGauge::default()
+                .gauge_gradient(Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0)]))
+                .label("")
+                .ratio(0.6);
+            f.render_widget(gauge, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![" ─────"]);
+    expected.get_mut(1, 0).set_fg(Color::Rgb(0, 0, 0));
+    expected.get_mut(2, 0).set_fg(Color::Rgb(50, 0, 0));
+    expected.get_mut(3, 0).set_fg(Color::Rgb(100, 0, 0));
+    terminal.backend().assert_buffer(&expected);
+}
@@ -0,0 +1,38 @@
+use tui::backend::TestBackend;
+use tui::buffer::Buffer;
+use tui::layout::Rect;
+use tui::style::{Color, Gradient, Style};
+use tui::text::Span;
+use tui::widgets::{Heatmap, LabelFormat};
+use tui::Terminal;
+
+#[test]
+fn widgets_heatmap_can_render_labeled_cells() {
+    let backend = TestBackend::new(8, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let data = vec![vec![0.0, 1.0], vec![2.0, f64::NAN]];
+    terminal
+        .draw(|f| {
+            let heatmap = Heatmap::new(&data)
+                .gradient(Gradient::new([
+                    Color::Rgb(0, 0, 0),
+                    Color::Rgb(200, 200, 200),
+                ]))
+                .cell_width(3)
+                .cell_labels(LabelFormat::Decimal(0))
+                .row_labels(vec![Span::raw("a"), Span::raw("b")])
+                .column_labels(vec![Span::raw("x"), Span::raw("y")]);
+            f.render_widget(heatmap, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["a  0  1 ", "b  2    ", "  x  y  "]);
+    for (x, y, color) in [
+        (2, 0, Color::Rgb(0, 0, 0)),
+        (5, 0, Color::Rgb(100, 100, 100)),
+        (2, 1, Color::Rgb(200, 200, 200)),
+    ] {
+        expected.set_style(Rect::new(x, y, 3, 1), Style::DEFAULT.bg(color));
+        expected.get_mut(x + 1, y).set_fg(color.contrast_fg());
+    }
+    terminal.backend().assert_buffer(&expected);
+}
@@ -0,0 +1,45 @@
+use tui::backend::TestBackend;
+use tui::buffer::Buffer;
+use tui::layout::Direction;
+use tui::widgets::{Histogram, LabelFormat};
+use tui::Terminal;
+
+#[test]
+fn widgets_histogram_can_render_binned_samples() {
+    let backend = TestBackend::new(9, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let samples = [0.5, 1.5, 2.5, 3.0, 4.5, 5.0, 5.5];
+    terminal
+        .draw(|f| {
+            let histogram = Histogram::new(&samples)
+                .range([0.0, 6.0])
+                .bin_width(2.0)
+                .label_format(LabelFormat::Decimal(0))
+                .bar_width(2)
+                .bar_gap(1);
+            f.render_widget(histogram, f.size());
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["      ██ ", "██ ██ ██ ", "2█ 2█ 3█ ", "0  2  4  "]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_histogram_can_render_horizontally() {
+    let backend = TestBackend::new(8, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let samples = [1.0, 2.0, 2.0, 3.0];
+    terminal
+        .draw(|f| {
+            let histogram = Histogram::new(&samples)
+                .bins(2)
+                .label_format(LabelFormat::Decimal(0))
+                .bar_width(1)
+                .bar_gap(0)
+                .direction(Direction::Horizontal);
+            f.render_widget(histogram, f.size());
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["1 1█    ", "2 3█████", "        "]);
+    terminal.backend().assert_buffer(&expected);
+}
@@ -0,0 +1,75 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Input, InputState},
+    Terminal,
+};
+
+fn render(input: Input, state: &mut InputState, width: u16, height: u16) -> Buffer {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            f.render_stateful_widget(input, f.size(), state);
+        })
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn widgets_input_renders_placeholder_when_empty() {
+    let mut state = InputState::default();
+    let input = Input::default()
+        .block(Block::default().borders(Borders::ALL))
+        .placeholder("Name");
+    let buffer = render(input, &mut state, 8, 3);
+    let mut expected = Buffer::with_lines(vec!["┌──────┐", "│Name  │", "└──────┘"]);
+    expected.set_style(
+        Rect::new(1, 1, 4, 1),
+        Style::DEFAULT.add_modifier(Modifier::DIM),
+    );
+    assert_eq!(buffer, expected);
+    assert_eq!(state.screen_cursor(), Some((1, 1)));
+}
+
+#[test]
+fn widgets_input_masks_value() {
+    let mut state = InputState::new("secret");
+    let buffer = render(Input::default().mask('*'), &mut state, 8, 1);
+    assert_eq!(buffer, Buffer::with_lines(vec!["******  "]));
+    assert_eq!(state.screen_cursor(), Some((6, 0)));
+}
+
+#[test]
+fn widgets_input_scrolls_to_the_cursor() {
+    let mut state = InputState::new("Hello, World");
+    let buffer = render(Input::default(), &mut state, 5, 1);
+    assert_eq!(buffer, Buffer::with_lines(vec!["orld "]));
+    assert_eq!(state.screen_cursor(), Some((4, 0)));
+
+    state.move_home();
+    let buffer = render(Input::default(), &mut state, 5, 1);
+    assert_eq!(buffer, Buffer::with_lines(vec!["Hello"]));
+    assert_eq!(state.screen_cursor(), Some((0, 0)));
+}
+
+#[test]
+fn widgets_input_applies_invalid_style() {
+    let input = || {
+        Input::default()
+            .validator(|value| value.chars().all(|c| c.is_ascii_digit()))
+            .invalid_style(Style::DEFAULT.fg(Color::Red))
+    };
+
+    let mut state = InputState::new("42");
+    let buffer = render(input(), &mut state, 4, 1);
+    assert_eq!(buffer, Buffer::with_lines(vec!["42  "]));
+
+    state.insert_char('a');
+    let buffer = render(input(), &mut state, 4, 1);
+    let mut expected = Buffer::with_lines(vec!["42a "]);
+    expected.set_style(Rect::new(0, 0, 4, 1), Style::DEFAULT.fg(Color::Red));
+    assert_eq!(buffer, expected);
+}
@@ -1,11 +1,11 @@
 use tui::{
     backend::TestBackend,
     buffer::Buffer,
-    layout::Rect,
+    layout::{Direction, Rect},
     style::{Color, Style},
     symbols,
     text::Spans,
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, LazyList, List, ListItem, ListState},
     Terminal,
 };
 
@@ -198,3 +198,148 @@ fn widgets_list_should_repeat_highlight_symbol() {
     }
     terminal.backend().assert_buffer(&expected);
 }
+
+#[test]
+fn widgets_list_should_render_the_selection() {
+    let backend = TestBackend::new(10, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = ListState::default();
+    state.select(Some(0));
+    state.toggle_selection(0);
+    state.extend_selection_to(1);
+    state.toggle_selection(3);
+    state.select(Some(2));
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let items = vec![
+                ListItem::new("Item 1"),
+                ListItem::new("Item 2"),
+                ListItem::new("Item 3"),
+                ListItem::new("Item 4"),
+            ];
+            let list = List::new(items)
+                .highlight_style(Style::DEFAULT.fg(Color::Yellow))
+                .highlight_symbol(">")
+                .selection_style(Style::DEFAULT.bg(Color::Blue))
+                .selection_symbol("* ");
+            f.render_stateful_widget(list, size, &mut state);
+        })
+        .unwrap();
+    let mut expected =
+        Buffer::with_lines(vec![" * Item 1 ", " * Item 2 ", ">  Item 3 ", " * Item 4 "]);
+    for y in [0, 1, 3] {
+        expected.set_style(Rect::new(0, y, 10, 1), Style::DEFAULT.bg(Color::Blue));
+    }
+    expected.set_style(Rect::new(0, 2, 10, 1), Style::DEFAULT.fg(Color::Yellow));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_list_should_render_items_horizontally() {
+    let backend = TestBackend::new(15, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = ListState::default();
+    let mut render = |state: &mut ListState| {
+        terminal
+            .draw(|f| {
+                let size = f.size();
+                let items = vec![
+                    ListItem::new("One"),
+                    ListItem::new("Two"),
+                    ListItem::new("Three"),
+                    ListItem::new("Four"),
+                ];
+                let list = List::new(items)
+                    .direction(Direction::Horizontal)
+                    .separator("|")
+                    .highlight_style(Style::DEFAULT.fg(Color::Yellow))
+                    .highlight_symbol(">");
+                f.render_stateful_widget(list, size, state);
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    };
+
+    state.select(Some(1));
+    let mut expected = Buffer::with_lines(vec![" One|>Two      "]);
+    expected.set_style(Rect::new(5, 0, 4, 1), Style::DEFAULT.fg(Color::Yellow));
+    assert_eq!(render(&mut state), expected);
+
+    // Scrolls to keep the selected item visible
+    state.select(Some(3));
+    let mut expected = Buffer::with_lines(vec![" Three|>Four   "]);
+    expected.set_style(Rect::new(7, 0, 5, 1), Style::DEFAULT.fg(Color::Yellow));
+    assert_eq!(render(&mut state), expected);
+    assert_eq!(state.offset(), 2);
+}
+
+#[test]
+fn widgets_lazy_list_should_only_request_the_visible_items() {
+    let backend = TestBackend::new(12, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = ListState::default();
+    let requested = std::cell::RefCell::new(Vec::new());
+    let mut render = |state: &mut ListState| {
+        terminal
+            .draw(|f| {
+                let size = f.size();
+                let list = LazyList::new(1_000_000, |range| {
+                    requested.borrow_mut().push(range.clone());
+                    range.map(|i| ListItem::new(i.to_string())).collect()
+                })
+                .highlight_symbol(">");
+                f.render_stateful_widget(list, size, state);
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    };
+
+    assert_eq!(
+        render(&mut state),
+        Buffer::with_lines(vec!["0           ", "1           ", "2           "])
+    );
+    state.select(Some(500_000));
+    assert_eq!(
+        render(&mut state),
+        Buffer::with_lines(vec![" 499998     ", " 499999     ", ">500000     "])
+    );
+    assert_eq!(state.offset(), 499_998);
+    assert_eq!(*requested.borrow(), [0..3, 0..3, 499_998..500_001]);
+}
+
+#[test]
+fn widgets_list_should_keep_the_scroll_padding_visible() {
+    let backend = TestBackend::new(10, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = ListState::default();
+    let mut render = |state: &mut ListState| {
+        terminal
+            .draw(|f| {
+                let size = f.size();
+                let items: Vec<ListItem> = (1..=6)
+                    .map(|i| ListItem::new(format!("Item {}", i)))
+                    .collect();
+                let list = List::new(items).highlight_symbol(">").scroll_padding(1);
+                f.render_stateful_widget(list, size, state);
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    };
+
+    state.select(Some(3));
+    assert_eq!(
+        render(&mut state),
+        Buffer::with_lines(vec![" Item 3   ", ">Item 4   ", " Item 5   "])
+    );
+    state.select(Some(5));
+    assert_eq!(
+        render(&mut state),
+        Buffer::with_lines(vec![" Item 4   ", " Item 5   ", ">Item 6   "])
+    );
+    state.select(Some(3));
+    assert_eq!(
+        render(&mut state),
+        Buffer::with_lines(vec![" Item 3   ", ">Item 4   ", " Item 5   "])
+    );
+}
@@ -1,9 +1,10 @@
 use tui::{
     backend::TestBackend,
     buffer::Buffer,
-    layout::Alignment,
+    layout::{Alignment, Rect, VerticalAlignment},
+    style::{Color, Style},
     text::{Span, Spans, Text},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, LineNumbers, Paragraph, Truncate, TruncatePosition, Wrap},
     Terminal,
 };
 
@@ -184,12 +185,14 @@ fn widgets_paragraph_can_scroll_horizontally() {
         terminal.backend().assert_buffer(&expected);
     };
 
+    // Wide chars cut by the edges are replaced by spaces, keeping the columns of all the lines
+    // aligned
     test_case(
         Alignment::Left,
         (0, 7),
         Buffer::with_lines(vec![
             "┌──────────────────┐",
-            "│在可以水平滚动了！│",
+            "│ 可以水平滚动了！ │",
             "│ph can scroll hori│",
             "│ine               │",
             "│                  │",
@@ -218,3 +221,313 @@ fn widgets_paragraph_can_scroll_horizontally() {
         ]),
     );
 }
+
+#[test]
+fn widgets_paragraph_can_display_line_numbers() {
+    let text = "Line one\nLine two is wrapped\n\nLine four";
+    let test_case = |line_numbers, expected: Vec<&str>| {
+        let backend = TestBackend::new(12, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let paragraph = Paragraph::new(text)
+                    .line_numbers(line_numbers)
+                    .line_number_style(Style::DEFAULT.fg(Color::DarkGray))
+                    .selected_line(1)
+                    .wrap(Wrap { trim: true });
+                f.render_widget(paragraph, f.size());
+            })
+            .unwrap();
+        let mut expected = Buffer::with_lines(expected);
+        expected.set_style(Rect::new(0, 0, 2, 6), Style::DEFAULT.fg(Color::DarkGray));
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    test_case(
+        LineNumbers::Absolute,
+        vec![
+            "1 Line one  ",
+            "2 Line two  ",
+            "  is wrapped",
+            "3           ",
+            "4 Line four ",
+            "            ",
+        ],
+    );
+    test_case(
+        LineNumbers::Relative,
+        vec![
+            "1 Line one  ",
+            "0 Line two  ",
+            "  is wrapped",
+            "1           ",
+            "2 Line four ",
+            "            ",
+        ],
+    );
+}
+
+#[test]
+fn widgets_paragraph_line_numbers_follow_vertical_scroll() {
+    let backend = TestBackend::new(8, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let text: Vec<Spans> = (1..=12).map(|i| Spans::from(format!("L{}", i))).collect();
+    terminal
+        .draw(|f| {
+            let paragraph = Paragraph::new(text)
+                .line_numbers(LineNumbers::Absolute)
+                .scroll((9, 0));
+            f.render_widget(paragraph, f.size());
+        })
+        .unwrap();
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["10 L10  ", "11 L11  "]));
+}
+
+#[test]
+fn widgets_paragraph_measures_its_content() {
+    let text = "The library is based on the principle";
+    let paragraph = Paragraph::new(text).block(Block::default().borders(Borders::ALL));
+    assert_eq!(paragraph.line_count(20), 3);
+    assert_eq!(paragraph.line_width(), 39);
+
+    let paragraph = paragraph.wrap(Wrap { trim: true });
+    assert_eq!(paragraph.line_count(20), 5);
+    assert_eq!(paragraph.line_count(2), 2);
+
+    let paragraph = paragraph.line_numbers(LineNumbers::Absolute);
+    assert_eq!(paragraph.line_count(12), 8);
+    assert_eq!(paragraph.line_width(), 41);
+
+    // The paragraph fits exactly in an area of the measured size
+    let backend = TestBackend::new(12, 8);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| f.render_widget(paragraph, f.size()))
+        .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "┌──────────┐",
+        "│1 The     │",
+        "│  library │",
+        "│  is based│",
+        "│  on the  │",
+        "│  principl│",
+        "│  e       │",
+        "└──────────┘",
+    ]));
+}
+
+#[test]
+fn widgets_paragraph_can_align_vertically() {
+    let test_case = |alignment, expected| {
+        let backend = TestBackend::new(12, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let paragraph = Paragraph::new("Nothing to\nshow")
+                    .block(Block::default().borders(Borders::ALL))
+                    .alignment(Alignment::Center)
+                    .vertical_alignment(alignment);
+                f.render_widget(paragraph, f.size());
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    test_case(
+        VerticalAlignment::Top,
+        Buffer::with_lines(vec![
+            "┌──────────┐",
+            "│Nothing to│",
+            "│   show   │",
+            "│          │",
+            "│          │",
+            "└──────────┘",
+        ]),
+    );
+    test_case(
+        VerticalAlignment::Center,
+        Buffer::with_lines(vec![
+            "┌──────────┐",
+            "│          │",
+            "│Nothing to│",
+            "│   show   │",
+            "│          │",
+            "└──────────┘",
+        ]),
+    );
+    test_case(
+        VerticalAlignment::Bottom,
+        Buffer::with_lines(vec![
+            "┌──────────┐",
+            "│          │",
+            "│          │",
+            "│Nothing to│",
+            "│   show   │",
+            "└──────────┘",
+        ]),
+    );
+}
+
+#[test]
+fn widgets_paragraph_can_truncate_with_an_ellipsis() {
+    let test_case = |position, expected| {
+        let backend = TestBackend::new(10, 2);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let text = vec![
+                    Spans::from("/home/user/main.rs"),
+                    Spans::from(vec![
+                        Span::styled("abcdef", Style::DEFAULT.fg(Color::Red)),
+                        Span::raw("ghijkl"),
+                    ]),
+                ];
+                let paragraph = Paragraph::new(text).truncate(Truncate {
+                    ellipsis: "…",
+                    position,
+                });
+                f.render_widget(paragraph, f.size());
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    let mut expected = Buffer::with_lines(vec!["…r/main.rs", "…defghijkl"]);
+    expected.set_style(Rect::new(1, 1, 3, 1), Style::DEFAULT.fg(Color::Red));
+    test_case(TruncatePosition::Start, expected);
+    let mut expected = Buffer::with_lines(vec!["/home…n.rs", "abcde…ijkl"]);
+    expected.set_style(Rect::new(0, 1, 5, 1), Style::DEFAULT.fg(Color::Red));
+    test_case(TruncatePosition::Middle, expected);
+    let mut expected = Buffer::with_lines(vec!["/home/use…", "abcdefghi…"]);
+    expected.set_style(Rect::new(0, 1, 6, 1), Style::DEFAULT.fg(Color::Red));
+    test_case(TruncatePosition::End, expected);
+}
+
+#[test]
+fn widgets_paragraph_line_number_gutter_can_be_widened() {
+    let backend = TestBackend::new(12, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let paragraph = Paragraph::new("Line one\nLine two is wrapped")
+                .line_numbers(LineNumbers::Absolute)
+                .line_number_width(3)
+                .wrap(Wrap { trim: true });
+            f.render_widget(paragraph, f.size());
+        })
+        .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "  1 Line one",
+        "  2 Line two",
+        "    is      ",
+    ]));
+}
+
+#[test]
+fn widgets_paragraph_can_indent_wrapped_lines() {
+    let backend = TestBackend::new(12, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let paragraph = Paragraph::new("- One two three four\n- Five")
+                .wrap(Wrap { trim: true })
+                .first_line_indent(1)
+                .hanging_indent(3);
+            f.render_widget(paragraph, f.size());
+        })
+        .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        " - One two  ",
+        "   three    ",
+        "   four     ",
+        " - Five     ",
+        "            ",
+    ]));
+    assert_eq!(
+        Paragraph::new("- One two three four\n- Five")
+            .wrap(Wrap { trim: true })
+            .first_line_indent(1)
+            .hanging_indent(3)
+            .line_count(12),
+        4
+    );
+}
+
+#[test]
+fn widgets_paragraph_hanging_indent_keeps_wrapped_lines_in_the_area() {
+    let backend = TestBackend::new(12, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let paragraph = Paragraph::new("x AAAAAAAAA\nB")
+                .wrap(Wrap { trim: true })
+                .hanging_indent(5);
+            f.render_widget(paragraph, Rect::new(0, 0, 10, 5));
+        })
+        .unwrap();
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "x           ",
+        "     AAAAA  ",
+        "     AAAA   ",
+        "B           ",
+        "            ",
+    ]));
+}
+
+#[test]
+fn widgets_paragraph_can_highlight_matches() {
+    let pattern = String::from("or");
+    let backend = TestBackend::new(10, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let text = vec![
+                Spans::from(vec![
+                    Span::styled("To b", Style::DEFAULT.fg(Color::Red)),
+                    Span::raw("e, or not"),
+                ]),
+                Spans::from("to be"),
+            ];
+            let paragraph = Paragraph::new(text)
+                .highlight("be", Style::DEFAULT.bg(Color::Yellow))
+                .highlight_matches(
+                    |line| {
+                        line.find(pattern.as_str())
+                            .map(|i| i..i + pattern.len())
+                            .into_iter()
+                            .collect()
+                    },
+                    Style::DEFAULT.bg(Color::Blue),
+                )
+                .wrap(Wrap { trim: true });
+            f.render_widget(paragraph, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["To be, or ", "not       ", "to be     "]);
+    expected.set_style(Rect::new(0, 0, 4, 1), Style::DEFAULT.fg(Color::Red));
+    expected.set_style(Rect::new(3, 0, 2, 1), Style::DEFAULT.bg(Color::Yellow));
+    expected.set_style(Rect::new(7, 0, 2, 1), Style::DEFAULT.bg(Color::Blue));
+    expected.set_style(Rect::new(3, 2, 2, 1), Style::DEFAULT.bg(Color::Yellow));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_paragraph_highlights_overlapping_matches() {
+    let backend = TestBackend::new(13, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let paragraph = Paragraph::new("To be, or not").highlight_matches(
+                |_| vec![3..9, 4..6, 11..13, 10..12],
+                Style::DEFAULT.bg(Color::Yellow),
+            );
+            f.render_widget(paragraph, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["To be, or not"]);
+    expected.set_style(Rect::new(3, 0, 6, 1), Style::DEFAULT.bg(Color::Yellow));
+    expected.set_style(Rect::new(10, 0, 3, 1), Style::DEFAULT.bg(Color::Yellow));
+    terminal.backend().assert_buffer(&expected);
+}
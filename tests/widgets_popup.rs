@@ -0,0 +1,40 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph, Popup},
+    Terminal,
+};
+
+#[test]
+fn widgets_popup_is_centered_over_a_backdrop() {
+    let backend = TestBackend::new(12, 6);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            f.render_widget(Paragraph::new(vec!["x".repeat(12).into(); 6]), size);
+            let popup = Popup::new(Paragraph::new("Hi"))
+                .block(Block::default().borders(Borders::ALL))
+                .width(Constraint::Length(6))
+                .height(Constraint::Length(3))
+                .backdrop_style(Style::DEFAULT.fg(Color::Gray))
+                .shadow_style(Style::DEFAULT.bg(Color::Black));
+            f.render_widget(popup, size);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![
+        "xxxxxxxxxxxx",
+        "xxx┌────┐xxx",
+        "xxx│Hi  │xxx",
+        "xxx└────┘xxx",
+        "xxxxxxxxxxxx",
+        "xxxxxxxxxxxx",
+    ]);
+    expected.set_style(Rect::new(0, 0, 12, 6), Style::DEFAULT.fg(Color::Gray));
+    expected.set_style(Rect::new(3, 1, 6, 3), Style::DEFAULT.fg(Color::Reset));
+    expected.set_style(Rect::new(9, 2, 1, 3), Style::DEFAULT.bg(Color::Black));
+    expected.set_style(Rect::new(4, 4, 6, 1), Style::DEFAULT.bg(Color::Black));
+    terminal.backend().assert_buffer(&expected);
+}
@@ -0,0 +1,110 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    symbols,
+    widgets::{List, ListItem, ListState, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Terminal,
+};
+
+#[test]
+fn widgets_scrollbar_vertical_thumb_follows_position() {
+    let test_case = |position: usize, expected: Buffer| {
+        let backend = TestBackend::new(3, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let mut state = ScrollbarState::new(10)
+                    .viewport_length(4)
+                    .position(position);
+                f.render_stateful_widget(
+                    Scrollbar::new(ScrollbarOrientation::VerticalRight),
+                    f.size(),
+                    &mut state,
+                );
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    test_case(
+        0,
+        Buffer::with_lines(vec!["  ↑", "  █", "  █", "  │", "  │", "  ↓"]),
+    );
+    test_case(
+        3,
+        Buffer::with_lines(vec!["  ↑", "  │", "  █", "  █", "  │", "  ↓"]),
+    );
+    test_case(
+        6,
+        Buffer::with_lines(vec!["  ↑", "  │", "  │", "  █", "  █", "  ↓"]),
+    );
+    // Positions past the end keep the thumb at the end of the track
+    test_case(
+        42,
+        Buffer::with_lines(vec!["  ↑", "  │", "  │", "  █", "  █", "  ↓"]),
+    );
+}
+
+#[test]
+fn widgets_scrollbar_horizontal_without_arrows() {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let mut state = ScrollbarState::new(20).position(5).viewport_length(10);
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .thumb_style(Style::DEFAULT.fg(Color::Yellow));
+            f.render_stateful_widget(scrollbar, f.size(), &mut state);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["          ", "───█████──"]);
+    expected.set_style(Rect::new(3, 1, 5, 1), Style::DEFAULT.fg(Color::Yellow));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_scrollbar_custom_symbols() {
+    let backend = TestBackend::new(1, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let mut state = ScrollbarState::new(0);
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalLeft)
+                .symbols(symbols::scrollbar::DOUBLE_VERTICAL)
+                .thumb_symbol("║")
+                .track_symbol(" ");
+            f.render_stateful_widget(scrollbar, f.size(), &mut state);
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["▲", "║", "║", "▼"]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_scrollbar_attached_to_list() {
+    let backend = TestBackend::new(6, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let items: Vec<ListItem> = (0..8)
+        .map(|i| ListItem::new(format!("Item{}", i)))
+        .collect();
+    let mut list_state = ListState::default();
+    list_state.select(Some(7));
+    terminal
+        .draw(|f| {
+            let len = items.len();
+            f.render_stateful_widget(List::new(items), f.size(), &mut list_state);
+            let mut state = ScrollbarState::for_list(&list_state, len).viewport_length(4);
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None);
+            f.render_stateful_widget(scrollbar, f.size(), &mut state);
+        })
+        .unwrap();
+    assert_eq!(list_state.offset(), 4);
+    let expected = Buffer::with_lines(vec!["Item4│", "Item5│", "Item6█", "Item7█"]);
+    terminal.backend().assert_buffer(&expected);
+}
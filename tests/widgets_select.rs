@@ -0,0 +1,76 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::Spans,
+    widgets::{Paragraph, Select, SelectState},
+    Terminal,
+};
+
+fn render(state: &mut SelectState, area: Rect) -> Buffer {
+    let backend = TestBackend::new(12, 6);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            // Widgets around the select, covered by its list of options
+            let lines: Vec<Spans> = (0..6)
+                .map(|y| if y == area.y { "" } else { "xxxxxxxxxxxx" }.into())
+                .collect();
+            f.render_widget(Paragraph::new(lines), f.size());
+            let select = Select::new(vec!["Red".into(), "Green".into(), "Blue".into()])
+                .placeholder("Color")
+                .highlight_style(Style::DEFAULT.fg(Color::Yellow));
+            f.render_stateful_widget(select, area, state);
+        })
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn widgets_select_shows_the_choice_or_the_placeholder() {
+    let mut state = SelectState::default();
+    let expected = Buffer::with_lines(vec![
+        "Color      ▼",
+        "xxxxxxxxxxxx",
+        "xxxxxxxxxxxx",
+        "xxxxxxxxxxxx",
+        "xxxxxxxxxxxx",
+        "xxxxxxxxxxxx",
+    ]);
+    assert_eq!(render(&mut state, Rect::new(0, 0, 12, 1)), expected);
+}
+
+#[test]
+fn widgets_select_opens_a_list_of_options() {
+    let mut state = SelectState::default();
+    state.select(Some(1));
+    state.open();
+    state.highlight_next(3, false);
+    let mut expected = Buffer::with_lines(vec![
+        "Green      ▲",
+        "┌──────────┐",
+        "│Red       │",
+        "│Green     │",
+        "│Blue      │",
+        "└──────────┘",
+    ]);
+    expected.set_style(Rect::new(1, 4, 10, 1), Style::DEFAULT.fg(Color::Yellow));
+    assert_eq!(render(&mut state, Rect::new(0, 0, 12, 1)), expected);
+
+    // Drawn above when there is more room there
+    let mut expected = Buffer::with_lines(vec![
+        "┌──────────┐",
+        "│Red       │",
+        "│Green     │",
+        "│Blue      │",
+        "└──────────┘",
+        "Green      ▲",
+    ]);
+    expected.set_style(Rect::new(1, 3, 10, 1), Style::DEFAULT.fg(Color::Yellow));
+    assert_eq!(render(&mut state, Rect::new(0, 5, 12, 1)), expected);
+
+    state.confirm();
+    assert_eq!(state.selected(), Some(2));
+    assert!(!state.is_open());
+}
@@ -0,0 +1,56 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Color, Style},
+    widgets::{LabelFormat, Slider, SliderState},
+    Terminal,
+};
+
+fn render(slider: Slider, state: &mut SliderState, width: u16, height: u16) -> Buffer {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| f.render_stateful_widget(slider, f.size(), state))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn widgets_slider_places_the_thumb_on_the_track() {
+    let mut state = SliderState::new(0.0, 100.0).step(25.0);
+    state.increment();
+    let slider = Slider::default()
+        .label_format(LabelFormat::Decimal(0))
+        .thumb_style(Style::DEFAULT.fg(Color::Yellow));
+    let mut expected = Buffer::with_lines(vec!["──●────── 25"]);
+    expected.set_style(Rect::new(2, 0, 1, 1), Style::DEFAULT.fg(Color::Yellow));
+    assert_eq!(render(slider, &mut state, 12, 1), expected);
+}
+
+#[test]
+fn widgets_slider_can_be_vertical() {
+    let mut state = SliderState::new(-1.0, 1.0);
+    state.set_value(2.0);
+    let slider = Slider::default()
+        .direction(Direction::Vertical)
+        .label_format(LabelFormat::Decimal(1));
+    let expected = Buffer::with_lines(vec!["●  ", "│  ", "│  ", "1.0"]);
+    assert_eq!(render(slider, &mut state, 3, 4), expected);
+}
+
+#[test]
+fn widgets_slider_skips_the_label_when_it_does_not_fit() {
+    let mut state = SliderState::new(0.0, 100.0);
+    let backend = TestBackend::new(10, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let slider = Slider::default().label_format(LabelFormat::Decimal(0));
+            f.render_stateful_widget(slider, Rect::new(9, 0, 1, 1), &mut state);
+        })
+        .unwrap();
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["          "]));
+}
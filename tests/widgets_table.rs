@@ -1,10 +1,10 @@
 use tui::{
     backend::TestBackend,
     buffer::Buffer,
-    layout::Constraint,
+    layout::{Constraint, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    widgets::{Block, Borders, Cell, Row, SortDirection, Table, TableState, Wrap},
     Terminal,
 };
 
@@ -819,3 +819,291 @@ fn widgets_table_should_clamp_offset_if_rows_are_removed() {
     ]);
     terminal.backend().assert_buffer(&expected);
 }
+
+#[test]
+fn widgets_table_shows_sort_indicator_in_header() {
+    let test_case = |sortable: Option<&[usize]>, expected| {
+        let backend = TestBackend::new(15, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut state = TableState::default();
+        state.sort(1, SortDirection::Descending);
+        terminal
+            .draw(|f| {
+                let size = f.size();
+                let mut table =
+                    Table::new(vec![Row::new(vec!["b", "2"]), Row::new(vec!["a", "1"])])
+                        .header(Row::new(vec!["Name", "Size"]))
+                        .widths(&[Constraint::Length(6), Constraint::Length(6)]);
+                if let Some(sortable) = sortable {
+                    table = table.sortable_columns(sortable);
+                }
+                f.render_stateful_widget(table, size, &mut state);
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    test_case(
+        None,
+        Buffer::with_lines(vec![
+            "Name   Size ▼  ",
+            "b      2       ",
+            "a      1       ",
+        ]),
+    );
+    test_case(
+        Some(&[0]),
+        Buffer::with_lines(vec![
+            "Name   Size    ",
+            "b      2       ",
+            "a      1       ",
+        ]),
+    );
+}
+
+#[test]
+fn widgets_table_can_scroll_horizontally_with_frozen_columns() {
+    let test_case = |col_offset, expected: Buffer, expected_offset| {
+        let backend = TestBackend::new(11, 2);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut state = TableState::default();
+        state.set_col_offset(col_offset);
+        terminal
+            .draw(|f| {
+                let size = f.size();
+                let table = Table::new(vec![Row::new(vec!["a0", "a1", "a2", "a3"])])
+                    .header(Row::new(vec!["c0", "c1", "c2", "c3"]))
+                    .widths(&[Constraint::Length(3); 4])
+                    .frozen_columns(1);
+                f.render_stateful_widget(table, size, &mut state);
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+        assert_eq!(state.col_offset(), expected_offset);
+    };
+
+    test_case(0, Buffer::with_lines(vec!["c0  c1  c2 ", "a0  a1  a2 "]), 0);
+    test_case(1, Buffer::with_lines(vec!["c0  c2  c3 ", "a0  a2  a3 "]), 1);
+    // the offset is clamped to the last column
+    test_case(
+        10,
+        Buffer::with_lines(vec!["c0  c3     ", "a0  a3     "]),
+        2,
+    );
+}
+
+#[test]
+fn widgets_table_keeps_scroll_padding_around_selection() {
+    let test_case = |scroll_padding, expected| {
+        let backend = TestBackend::new(5, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut state = TableState::default();
+        state.select(Some(2));
+        terminal
+            .draw(|f| {
+                let size = f.size();
+                let table = Table::new((0..6).map(|i| Row::new(vec![format!("Row{}", i)])))
+                    .widths(&[Constraint::Length(5)])
+                    .scroll_padding(scroll_padding);
+                f.render_stateful_widget(table, size, &mut state);
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    test_case(0, Buffer::with_lines(vec!["Row0 ", "Row1 ", "Row2 "]));
+    test_case(1, Buffer::with_lines(vec!["Row1 ", "Row2 ", "Row3 "]));
+    // too much padding to fit, the selected row is still visible
+    test_case(5, Buffer::with_lines(vec!["Row0 ", "Row1 ", "Row2 "]));
+}
+
+#[test]
+fn widgets_table_rows_grow_to_fit_wrapped_cells() {
+    let backend = TestBackend::new(11, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let table = Table::new(vec![
+                Row::new(vec!["hello world", "a"]),
+                Row::new(vec!["x", "y"]),
+            ])
+            .header(Row::new(vec!["Text", "Long header"]))
+            .widths(&[Constraint::Length(6), Constraint::Length(4)])
+            .wrap(Wrap { trim: true });
+            f.render_widget(table, size);
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec![
+        "Text   Long",
+        "       head",
+        "       er  ",
+        "hello  a   ",
+        "world      ",
+    ]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_table_footer_stays_at_the_bottom() {
+    let backend = TestBackend::new(12, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TableState::default();
+    state.select(Some(3));
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let table = Table::new(vec![
+                Row::new(vec!["a", "1"]),
+                Row::new(vec!["b", "2"]),
+                Row::new(vec!["c", "3"]),
+                Row::new(vec!["d", "4"]),
+            ])
+            .header(Row::new(vec!["Name", "Qty"]))
+            .footer(Row::new(vec!["Total", "10"]).style(Style::DEFAULT.fg(Color::Yellow)))
+            .highlight_symbol(">")
+            .widths(&[Constraint::Length(5), Constraint::Length(3)]);
+            f.render_stateful_widget(table, size, &mut state);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![
+        " Name  Qty  ",
+        " b     2    ",
+        " c     3    ",
+        ">d     4    ",
+        " Total 10   ",
+    ]);
+    expected.set_style(Rect::new(0, 4, 12, 1), Style::DEFAULT.fg(Color::Yellow));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_table_columns_widths_can_fit_content() {
+    let backend = TestBackend::new(20, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let table = Table::new(vec![
+                Row::new(vec!["1", "Short", "x"]),
+                Row::new(vec!["2", "A bit longer", "y"]),
+            ])
+            .header(Row::new(vec!["Id", "Name", "Rest"]))
+            .widths(&[Constraint::Fit, Constraint::Fit, Constraint::Fill(1)]);
+            f.render_widget(table, size);
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec![
+        "Id Name         Rest",
+        "1  Short        x   ",
+        "2  A bit longer y   ",
+    ]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_table_fit_columns_share_the_space_left() {
+    let backend = TestBackend::new(20, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let table = Table::new(vec![Row::new(vec!["1", "Short", "x"])])
+                .header(Row::new(vec!["Id", "Name", "Rest"]))
+                .widths(&[Constraint::Fit, Constraint::Fit, Constraint::Length(4)]);
+            f.render_widget(table, size);
+        })
+        .unwrap();
+    // 7 cells are left once the columns fit their content, 4 for the first one and 3 for the
+    // second one
+    let expected = Buffer::with_lines(vec!["Id     Name     Rest", "1      Short    x   "]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_table_cells_can_span_several_columns() {
+    let backend = TestBackend::new(16, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let table = Table::new(vec![
+                Row::new(vec!["Name", "Street", "City"]),
+                Row::new(vec![Cell::from("Unknown address")
+                    .col_span(3)
+                    .style(Style::DEFAULT.fg(Color::Red))]),
+            ])
+            .header(Row::new(vec![
+                Cell::from(""),
+                Cell::from("Address").col_span(2),
+            ]))
+            .widths(&[
+                Constraint::Length(4),
+                Constraint::Length(6),
+                Constraint::Length(4),
+            ]);
+            f.render_widget(table, size);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![
+        "     Address    ",
+        "Name Street City",
+        "Unknown address ",
+    ]);
+    expected.set_style(Rect::new(0, 2, 16, 1), Style::DEFAULT.fg(Color::Red));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_table_can_have_row_separators_and_zebra_stripes() {
+    let backend = TestBackend::new(6, 6);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let table = Table::new(vec![
+                Row::new(vec!["Row1"]),
+                Row::new(vec!["Row2"]),
+                Row::new(vec!["Row3"]).style(Style::DEFAULT.fg(Color::Red)),
+                Row::new(vec!["Row4"]),
+            ])
+            .header(Row::new(vec!["Head"]))
+            .widths(&[Constraint::Length(6)])
+            .row_separator("-", Style::DEFAULT.fg(Color::Gray))
+            .zebra(Style::DEFAULT, Style::DEFAULT.bg(Color::Blue));
+            f.render_widget(table, size);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![
+        "Head  ", "Row1  ", "------", "Row2  ", "------", "Row3  ",
+    ]);
+    expected.set_style(Rect::new(0, 2, 6, 1), Style::DEFAULT.fg(Color::Gray));
+    expected.set_style(Rect::new(0, 3, 6, 1), Style::DEFAULT.bg(Color::Blue));
+    expected.set_style(Rect::new(0, 4, 6, 1), Style::DEFAULT.fg(Color::Gray));
+    expected.set_style(Rect::new(0, 5, 6, 1), Style::DEFAULT.fg(Color::Red));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_table_row_separators_are_only_between_rows() {
+    let backend = TestBackend::new(6, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TableState::default();
+    state.select(Some(3));
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let table = Table::new(vec![
+                Row::new(vec!["Row1"]),
+                Row::new(vec!["Row2"]),
+                Row::new(vec!["Row3"]),
+                Row::new(vec!["Row4"]),
+            ])
+            .widths(&[Constraint::Length(6)])
+            .row_separator("-", Style::DEFAULT);
+            f.render_stateful_widget(table, size, &mut state);
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["Row2  ", "------", "Row3  ", "------", "Row4  "]);
+    terminal.backend().assert_buffer(&expected);
+}
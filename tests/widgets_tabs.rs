@@ -1,5 +1,11 @@
 use tui::{
-    backend::TestBackend, buffer::Buffer, layout::Rect, symbols, text::Spans, widgets::Tabs,
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Color, Style},
+    symbols,
+    text::Spans,
+    widgets::{Tab, Tabs, TabsState},
     Terminal,
 };
 
@@ -21,12 +27,38 @@ fn widgets_tabs_should_not_panic_on_narrow_areas() {
             );
         })
         .unwrap();
-    let expected = Buffer::with_lines(vec![" "]);
+    let expected = Buffer::with_lines(vec!["▶"]);
     terminal.backend().assert_buffer(&expected);
 }
 
 #[test]
-fn widgets_tabs_should_truncate_the_last_item() {
+fn widgets_tabs_should_not_panic_on_empty_areas() {
+    let backend = TestBackend::new(4, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let tabs =
+                Tabs::new(["Tab1", "Tab2"].iter().cloned().map(Spans::from).collect()).select(1);
+            f.render_widget(tabs, Rect::new(4, 0, 0, 1));
+        })
+        .unwrap();
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["    "]));
+    terminal
+        .draw(|f| {
+            let tabs = Tabs::new(["Tab1", "Tab2"].iter().cloned().map(Spans::from).collect())
+                .direction(Direction::Vertical);
+            f.render_widget(tabs, Rect::new(4, 0, 0, 1));
+        })
+        .unwrap();
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["    "]));
+}
+
+#[test]
+fn widgets_tabs_should_indicate_hidden_items() {
     let backend = TestBackend::new(10, 1);
     let mut terminal = Terminal::new(backend).unwrap();
     terminal
@@ -43,6 +75,131 @@ fn widgets_tabs_should_truncate_the_last_item() {
             );
         })
         .unwrap();
-    let expected = Buffer::with_lines(vec![format!(" Tab1 {} T ", symbols::line::VERTICAL)]);
+    let expected = Buffer::with_lines(vec![format!("  Tab1 {}▶ ", symbols::line::VERTICAL)]);
     terminal.backend().assert_buffer(&expected);
 }
+
+#[test]
+fn widgets_tabs_should_highlight_the_tab_selected_in_the_state() {
+    let backend = TestBackend::new(13, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TabsState::new(2);
+    state.next();
+    terminal
+        .draw(|f| {
+            let tabs = Tabs::new(["Tab1", "Tab2"].iter().cloned().map(Spans::from).collect())
+                .highlight_style(Style::DEFAULT.fg(Color::Yellow))
+                .state(&state);
+            f.render_widget(tabs, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![format!(" Tab1 {} Tab2 ", symbols::line::VERTICAL)]);
+    for x in 8..12 {
+        expected.get_mut(x, 0).set_fg(Color::Yellow);
+    }
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_tabs_should_scroll_to_the_selected_item() {
+    let test_case = |selected, expected| {
+        let backend = TestBackend::new(16, 1);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let titles = ["Tab1", "Tab2", "Tab3", "Tab4"];
+                let tabs = Tabs::new(titles.iter().cloned().map(Spans::from).collect())
+                    .divider("|")
+                    .select(selected);
+                f.render_widget(tabs, f.size());
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    test_case(0, Buffer::with_lines(vec!["  Tab1 | Tab2 |▶"]));
+    test_case(1, Buffer::with_lines(vec!["  Tab1 | Tab2 |▶"]));
+    test_case(2, Buffer::with_lines(vec!["◀ Tab2 | Tab3 |▶"]));
+    test_case(3, Buffer::with_lines(vec!["◀ Tab3 | Tab4   "]));
+}
+
+#[test]
+fn widgets_tabs_can_be_laid_out_in_a_column() {
+    let test_case = |selected, expected| {
+        let backend = TestBackend::new(8, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let titles = ["Home", "Logs", "Settings", "Help"];
+                let tabs = Tabs::new(titles.iter().cloned().map(Spans::from).collect())
+                    .direction(Direction::Vertical)
+                    .highlight_style(Style::DEFAULT.fg(Color::Yellow))
+                    .select(selected);
+                f.render_widget(tabs, f.size());
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    let mut expected = Buffer::with_lines(vec!["  Home  ", "┃ Logs  ", "  Settin"]);
+    expected.set_style(Rect::new(0, 1, 8, 1), Style::DEFAULT.fg(Color::Yellow));
+    test_case(1, expected);
+
+    let mut expected = Buffer::with_lines(vec!["  Logs  ", "  Settin", "┃ Help  "]);
+    expected.set_style(Rect::new(0, 2, 8, 1), Style::DEFAULT.fg(Color::Yellow));
+    test_case(3, expected);
+}
+
+#[test]
+fn widgets_tabs_can_have_badges_and_styles() {
+    let backend = TestBackend::new(21, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let tabs = Tabs::with_tabs(vec![
+                Tab::new("Logs").badge(3),
+                Tab::new("Build").style(Style::DEFAULT.fg(Color::Red)),
+            ])
+            .divider("|")
+            .highlight_style(Style::DEFAULT.bg(Color::Blue));
+            f.render_widget(tabs, f.size());
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![" Logs (3) | Build    "]);
+    expected.set_style(Rect::new(1, 0, 8, 1), Style::DEFAULT.bg(Color::Blue));
+    expected.set_style(Rect::new(12, 0, 5, 1), Style::DEFAULT.fg(Color::Red));
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_tabs_drop_badges_that_do_not_fit() {
+    let tabs = || {
+        Tabs::with_tabs(vec![
+            Tab::new("Logs").badge(3),
+            Tab::new("Settings").badge(12),
+        ])
+    };
+
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| f.render_widget(tabs().direction(Direction::Vertical), f.size()))
+        .unwrap();
+    let expected = Buffer::with_lines(vec![
+        "┃ Logs (3)",
+        "  Settings",
+        "          ",
+        "          ",
+        "          ",
+    ]);
+    terminal.backend().assert_buffer(&expected);
+
+    let backend = TestBackend::new(10, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| f.render_widget(tabs().divider("|"), f.size()))
+        .unwrap();
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["  Logs | ▶"]));
+}
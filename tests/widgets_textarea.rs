@@ -0,0 +1,76 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, TextArea, TextAreaState},
+    Terminal,
+};
+
+#[test]
+fn widgets_textarea_renders_content_and_cursor() {
+    let backend = TestBackend::new(10, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TextAreaState::new("Hello\nWorld");
+    state.move_down();
+    state.move_end();
+    terminal
+        .draw(|f| {
+            let textarea = TextArea::default().block(Block::default().borders(Borders::ALL));
+            f.render_stateful_widget(textarea, f.size(), &mut state);
+            let (x, y) = state.screen_cursor().unwrap();
+            f.set_cursor(x, y);
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["┌────────┐", "│Hello   │", "│World   │", "└────────┘"]);
+    terminal.backend().assert_buffer(&expected);
+    assert_eq!(state.screen_cursor(), Some((6, 2)));
+}
+
+#[test]
+fn widgets_textarea_scrolls_to_the_cursor() {
+    let backend = TestBackend::new(4, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TextAreaState::new("one\ntwo\nthree\nfour");
+    state.set_cursor(2, 5);
+    terminal
+        .draw(|f| {
+            f.render_stateful_widget(TextArea::default(), f.size(), &mut state);
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["o   ", "ree "]);
+    terminal.backend().assert_buffer(&expected);
+    assert_eq!(state.offset(), (1, 2));
+    assert_eq!(state.screen_cursor(), Some((3, 1)));
+
+    // Moving to the top scrolls back
+    state.move_top();
+    terminal
+        .draw(|f| {
+            f.render_stateful_widget(TextArea::default(), f.size(), &mut state);
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["one ", "two "]);
+    terminal.backend().assert_buffer(&expected);
+    assert_eq!(state.offset(), (0, 0));
+}
+
+#[test]
+fn widgets_textarea_highlights_selection() {
+    let backend = TestBackend::new(6, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TextAreaState::new("abc\ndef");
+    state.set_cursor(0, 1);
+    state.start_selection();
+    state.set_cursor(1, 1);
+    terminal
+        .draw(|f| {
+            let textarea = TextArea::default().selection_style(Style::DEFAULT.bg(Color::Blue));
+            f.render_stateful_widget(textarea, f.size(), &mut state);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec!["abc   ", "def   "]);
+    expected.set_style(Rect::new(1, 0, 2, 1), Style::DEFAULT.bg(Color::Blue));
+    expected.set_style(Rect::new(0, 1, 1, 1), Style::DEFAULT.bg(Color::Blue));
+    terminal.backend().assert_buffer(&expected);
+}
@@ -0,0 +1,44 @@
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    symbols,
+    widgets::{Throbber, ThrobberState},
+    Terminal,
+};
+
+#[test]
+fn widgets_throbber_cycles_through_its_frames() {
+    let backend = TestBackend::new(12, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = ThrobberState::default();
+    let mut draw = |state: &mut ThrobberState| {
+        terminal
+            .draw(|f| {
+                let throbber = Throbber::default()
+                    .label("Loading")
+                    .throbber_set(symbols::throbber::LINE)
+                    .throbber_style(Style::DEFAULT.fg(Color::Cyan));
+                f.render_stateful_widget(throbber, f.size(), state);
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    };
+
+    let mut expected = Buffer::with_lines(vec!["- Loading   "]);
+    expected.set_style(Rect::new(0, 0, 1, 1), Style::DEFAULT.fg(Color::Cyan));
+    assert_eq!(draw(&mut state), expected);
+
+    state.tick();
+    let mut expected = Buffer::with_lines(vec!["\\ Loading   "]);
+    expected.set_style(Rect::new(0, 0, 1, 1), Style::DEFAULT.fg(Color::Cyan));
+    assert_eq!(draw(&mut state), expected);
+
+    for _ in 0..3 {
+        state.tick();
+    }
+    let mut expected = Buffer::with_lines(vec!["- Loading   "]);
+    expected.set_style(Rect::new(0, 0, 1, 1), Style::DEFAULT.fg(Color::Cyan));
+    assert_eq!(draw(&mut state), expected);
+}
@@ -0,0 +1,57 @@
+use std::time::Duration;
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::{Corner, Rect},
+    style::{Color, Style},
+    widgets::{ToastLevel, ToastState, Toasts},
+    Terminal,
+};
+
+#[test]
+fn widgets_toasts_are_stacked_in_a_corner() {
+    let test_case = |corner, expected| {
+        let backend = TestBackend::new(12, 7);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut state = ToastState::default();
+        state.push("Saved", ToastLevel::Success, Duration::from_secs(1));
+        state.push("Failed", ToastLevel::Error, Duration::from_secs(1));
+        terminal
+            .draw(|f| {
+                let toasts = Toasts::default().corner(corner).width(10);
+                f.render_stateful_widget(toasts, f.size(), &mut state);
+            })
+            .unwrap();
+        terminal.backend().assert_buffer(&expected);
+    };
+
+    let mut expected = Buffer::with_lines(vec![
+        "            ",
+        "  ┌────────┐",
+        "  │Saved   │",
+        "  └────────┘",
+        "  ┌────────┐",
+        "  │Failed  │",
+        "  └────────┘",
+    ]);
+    expected.set_style(Rect::new(2, 1, 10, 3), Style::DEFAULT.fg(Color::Green));
+    expected.set_style(Rect::new(3, 2, 8, 1), Style::DEFAULT.fg(Color::Reset));
+    expected.set_style(Rect::new(2, 4, 10, 3), Style::DEFAULT.fg(Color::Red));
+    expected.set_style(Rect::new(3, 5, 8, 1), Style::DEFAULT.fg(Color::Reset));
+    test_case(Corner::BottomRight, expected);
+
+    let mut expected = Buffer::with_lines(vec![
+        "┌────────┐  ",
+        "│Failed  │  ",
+        "└────────┘  ",
+        "┌────────┐  ",
+        "│Saved   │  ",
+        "└────────┘  ",
+        "            ",
+    ]);
+    expected.set_style(Rect::new(0, 0, 10, 3), Style::DEFAULT.fg(Color::Red));
+    expected.set_style(Rect::new(1, 1, 8, 1), Style::DEFAULT.fg(Color::Reset));
+    expected.set_style(Rect::new(0, 3, 10, 3), Style::DEFAULT.fg(Color::Green));
+    expected.set_style(Rect::new(1, 4, 8, 1), Style::DEFAULT.fg(Color::Reset));
+    test_case(Corner::TopLeft, expected);
+}
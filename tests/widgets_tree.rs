@@ -0,0 +1,122 @@
+use std::cell::Cell;
+use tui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Tree, TreeItem, TreeState},
+    Terminal,
+};
+
+fn items() -> Vec<TreeItem<'static>> {
+    vec![
+        TreeItem::new("a").children(vec![
+            TreeItem::new("b").children(vec![TreeItem::new("d")]),
+            TreeItem::new("c"),
+        ]),
+        TreeItem::new("e").lazy(),
+    ]
+}
+
+#[test]
+fn widgets_tree_renders_guides_and_loads_lazy_children() {
+    let backend = TestBackend::new(10, 6);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TreeState::default();
+    state.open(vec![0]);
+    state.open(vec![0, 0]);
+    state.open(vec![1]);
+    state.select(Some(vec![0, 1]));
+    terminal
+        .draw(|f| {
+            let tree = Tree::new(items())
+                .highlight_symbol(">")
+                .highlight_style(Style::DEFAULT.fg(Color::Yellow))
+                .loader(|path| {
+                    assert_eq!(path, [1]);
+                    vec![TreeItem::new("x")]
+                });
+            f.render_stateful_widget(tree, f.size(), &mut state);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(vec![
+        " ▼ a      ",
+        " ├─▼ b    ",
+        " │ └─  d  ",
+        ">└─  c    ",
+        " ▼ e      ",
+        " └─  x    ",
+    ]);
+    expected.set_style(Rect::new(0, 3, 10, 1), Style::DEFAULT.fg(Color::Yellow));
+    terminal.backend().assert_buffer(&expected);
+
+    state.select_next();
+    assert_eq!(state.selected(), Some(&[1][..]));
+    state.select_next();
+    assert_eq!(state.selected(), Some(&[1, 0][..]));
+}
+
+#[test]
+fn widgets_tree_without_guides_and_collapsed_nodes() {
+    let backend = TestBackend::new(8, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TreeState::default();
+    state.open(vec![0]);
+    terminal
+        .draw(|f| {
+            let tree = Tree::new(items()).guides(false).expand_symbols("+ ", "- ");
+            f.render_stateful_widget(tree, f.size(), &mut state);
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["- a     ", "  + b   ", "    c   "]);
+    terminal.backend().assert_buffer(&expected);
+}
+
+#[test]
+fn widgets_tree_scrolls_to_the_selection() {
+    let backend = TestBackend::new(6, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TreeState::default();
+    state.open(vec![0]);
+    state.select(Some(vec![1]));
+    terminal
+        .draw(|f| {
+            f.render_stateful_widget(Tree::new(items()), f.size(), &mut state);
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec!["└─  c ", "▶ e   "]);
+    terminal.backend().assert_buffer(&expected);
+    assert_eq!(state.offset(), 2);
+}
+
+#[test]
+fn widgets_tree_keeps_loaded_children_until_reloaded() {
+    let backend = TestBackend::new(6, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = TreeState::default();
+    state.open(vec![0]);
+    let loads = Cell::new(0);
+    let mut draw = |state: &mut TreeState| {
+        terminal
+            .draw(|f| {
+                let tree = Tree::new(vec![TreeItem::new("a").lazy()]).loader(|_| {
+                    loads.set(loads.get() + 1);
+                    vec![TreeItem::new(format!("x{}", loads.get()))]
+                });
+                f.render_stateful_widget(tree, f.size(), state);
+            })
+            .unwrap();
+        terminal.backend().buffer().clone()
+    };
+    let expected = Buffer::with_lines(vec!["▼ a   ", "└─  x1"]);
+    assert_eq!(draw(&mut state), expected);
+    assert_eq!(draw(&mut state), expected);
+    assert_eq!(loads.get(), 1);
+
+    state.reload(&[0]);
+    assert_eq!(
+        draw(&mut state),
+        Buffer::with_lines(vec!["▼ a   ", "└─  x2"])
+    );
+    assert_eq!(loads.get(), 2);
+}